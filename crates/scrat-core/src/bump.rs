@@ -11,18 +11,27 @@
 //!
 //! If the plan comes back as [`BumpPlan::NeedsInteraction`], the CLI prompts
 //! the user and calls [`resolve_interactive`] to get a [`ReadyBump`].
+//!
+//! [`sync_version`] repairs [version drift](crate::preflight::detect_drift)
+//! between the manifest and the latest tag instead of bumping.
 
 use std::process::Command;
 
-use camino::Utf8Path;
-use semver::Version;
+use camino::{Utf8Path, Utf8PathBuf};
+use semver::{Prerelease, Version};
 use serde::Serialize;
 use thiserror::Error;
 use tracing::{debug, info, instrument};
 
-use crate::config::Config;
-use crate::ecosystem::{ChangelogTool, Ecosystem, ProjectDetection, VersionStrategy};
-use crate::version::{self, conventional, explicit, interactive};
+use crate::config::{Config, VersionFileConfig};
+use crate::ecosystem::{ChangelogTool, ProjectDetection, VersionStrategy};
+use crate::manifest;
+use crate::package::PackageScope;
+use crate::preflight::{self, DriftState, VersionDrift};
+use crate::provider::VersionUpdate;
+use crate::version::calver::Date;
+use crate::version::tag::TagTemplate;
+use crate::version::{self, BumpLevel, VersionScheme, conventional, explicit, interactive};
 
 // ──────────────────────────────────────────────
 // Errors
@@ -40,13 +49,18 @@ pub enum BumpError {
         message: String,
     },
 
-    /// No bump tool available for this ecosystem.
-    #[error("no bump tool available (install cargo-edit for Rust)")]
-    NoBumpTool,
+    /// The version does not belong to the maintenance branch's line.
+    #[error("{version} is outside the {line} line of this maintenance branch")]
+    OutsideLine {
+        /// The version being released.
+        version: String,
+        /// The branch's line.
+        line: crate::version::line::VersionLine,
+    },
 
-    /// Ecosystem not supported for bump operations.
-    #[error("bump not yet supported for {0} ecosystem")]
-    UnsupportedEcosystem(Ecosystem),
+    /// `--bump` was used with a strategy that has no bump levels.
+    #[error("a forced bump level does not apply to the {0} strategy")]
+    ForcedLevel(VersionStrategy),
 
     /// Project detection failed.
     #[error("project detection failed: {0}")]
@@ -55,11 +69,42 @@ pub enum BumpError {
     /// Version computation failed.
     #[error(transparent)]
     Version(#[from] crate::version::VersionError),
+
+    /// Rewriting a project manifest failed.
+    #[error(transparent)]
+    Manifest(#[from] crate::manifest::ManifestError),
+
+    /// Creating the missing tag failed.
+    #[error(transparent)]
+    Git(#[from] crate::git::GitError),
+
+    /// The manifest and the latest tag disagree in a way `--sync` cannot
+    /// repair.
+    #[error("{0}")]
+    Drift(String),
 }
 
 /// Result alias for bump operations.
 pub type BumpResult<T> = Result<T, BumpError>;
 
+// ──────────────────────────────────────────────
+// Options
+// ──────────────────────────────────────────────
+
+/// Options for planning a bump.
+#[derive(Debug, Clone, Default)]
+pub struct BumpOptions {
+    /// Set the version explicitly (from CLI `--version`); overrides everything.
+    pub explicit_version: Option<String>,
+    /// Bump a single monorepo package instead of the whole repository.
+    pub package: Option<PackageScope>,
+    /// Release a pre-release on this channel (from CLI `--pre`, e.g. `"rc"`).
+    pub pre: Option<String>,
+    /// Force this bump level instead of the computed one (from CLI `--bump`).
+    /// The base is still the latest tag's version.
+    pub bump: Option<BumpLevel>,
+}
+
 // ──────────────────────────────────────────────
 // Plan types
 // ──────────────────────────────────────────────
@@ -84,6 +129,20 @@ pub struct ReadyBump {
     pub strategy: VersionStrategy,
     /// Detected ecosystem and tools.
     pub detection: ProjectDetection,
+    /// The monorepo package being bumped (`None` = whole repository).
+    pub package: Option<PackageScope>,
+    /// Python module whose `__version__` is bumped (`project.version_module`).
+    pub version_module: Option<Utf8PathBuf>,
+    /// Extra files whose version is rewritten (`[[version.files]]`).
+    pub version_files: Vec<VersionFileConfig>,
+    /// Secondary npm manifests that follow the version (`version.linked`).
+    pub linked: Vec<Utf8PathBuf>,
+    /// Whether the project carries a `-SNAPSHOT` version between releases.
+    pub snapshots: bool,
+    /// How release tags are named (`release.tag_template` or the package prefix).
+    pub tags: TagTemplate,
+    /// The bump level forced with `--bump` (`None` = computed).
+    pub forced: Option<BumpLevel>,
 }
 
 /// A bump plan that requires the user to pick a version interactively.
@@ -93,6 +152,18 @@ pub struct InteractiveBump {
     pub context: interactive::InteractiveContext,
     /// Detected ecosystem and tools.
     pub detection: ProjectDetection,
+    /// The monorepo package being bumped (`None` = whole repository).
+    pub package: Option<PackageScope>,
+    /// Python module whose `__version__` is bumped (`project.version_module`).
+    pub version_module: Option<Utf8PathBuf>,
+    /// Extra files whose version is rewritten (`[[version.files]]`).
+    pub version_files: Vec<VersionFileConfig>,
+    /// Secondary npm manifests that follow the version (`version.linked`).
+    pub linked: Vec<Utf8PathBuf>,
+    /// Whether the project carries a `-SNAPSHOT` version between releases.
+    pub snapshots: bool,
+    /// How release tags are named (`release.tag_template` or the package prefix).
+    pub tags: TagTemplate,
 }
 
 // ──────────────────────────────────────────────
@@ -105,60 +176,186 @@ pub struct InteractiveBump {
 /// (explicit or conventional commits), or [`BumpPlan::NeedsInteraction`] when
 /// the user must pick a version from candidates.
 ///
+/// On a maintenance branch, a version outside the branch's line is
+/// rejected with [`BumpError::OutsideLine`].
+///
 /// # Arguments
 /// * `project_root` — project working directory
 /// * `config` — loaded configuration (for strategy overrides)
-/// * `explicit_version` — if set, overrides everything (from CLI `--version` flag)
+/// * `options` — explicit version and monorepo package, if any
 #[instrument(skip(config), fields(%project_root))]
 pub fn plan_bump(
     project_root: &Utf8Path,
     config: &Config,
-    explicit_version: Option<&str>,
+    options: &BumpOptions,
+) -> BumpResult<BumpPlan> {
+    let plan = plan(project_root, config, options)?;
+    if let BumpPlan::Ready(ref ready) = plan {
+        ready.check_line()?;
+    }
+    Ok(plan)
+}
+
+/// Detect, resolve the strategy, and compute the version for [`plan_bump`]
+/// and [`explain_version`](crate::version::explain::explain_version).
+pub(crate) fn plan(
+    project_root: &Utf8Path,
+    config: &Config,
+    options: &BumpOptions,
 ) -> BumpResult<BumpPlan> {
+    let package = options.package.as_ref();
+
     // Step 1: Detect ecosystem (config override > auto-detect)
-    let detection = crate::detect::resolve_detection(project_root, config).ok_or_else(|| {
+    let detection = package.map_or_else(
+        || crate::detect::resolve_detection(project_root, config),
+        |p| crate::detect::resolve_package_detection(project_root, p),
+    );
+    let mut detection = detection.ok_or_else(|| {
         BumpError::Detection(
             "could not detect project type — use `project.type` in config or select interactively"
                 .into(),
         )
     })?;
 
+    // An explicitly configured bump command replaces the native file rewrite
+    if let Some(bump_cmd) = config.commands.as_ref().and_then(|c| c.bump.clone()) {
+        detection.tools.bump_cmd = Some(bump_cmd);
+    }
+
     // Step 2: Determine version strategy
     // CLI --version flag > config override > auto-detected
-    let strategy = explicit_version.map_or_else(
-        || resolve_strategy(config, &detection),
+    let tags = TagTemplate::resolve(config, package)?;
+    let strategy = options.explicit_version.as_deref().map_or_else(
+        || resolve_strategy(config, &detection, tags.scheme()),
         |v| VersionStrategy::Explicit(v.to_owned()),
     );
 
     debug!(%strategy, "resolved version strategy");
 
+    let version_module = config
+        .project
+        .as_ref()
+        .and_then(|p| p.version_module.clone());
+    // Version files and linked manifests describe the repository's version,
+    // not a package's
+    let version_config = config.version.as_ref().filter(|_| package.is_none());
+    let version_files = version_config
+        .and_then(|v| v.files.clone())
+        .unwrap_or_default();
+    let linked = version_config
+        .and_then(|v| v.linked.clone())
+        .unwrap_or_default();
+    let rules = config.version.as_ref().and_then(|v| v.bump.as_ref());
+    let work_dir = package.map_or_else(|| project_root.to_owned(), |p| p.root(project_root));
+    let snapshots = detection.ecosystem.provider().uses_snapshots(&work_dir);
+
     // Step 3: Compute version (or gather interactive context)
     match strategy {
         VersionStrategy::Explicit(ref v) => {
-            let next = explicit::validate_explicit(v)?;
-            let previous = current_or_zero()?;
+            let previous = current_or_zero(&tags)?;
+            let explicit = tags
+                .scheme()
+                .parse(v)
+                .map_or_else(|| explicit::validate_explicit(v), Ok)?;
+            let next = with_channel(&previous, explicit, options)?;
             Ok(BumpPlan::Ready(ReadyBump {
                 previous,
                 next,
                 strategy,
                 detection,
+                package: options.package.clone(),
+                version_module,
+                version_files,
+                linked,
+                snapshots,
+                tags,
+                forced: None,
             }))
         }
         VersionStrategy::ConventionalCommits { tool } => {
-            let next = conventional::compute_next_version(tool)?;
-            let previous = current_or_zero()?;
+            let previous = current_or_zero(&tags)?;
+            let release = if previous.pre.is_empty() {
+                conventional::compute_next_version(tool, &tags, package, rules, options.bump)?
+            } else {
+                // An open pre-release cycle leads to its own final version,
+                // unless a level is forced
+                options.bump.map_or_else(
+                    || version::release_of(&previous),
+                    |level| version::next_version(&previous, level),
+                )
+            };
+            let next = with_channel(&previous, release, options)?;
             Ok(BumpPlan::Ready(ReadyBump {
                 previous,
                 next,
                 strategy: VersionStrategy::ConventionalCommits { tool },
                 detection,
+                package: options.package.clone(),
+                version_module,
+                version_files,
+                linked,
+                snapshots,
+                tags,
+                forced: options.bump,
+            }))
+        }
+        VersionStrategy::CalVer { .. } if options.bump.is_some() => {
+            Err(BumpError::ForcedLevel(strategy))
+        }
+        VersionStrategy::CalVer { ref format } => {
+            let previous = current_or_zero(&tags)?;
+            let release = format.next(&previous, Date::today());
+            let next = with_channel(&previous, release, options)?;
+            Ok(BumpPlan::Ready(ReadyBump {
+                previous,
+                next,
+                strategy,
+                detection,
+                package: options.package.clone(),
+                version_module,
+                version_files,
+                linked,
+                snapshots,
+                tags,
+                forced: None,
             }))
         }
         VersionStrategy::Interactive => {
-            let context = interactive::gather_interactive_context(20)?;
+            // A forced level leaves nothing to pick
+            if let Some(level) = options.bump {
+                let previous = current_or_zero(&tags)?;
+                let next =
+                    with_channel(&previous, version::next_version(&previous, level), options)?;
+                return Ok(BumpPlan::Ready(ReadyBump {
+                    previous,
+                    next,
+                    strategy,
+                    detection,
+                    package: options.package.clone(),
+                    version_module,
+                    version_files,
+                    linked,
+                    snapshots,
+                    tags,
+                    forced: Some(level),
+                }));
+            }
+            let context = interactive::gather_interactive_context(
+                20,
+                &tags,
+                package,
+                options.pre.as_deref(),
+                rules,
+            )?;
             Ok(BumpPlan::NeedsInteraction(InteractiveBump {
                 context,
                 detection,
+                package: options.package.clone(),
+                version_module,
+                version_files,
+                linked,
+                snapshots,
+                tags,
             }))
         }
     }
@@ -176,23 +373,38 @@ pub fn resolve_interactive(plan: InteractiveBump, chosen_version: Version) -> Re
         next: chosen_version,
         strategy: VersionStrategy::Interactive,
         detection: plan.detection,
+        package: plan.package,
+        version_module: plan.version_module,
+        version_files: plan.version_files,
+        linked: plan.linked,
+        snapshots: plan.snapshots,
+        tags: plan.tags,
+        forced: None,
     }
 }
 
 /// Determine the version strategy from config overrides or auto-detection.
-fn resolve_strategy(config: &Config, detection: &ProjectDetection) -> VersionStrategy {
+fn resolve_strategy(
+    config: &Config,
+    detection: &ProjectDetection,
+    scheme: &VersionScheme,
+) -> VersionStrategy {
+    // A CalVer scheme is computed from the date, whatever the commits say
+    if let VersionScheme::CalVer(format) = scheme {
+        return VersionStrategy::CalVer {
+            format: format.clone(),
+        };
+    }
     // Config strategy override
     if let Some(ref vc) = config.version
         && let Some(ref s) = vc.strategy
     {
         match s.as_str() {
             "conventional-commits" => {
-                // Use the detected changelog tool, or default to git-cliff
-                let tool = detection
-                    .tools
-                    .changelog_tool
-                    .unwrap_or(ChangelogTool::GitCliff);
-                return VersionStrategy::ConventionalCommits { tool };
+                // Use the detected changelog tool, or the built-in parser
+                return VersionStrategy::ConventionalCommits {
+                    tool: detection.tools.changelog_tool,
+                };
             }
             "interactive" => return VersionStrategy::Interactive,
             // Anything else: fall through to detection
@@ -202,9 +414,21 @@ fn resolve_strategy(config: &Config, detection: &ProjectDetection) -> VersionStr
     detection.version_strategy.clone()
 }
 
+/// Turn `release` into a pre-release when a channel was requested.
+fn with_channel(
+    previous: &Version,
+    release: Version,
+    options: &BumpOptions,
+) -> BumpResult<Version> {
+    match options.pre {
+        Some(ref channel) => Ok(version::next_prerelease(Some(previous), &release, channel)?),
+        None => Ok(release),
+    }
+}
+
 /// Get the current version from tags, defaulting to 0.0.0 for first releases.
-fn current_or_zero() -> BumpResult<Version> {
-    let current = version::current_version_from_tags()?;
+fn current_or_zero(tags: &TagTemplate) -> BumpResult<Version> {
+    let current = version::current_version_from_tags(tags)?;
     Ok(current.unwrap_or_else(|| Version::new(0, 0, 0)))
 }
 
@@ -226,6 +450,55 @@ pub struct BumpOutcome {
 }
 
 impl ReadyBump {
+    /// The tag for the new version (`v1.2.3`, or `{prefix}1.2.3` for a package).
+    pub fn tag(&self) -> String {
+        self.tags.format(&self.next)
+    }
+
+    /// The tag of the previous version.
+    pub fn previous_tag(&self) -> String {
+        self.tags.format(&self.previous)
+    }
+
+    /// The new version as released (`26.05.1` for a zero-padded CalVer
+    /// format; the semver rendering otherwise).
+    pub fn version(&self) -> String {
+        self.tags.scheme().format(&self.next)
+    }
+
+    /// The previous version as released.
+    pub fn previous_version(&self) -> String {
+        self.tags.scheme().format(&self.previous)
+    }
+
+    /// Reject a version outside the maintenance line the tags are
+    /// restricted to (e.g. `2.0.0` on `release/1.x`).
+    pub fn check_line(&self) -> BumpResult<()> {
+        match self.tags.line() {
+            Some(line) if !line.contains(&self.next) => Err(BumpError::OutsideLine {
+                version: self.version(),
+                line: *line,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Directory of the project files being bumped: the package directory
+    /// in a monorepo, otherwise `project_root`.
+    pub fn work_dir(&self, project_root: &Utf8Path) -> Utf8PathBuf {
+        self.package
+            .as_ref()
+            .map_or_else(|| project_root.to_owned(), |p| p.root(project_root))
+    }
+
+    /// Path of the changelog relative to `project_root`.
+    pub fn changelog_path(&self) -> String {
+        self.package.as_ref().map_or_else(
+            || "CHANGELOG.md".into(),
+            |p| format!("{}/CHANGELOG.md", p.path),
+        )
+    }
+
     /// Execute the bump: update project files and optionally generate changelog.
     #[instrument(skip(self), fields(ecosystem = %self.detection.ecosystem, next = %self.next))]
     pub fn execute(
@@ -233,27 +506,24 @@ impl ReadyBump {
         project_root: &Utf8Path,
         update_changelog: bool,
     ) -> BumpResult<BumpOutcome> {
-        let mut modified_files = Vec::new();
-
-        // Update version in project files (Generic has no project files to update)
-        match self.detection.ecosystem {
-            Ecosystem::Rust => {
-                bump_rust_version(project_root, &self.next, &self.detection)?;
-                modified_files.push("Cargo.toml".into());
-            }
-            Ecosystem::Node => {
-                return Err(BumpError::UnsupportedEcosystem(Ecosystem::Node));
-            }
-            Ecosystem::Generic => {
-                debug!("generic ecosystem — no project files to bump");
-            }
-        }
+        // Declared version files and linked manifests go first: a stale
+        // locator fails the bump before any project file has been touched.
+        let mut modified_files = self.write_declared_versions(project_root)?;
+        modified_files.extend(self.write_project_version(project_root, &self.next)?);
 
         // Generate/update changelog (if requested and tool available)
         let changelog_updated = if update_changelog {
             if let Some(tool) = self.detection.tools.changelog_tool {
-                generate_changelog(project_root, &self.next, tool)?;
-                modified_files.push("CHANGELOG.md".into());
+                let changelog = self.changelog_path();
+                generate_changelog(
+                    project_root,
+                    &self.tag(),
+                    &changelog,
+                    tool,
+                    &self.tags,
+                    self.package.as_ref(),
+                )?;
+                modified_files.push(changelog);
                 true
             } else {
                 debug!("no changelog tool configured, skipping");
@@ -277,23 +547,210 @@ impl ReadyBump {
             modified_files,
         })
     }
+
+    /// The version the project moves on to after this release, when it
+    /// [uses snapshots](crate::provider::EcosystemProvider::uses_snapshots):
+    /// the next patch as a snapshot (`1.4.0` → `1.4.1-SNAPSHOT`), or the
+    /// release itself for a pre-release (`2.0.0-rc.1` → `2.0.0-SNAPSHOT`).
+    pub fn development_version(&self) -> Option<Version> {
+        if !self.snapshots {
+            return None;
+        }
+        let patch = if self.next.pre.is_empty() {
+            self.next.patch + 1
+        } else {
+            self.next.patch
+        };
+        let mut development = Version::new(self.next.major, self.next.minor, patch);
+        development.pre = Prerelease::new("SNAPSHOT").expect("SNAPSHOT is a valid pre-release");
+        Some(development)
+    }
+
+    /// Write the [development version](Self::development_version) into the
+    /// project files.
+    ///
+    /// Returns the files modified (none if the project does not use
+    /// snapshots).
+    pub fn set_development_version(&self, project_root: &Utf8Path) -> BumpResult<Vec<String>> {
+        self.development_version().map_or_else(
+            || Ok(Vec::new()),
+            |version| self.write_project_version(project_root, &version),
+        )
+    }
+
+    /// Write the release version into the declared version files
+    /// (`[[version.files]]`), as released, and linked manifests
+    /// (`version.linked`), as semver.
+    fn write_declared_versions(&self, project_root: &Utf8Path) -> BumpResult<Vec<String>> {
+        let mut modified_files =
+            manifest::files::set_version(project_root, &self.version_files, &self.version())?;
+        modified_files.extend(manifest::linked::set_version(
+            project_root,
+            &self.linked,
+            &self.next,
+        )?);
+        Ok(modified_files)
+    }
+
+    /// Write `version` into the project files, through the configured bump
+    /// command or the ecosystem provider (Generic has no files to update).
+    fn write_project_version(
+        &self,
+        project_root: &Utf8Path,
+        version: &Version,
+    ) -> BumpResult<Vec<String>> {
+        let package_dir = self.package.as_ref().map(|p| p.path.as_path());
+        if let Some(ref bump_cmd) = self.detection.tools.bump_cmd {
+            let work_dir = self.work_dir(project_root);
+            run_bump_command(&work_dir, bump_cmd, version)?;
+            return Ok(self
+                .detection
+                .ecosystem
+                .provider()
+                .manifest_file(&work_dir)
+                .map(|manifest| {
+                    package_dir
+                        .map_or_else(|| manifest.clone(), |dir| dir.join(&manifest).into_string())
+                })
+                .into_iter()
+                .collect());
+        }
+        Ok(self
+            .detection
+            .ecosystem
+            .provider()
+            .set_version(&VersionUpdate {
+                project_root,
+                package_dir,
+                version,
+                version_module: self.version_module.as_deref(),
+            })?)
+    }
 }
 
 // ──────────────────────────────────────────────
-// Internal helpers
+// Sync
 // ──────────────────────────────────────────────
 
-/// Bump the version in Cargo.toml using `cargo set-version`.
-fn bump_rust_version(
+/// The repair [`sync_version`] made (or would make, in a dry run).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "action")]
+pub enum SyncAction {
+    /// Nothing to repair.
+    None,
+    /// The manifest was rewritten to the tagged version.
+    RewroteManifest {
+        /// The version written.
+        version: String,
+        /// Files that were modified (empty in a dry run).
+        modified_files: Vec<String>,
+    },
+    /// The missing tag was created at `HEAD`.
+    CreatedTag {
+        /// The tag name.
+        tag: String,
+    },
+}
+
+/// Result of [`sync_version`].
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncOutcome {
+    /// The drift found (`None` = nothing to compare: no tag or no manifest
+    /// version).
+    pub drift: Option<VersionDrift>,
+    /// The repair made.
+    pub action: SyncAction,
+}
+
+/// Repair drift between the manifest version and the latest reachable tag
+/// (`scrat bump --sync`).
+///
+/// A manifest behind the tag is rewritten to the tagged version (or the
+/// development version after it, for snapshot projects), along with the
+/// declared version files. A manifest ahead of the tag gets its missing tag
+/// at `HEAD`. With `dry_run`, only the repair is reported.
+///
+/// # Errors
+///
+/// Returns [`BumpError::Drift`] for a [mismatch](DriftState::Mismatch),
+/// which needs a human to decide.
+#[instrument(skip(config), fields(%project_root))]
+pub fn sync_version(
+    project_root: &Utf8Path,
+    config: &Config,
+    dry_run: bool,
+) -> BumpResult<SyncOutcome> {
+    let detection = crate::detect::resolve_detection(project_root, config).ok_or_else(|| {
+        BumpError::Detection("could not detect project type — use `project.type` in config".into())
+    })?;
+    let drift = preflight::detect_drift(project_root, config, &detection)?;
+    let action = match drift {
+        None => SyncAction::None,
+        Some(ref drift) => match drift.state {
+            DriftState::InSync => SyncAction::None,
+            DriftState::Mismatch => return Err(BumpError::Drift(drift.message())),
+            DriftState::Behind => rewrite_to_tag(project_root, config, drift, dry_run)?,
+            DriftState::Ahead => {
+                let tags = TagTemplate::resolve(config, None)?;
+                let version = drift
+                    .manifest()
+                    .ok_or_else(|| BumpError::Drift(drift.message()))?;
+                let tag = tags.format(&version);
+                if !dry_run {
+                    let name = tags.scheme().format(&version);
+                    crate::git::create_tag(&tag, &format!("Release {name}"))?;
+                    info!(%tag, "created missing tag");
+                }
+                SyncAction::CreatedTag { tag }
+            }
+        },
+    };
+    Ok(SyncOutcome { drift, action })
+}
+
+/// Write the tagged version back into the project files.
+fn rewrite_to_tag(
     project_root: &Utf8Path,
-    version: &Version,
-    detection: &ProjectDetection,
-) -> BumpResult<()> {
-    let Some(ref bump_cmd) = detection.tools.bump_cmd else {
-        return Err(BumpError::NoBumpTool);
+    config: &Config,
+    drift: &VersionDrift,
+    dry_run: bool,
+) -> BumpResult<SyncAction> {
+    let tags = TagTemplate::resolve(config, None)?;
+    let options = BumpOptions {
+        explicit_version: Some(tags.scheme().format(&drift.tag_version)),
+        ..BumpOptions::default()
+    };
+    let BumpPlan::Ready(ready) = plan_bump(project_root, config, &options)? else {
+        unreachable!("an explicit version never needs interaction");
+    };
+    let version = ready
+        .development_version()
+        .map_or_else(|| ready.version(), |v| v.to_string());
+    let modified_files = if dry_run {
+        Vec::new()
+    } else if ready.snapshots {
+        // As after a release: the declared files carry the tagged version,
+        // the manifest the development version after it
+        let mut files = ready.write_declared_versions(project_root)?;
+        files.extend(ready.set_development_version(project_root)?);
+        files
+    } else {
+        ready.execute(project_root, false)?.modified_files
     };
+    Ok(SyncAction::RewroteManifest {
+        version,
+        modified_files,
+    })
+}
 
-    debug!(%bump_cmd, %version, "bumping Rust version");
+// ──────────────────────────────────────────────
+// Internal helpers
+// ──────────────────────────────────────────────
+
+/// Bump the version with an explicitly configured command
+/// (e.g. `cargo set-version`), passing the new version as the last argument.
+fn run_bump_command(project_root: &Utf8Path, bump_cmd: &str, version: &Version) -> BumpResult<()> {
+    debug!(%bump_cmd, %version, "bumping version via configured command");
 
     let parts: Vec<&str> = bump_cmd.split_whitespace().collect();
     let (bin, args) = parts.split_first().unwrap_or((&"cargo", &[]));
@@ -304,14 +761,14 @@ fn bump_rust_version(
         .current_dir(project_root.as_std_path())
         .output()
         .map_err(|e| BumpError::ToolFailed {
-            tool: bump_cmd.clone(),
+            tool: bump_cmd.to_owned(),
             message: format!("failed to execute: {e}"),
         })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
         return Err(BumpError::ToolFailed {
-            tool: bump_cmd.clone(),
+            tool: bump_cmd.to_owned(),
             message: stderr,
         });
     }
@@ -320,17 +777,25 @@ fn bump_rust_version(
 }
 
 /// Generate or update the changelog.
+///
+/// git-cliff only considers tags matching `tags`. For a monorepo package,
+/// it only considers the package's commits and writes the package's own
+/// changelog.
 fn generate_changelog(
     project_root: &Utf8Path,
-    version: &Version,
+    tag: &str,
+    changelog: &str,
     tool: ChangelogTool,
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
 ) -> BumpResult<()> {
     match tool {
         ChangelogTool::GitCliff => {
-            debug!("generating changelog via git-cliff");
-            let output = Command::new("git-cliff")
-                .args(["--output", "CHANGELOG.md", "--tag"])
-                .arg(format!("v{version}"))
+            debug!(%changelog, "generating changelog via git-cliff");
+            let mut cmd = Command::new("git-cliff");
+            cmd.args(["--output", changelog, "--tag", tag]);
+            cmd.args(conventional::cliff_tag_args(tags, package));
+            let output = cmd
                 .current_dir(project_root.as_std_path())
                 .output()
                 .map_err(|e| BumpError::ToolFailed {
@@ -369,3 +834,54 @@ fn generate_changelog(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::version::calver::CalVerFormat;
+
+    #[test]
+    fn calver_version_files_keep_padding() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = Utf8Path::from_path(tmp.path()).unwrap();
+        std::fs::write(root.join("VERSION"), "2026.04.3\n").unwrap();
+        std::fs::write(
+            root.join("package.json"),
+            "{\n  \"name\": \"web\",\n  \"version\": \"2026.4.3\"\n}\n",
+        )
+        .unwrap();
+
+        let format = CalVerFormat::new("YYYY.0M.MICRO").unwrap();
+        let ready = ReadyBump {
+            previous: Version::new(2026, 4, 3),
+            next: Version::new(2026, 5, 1),
+            strategy: VersionStrategy::CalVer {
+                format: format.clone(),
+            },
+            detection: ProjectDetection::generic(VersionStrategy::Interactive),
+            package: None,
+            version_module: None,
+            version_files: vec![VersionFileConfig {
+                path: "VERSION".into(),
+                regex: Some("(.+)".into()),
+                ..VersionFileConfig::default()
+            }],
+            linked: vec!["package.json".into()],
+            snapshots: false,
+            tags: TagTemplate::default().with_scheme(VersionScheme::CalVer(format)),
+            forced: None,
+        };
+
+        let modified = ready.write_declared_versions(root).unwrap();
+        assert_eq!(modified, vec!["VERSION", "package.json"]);
+        assert_eq!(
+            std::fs::read_to_string(root.join("VERSION")).unwrap(),
+            "2026.05.1\n"
+        );
+        assert!(
+            std::fs::read_to_string(root.join("package.json"))
+                .unwrap()
+                .contains("\"version\": \"2026.5.1\"")
+        );
+    }
+}
@@ -32,6 +32,8 @@
 //!     .unwrap();
 //! ```
 
+use std::collections::BTreeMap;
+
 use camino::{Utf8Path, Utf8PathBuf};
 use figment::Figment;
 use figment::providers::{Format, Json, Serialized, Toml, Yaml};
@@ -39,6 +41,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::ecosystem::{ChangelogTool, Ecosystem};
 use crate::error::{ConfigError, ConfigResult};
+use crate::version::BumpLevel;
 
 /// The configuration for scrat.
 ///
@@ -54,10 +57,14 @@ pub struct Config {
     pub log_dir: Option<Utf8PathBuf>,
     /// Project configuration overrides.
     pub project: Option<ProjectConfig>,
+    /// Independently versioned packages of a monorepo (`[[packages]]`).
+    pub packages: Option<Vec<PackageConfig>>,
     /// Version strategy overrides.
     pub version: Option<VersionConfig>,
     /// Command overrides per workflow phase.
     pub commands: Option<CommandsConfig>,
+    /// Registry publish behavior.
+    pub publish: Option<PublishConfig>,
     /// Release workflow configuration.
     pub release: Option<ReleaseConfig>,
     /// Hook commands per release phase.
@@ -72,11 +79,41 @@ pub struct Config {
 /// Use this section to override the detected values.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ProjectConfig {
-    /// Override the detected ecosystem (e.g., `"rust"`, `"node"`).
+    /// Override the detected ecosystem (e.g., `"rust"`, `"node"`, `"python"`).
     #[serde(rename = "type")]
     pub project_type: Option<Ecosystem>,
     /// Override the release branch (default: auto-detect `main` or `master`).
     pub release_branch: Option<String>,
+    /// Maintenance branch patterns for older lines, released alongside the
+    /// release branch (e.g. `["release/*"]`). `*` matches any text and `?`
+    /// one character; the line comes from the branch name (`release/1.x`
+    /// ships `1.*`).
+    pub release_branches: Option<Vec<String>>,
+    /// Git remote releases are pushed to and published on (default: the
+    /// remote the current branch tracks, then the one the release branch
+    /// tracks, else `"origin"`).
+    pub remote: Option<String>,
+    /// Python module whose `__version__` is bumped, relative to the project
+    /// root (e.g. `"src/mypkg/__init__.py"`).
+    pub version_module: Option<Utf8PathBuf>,
+}
+
+/// A package released on its own schedule within a monorepo.
+///
+/// Each package gets its own tag (`{name}-v{version}` by default), and its
+/// version, changelog, dependency diff, and stats only consider commits
+/// that touched files under `path`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PackageConfig {
+    /// Package name, used for `--package` and the default tag prefix.
+    pub name: String,
+    /// Package directory relative to the repository root.
+    pub path: Utf8PathBuf,
+    /// Override the detected ecosystem for this package.
+    #[serde(rename = "type")]
+    pub package_type: Option<Ecosystem>,
+    /// Override the tag prefix (default: `"{name}-v"`).
+    pub tag_prefix: Option<String>,
 }
 
 /// Version strategy configuration.
@@ -86,8 +123,59 @@ pub struct ProjectConfig {
 pub struct VersionConfig {
     /// Override the version strategy.
     ///
-    /// Possible values: `"conventional-commits"`, `"interactive"`, `"explicit"`.
+    /// Possible values: `"conventional-commits"`, `"interactive"`, `"explicit"`,
+    /// `"calver"`.
     pub strategy: Option<String>,
+    /// Calendar versioning format for `strategy = "calver"`
+    /// (default: `"YYYY.MM.MICRO"`), e.g. `"YY.0W.MICRO"`.
+    pub calver_format: Option<String>,
+    /// Extra files carrying the version, rewritten on every bump
+    /// (`[[version.files]]`).
+    pub files: Option<Vec<VersionFileConfig>>,
+    /// Secondary `package.json` files (or their directories) that follow the
+    /// project version, along with the dependency pins between them.
+    ///
+    /// Path segments may use `*` and `?` wildcards, as Cargo workspace
+    /// members do (e.g. `"npm/platforms/*"`).
+    pub linked: Option<Vec<Utf8PathBuf>>,
+    /// How conventional commits map to bump levels (`[version.bump]`).
+    pub bump: Option<BumpRulesConfig>,
+}
+
+/// Commit-type to bump-level policy for the conventional-commits strategy.
+///
+/// When set, scrat analyzes the commits itself instead of asking git-cliff
+/// or cog for the next version, so the policy holds whichever tool is
+/// configured.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct BumpRulesConfig {
+    /// Bump level per commit type, on top of the defaults
+    /// (`feat` → minor, `fix` and `perf` → patch), e.g. `{ perf = "minor" }`.
+    pub types: Option<BTreeMap<String, BumpLevel>>,
+    /// Commit types that never trigger a release, even when breaking.
+    pub ignore: Option<Vec<String>>,
+    /// Level of a major bump while the version is `0.x` (default: major).
+    /// Set to `"minor"` for breaking changes to bump `0.4.2` → `0.5.0`.
+    pub breaking_in_zero: Option<BumpLevel>,
+}
+
+/// A file whose version is rewritten on every bump.
+///
+/// Exactly one locator (`regex`, `toml`, `json`, or `yaml`) must be set,
+/// and it must match exactly one place in the file.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VersionFileConfig {
+    /// File path relative to the project root.
+    pub path: Utf8PathBuf,
+    /// Regex whose first capture group is the version
+    /// (e.g. `'const Version = "(.+)"'`).
+    pub regex: Option<String>,
+    /// Dotted TOML key path (e.g. `"tool.mytool.version"`).
+    pub toml: Option<String>,
+    /// JSON pointer (e.g. `"/version"`).
+    pub json: Option<String>,
+    /// Dotted YAML key path (e.g. `"image.tag"`).
+    pub yaml: Option<String>,
 }
 
 /// Command overrides for each phase of the release workflow.
@@ -102,10 +190,39 @@ pub struct CommandsConfig {
     pub build: Option<String>,
     /// Override the publish command (e.g., `"cargo publish"`).
     pub publish: Option<String>,
+    /// External version bump command (e.g., `"cargo set-version"`).
+    ///
+    /// The new version is appended as the last argument. When unset, scrat
+    /// rewrites the version in the project manifest itself.
+    pub bump: Option<String>,
     /// Override the clean command.
     pub clean: Option<String>,
 }
 
+/// Registry publish configuration.
+///
+/// Used when publishing a Cargo workspace crate by crate: after each crate
+/// is uploaded, scrat waits for it to appear in the registry index before
+/// publishing the crates that depend on it.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PublishConfig {
+    /// Registry name passed to `cargo publish --registry` (default: crates.io).
+    pub registry: Option<String>,
+    /// Registry index to poll (default: `https://index.crates.io/`).
+    /// Required when [`registry`](Self::registry) is set.
+    ///
+    /// Accepts a sparse index URL (`sparse+https://...` or `https://...`)
+    /// or a local directory (`file://...` or a plain path) for mirrors.
+    pub index: Option<String>,
+    /// Seconds between index polls (default: 5).
+    pub poll_interval: Option<u64>,
+    /// Seconds to wait for a crate to appear in the index (default: 300).
+    pub timeout: Option<u64>,
+    /// Command run in each linked npm package directory (default:
+    /// `npm publish`).
+    pub linked_command: Option<String>,
+}
+
 /// Release workflow configuration.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ReleaseConfig {
@@ -139,6 +256,13 @@ pub struct ReleaseConfig {
     /// When set, passes `--discussion-category <value>` to `gh release create`.
     /// Only applies to newly created releases (not edits).
     pub discussion_category: Option<String>,
+    /// How release tags are named, with `{version}` standing for the version
+    /// (default: `"v{version}"`).
+    ///
+    /// Every tag scrat creates, looks up, or compares against goes through
+    /// it; tags that don't match are ignored. Monorepo packages use their
+    /// own `tag_prefix` instead.
+    pub tag_template: Option<String>,
 }
 
 /// Hook commands to run at each phase of the release workflow.
@@ -707,6 +831,38 @@ release_branch = "main"
         assert_eq!(project.release_branch.as_deref(), Some("main"));
     }
 
+    #[test]
+    fn test_config_python_version_module() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+[project]
+type = "python"
+version_module = "src/app/__init__.py"
+"#,
+        )
+        .unwrap();
+
+        let config_path = Utf8PathBuf::try_from(config_path).unwrap();
+        let config = ConfigLoader::new()
+            .with_user_config(false)
+            .with_file(&config_path)
+            .load()
+            .unwrap();
+
+        let project = config.project.unwrap();
+        assert_eq!(
+            project.project_type,
+            Some(crate::ecosystem::Ecosystem::Python)
+        );
+        assert_eq!(
+            project.version_module.as_deref(),
+            Some(Utf8Path::new("src/app/__init__.py"))
+        );
+    }
+
     #[test]
     fn test_config_with_commands_section() {
         let tmp = TempDir::new().unwrap();
@@ -927,6 +1083,141 @@ confirm = false
         assert_eq!(ship.confirm, Some(false));
     }
 
+    #[test]
+    fn test_config_with_publish_section() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+[publish]
+index = "file:///srv/crates-mirror"
+poll_interval = 2
+timeout = 60
+"#,
+        )
+        .unwrap();
+
+        let config_path = Utf8PathBuf::try_from(config_path).unwrap();
+        let config = ConfigLoader::new()
+            .with_user_config(false)
+            .with_file(&config_path)
+            .load()
+            .unwrap();
+
+        let publish = config.publish.unwrap();
+        assert_eq!(publish.index.as_deref(), Some("file:///srv/crates-mirror"));
+        assert_eq!(publish.poll_interval, Some(2));
+        assert_eq!(publish.timeout, Some(60));
+        assert!(publish.registry.is_none());
+    }
+
+    #[test]
+    fn test_config_with_packages() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+[[packages]]
+name = "scrat-core"
+path = "crates/scrat-core"
+
+[[packages]]
+name = "web"
+path = "packages/web"
+type = "node"
+tag_prefix = "web@"
+"#,
+        )
+        .unwrap();
+
+        let config_path = Utf8PathBuf::try_from(config_path).unwrap();
+        let config = ConfigLoader::new()
+            .with_user_config(false)
+            .with_file(&config_path)
+            .load()
+            .unwrap();
+
+        let packages = config.packages.unwrap();
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].name, "scrat-core");
+        assert_eq!(packages[0].path, "crates/scrat-core");
+        assert!(packages[0].package_type.is_none());
+        assert_eq!(packages[1].package_type, Some(Ecosystem::Node));
+        assert_eq!(packages[1].tag_prefix.as_deref(), Some("web@"));
+    }
+
+    #[test]
+    fn test_config_with_version_files() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+[version]
+strategy = "interactive"
+
+[[version.files]]
+path = "VERSION"
+regex = '(\d+\.\d+\.\d+)'
+
+[[version.files]]
+path = "chart/Chart.yaml"
+yaml = "appVersion"
+"#,
+        )
+        .unwrap();
+
+        let config_path = Utf8PathBuf::try_from(config_path).unwrap();
+        let config = ConfigLoader::new()
+            .with_user_config(false)
+            .with_file(&config_path)
+            .load()
+            .unwrap();
+
+        let files = config.version.unwrap().files.unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "VERSION");
+        assert_eq!(files[0].regex.as_deref(), Some(r"(\d+\.\d+\.\d+)"));
+        assert_eq!(files[1].yaml.as_deref(), Some("appVersion"));
+        assert!(files[1].regex.is_none());
+    }
+
+    #[test]
+    fn test_config_with_bump_rules() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = tmp.path().join("config.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version:
+  strategy: conventional-commits
+  bump:
+    types:
+      perf: minor
+      deps: patch
+    ignore: [chore, ci]
+    breaking_in_zero: minor
+"#,
+        )
+        .unwrap();
+
+        let config_path = Utf8PathBuf::try_from(config_path).unwrap();
+        let config = ConfigLoader::new()
+            .with_user_config(false)
+            .with_file(&config_path)
+            .load()
+            .unwrap();
+
+        let rules = config.version.unwrap().bump.unwrap();
+        let types = rules.types.unwrap();
+        assert_eq!(types["perf"], BumpLevel::Minor);
+        assert_eq!(types["deps"], BumpLevel::Patch);
+        assert_eq!(rules.ignore.unwrap(), vec!["chore", "ci"]);
+        assert_eq!(rules.breaking_in_zero, Some(BumpLevel::Minor));
+    }
+
     #[test]
     fn test_config_ship_defaults_to_none() {
         let config = Config::default();
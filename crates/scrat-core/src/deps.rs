@@ -5,8 +5,18 @@
 //! and `filter:` hooks via the [`PipelineContext`](crate::pipeline::PipelineContext).
 //!
 //! Currently supports:
-//! - **Cargo.lock** (Rust) — full parser
-//! - **package-lock.json** (Node) — stub, returns empty
+//! - **Cargo.lock** (Rust)
+//! - **package-lock.json** / **npm-shrinkwrap.json** (Node, lockfile v2/v3)
+//! - **pnpm-lock.yaml** (Node, lockfile v5 through v9)
+//! - **yarn.lock** (Node, classic and berry)
+//! - **uv.lock** / **poetry.lock** (Python)
+//! - **requirements.txt** (Python, pinned `name==version` lines)
+//! - **go.sum** (Go)
+//! - **gradle.lockfile** (Gradle)
+//! - **Gemfile.lock** (Ruby)
+//! - **composer.lock** (PHP)
+
+use std::collections::BTreeMap;
 
 use tracing::{debug, warn};
 
@@ -16,39 +26,73 @@ use crate::pipeline::DepChange;
 
 /// Compute dependency changes between a ref and HEAD for the given ecosystem.
 ///
-/// Returns an empty `Vec` if the lockfile doesn't exist or hasn't changed.
+/// Tries each of the ecosystem's [lockfiles](Ecosystem::lockfile_paths) in
+/// order and parses the first one with changes. For a monorepo package,
+/// `package_dir` names its directory: lockfiles there are tried before the
+/// shared ones at the repository root.
+///
+/// Returns an empty `Vec` if no lockfile exists or none has changed.
 /// Deps diff failure is non-fatal — logs a warning and returns empty.
-pub fn compute_deps(ecosystem: Ecosystem, previous_tag: &str) -> Vec<DepChange> {
-    let Some(lockfile) = ecosystem.lockfile_path() else {
+pub fn compute_deps(
+    ecosystem: Ecosystem,
+    previous_tag: &str,
+    package_dir: Option<&str>,
+) -> Vec<DepChange> {
+    let provider = ecosystem.provider();
+    let lockfiles = provider.lockfiles();
+    if lockfiles.is_empty() {
         debug!(%ecosystem, "no lockfile for ecosystem, skipping deps diff");
         return Vec::new();
-    };
+    }
 
-    let diff = match git::diff_file(previous_tag, lockfile) {
-        Ok(d) => d,
-        Err(e) => {
-            warn!(%e, lockfile, "failed to diff lockfile, skipping deps");
-            return Vec::new();
+    let candidates = package_dir
+        .into_iter()
+        .flat_map(|dir| lockfiles.iter().map(move |l| (*l, format!("{dir}/{l}"))))
+        .chain(lockfiles.iter().map(|l| (*l, (*l).to_string())));
+
+    for (lockfile, path) in candidates {
+        let diff = match git::diff_file(previous_tag, &path) {
+            Ok(d) => d,
+            Err(e) => {
+                warn!(%e, %path, "failed to diff lockfile, skipping deps");
+                return Vec::new();
+            }
+        };
+
+        if diff.is_empty() {
+            debug!(%path, "no lockfile changes");
+            continue;
         }
-    };
 
-    if diff.is_empty() {
-        debug!(lockfile, "no lockfile changes");
-        return Vec::new();
+        let changes = provider.parse_lockfile_diff(lockfile, &diff);
+        debug!(%path, count = changes.len(), "parsed dep changes");
+        return changes;
     }
 
-    let changes = match ecosystem {
-        Ecosystem::Rust => parse_cargo_lock_diff(&diff),
-        Ecosystem::Node => parse_package_lock_diff(&diff),
-        Ecosystem::Generic => Vec::new(),
-    };
+    Vec::new()
+}
 
-    debug!(lockfile, count = changes.len(), "parsed dep changes");
-    changes
+/// Dispatch a lockfile diff to the parser for its format.
+pub(crate) fn parse_lockfile_diff(lockfile: &str, diff: &str) -> Vec<DepChange> {
+    match lockfile {
+        "Cargo.lock" | "uv.lock" | "poetry.lock" => parse_cargo_lock_diff(diff),
+        "package-lock.json" | "npm-shrinkwrap.json" => parse_package_lock_diff(diff),
+        "pnpm-lock.yaml" => parse_pnpm_lock_diff(diff),
+        "yarn.lock" => parse_yarn_lock_diff(diff),
+        "requirements.txt" => parse_requirements_diff(diff),
+        "go.sum" => parse_go_sum_diff(diff),
+        "gradle.lockfile" => parse_gradle_lockfile_diff(diff),
+        "Gemfile.lock" => parse_gemfile_lock_diff(diff),
+        "composer.lock" => parse_composer_lock_diff(diff),
+        _ => Vec::new(),
+    }
 }
 
 /// Parse a unified diff of `Cargo.lock` into dependency changes.
 ///
+/// `uv.lock` and `poetry.lock` share the same `[[package]]` layout and are
+/// parsed here too.
+///
 /// State machine tracking per-`[[package]]` blocks:
 /// - `name` from any `name = "..."` line (context, removed, or added)
 /// - `old_version` from `-version = "..."` lines
@@ -152,11 +196,412 @@ fn extract_toml_string_value(line: &str, key: &str) -> Option<String> {
     Some(value.to_string())
 }
 
+/// Versions of a package that were removed or added in a diff.
+#[derive(Debug, Default)]
+struct VersionDelta {
+    removed: Vec<String>,
+    added: Vec<String>,
+}
+
+/// Accumulates `(name, version)` pairs from removed and added diff lines.
+///
+/// Node lockfiles may list the same package several times (multiple
+/// versions, or duplicated sections), so pairs are merged per name before
+/// emitting [`DepChange`]s.
+#[derive(Debug, Default)]
+struct DeltaCollector {
+    packages: BTreeMap<String, VersionDelta>,
+}
+
+impl DeltaCollector {
+    fn record(&mut self, sign: char, name: &str, version: &str) {
+        let delta = self.packages.entry(name.to_string()).or_default();
+        let list = if sign == '-' {
+            &mut delta.removed
+        } else {
+            &mut delta.added
+        };
+        if !list.iter().any(|v| v == version) {
+            list.push(version.to_string());
+        }
+    }
+
+    /// Pair removed and added versions per package, in version order.
+    ///
+    /// Versions present on both sides (e.g. a block re-serialized without a
+    /// version change) cancel out. Leftovers become additions or removals.
+    fn finish(self) -> Vec<DepChange> {
+        let mut changes = Vec::new();
+        for (name, mut delta) in self.packages {
+            let unchanged: Vec<String> = delta
+                .removed
+                .iter()
+                .filter(|v| delta.added.contains(v))
+                .cloned()
+                .collect();
+            delta.removed.retain(|v| !unchanged.contains(v));
+            delta.added.retain(|v| !unchanged.contains(v));
+            delta.removed.sort_by(|a, b| compare_versions(a, b));
+            delta.added.sort_by(|a, b| compare_versions(a, b));
+
+            let count = delta.removed.len().max(delta.added.len());
+            for i in 0..count {
+                changes.push(DepChange {
+                    name: name.clone(),
+                    from: delta.removed.get(i).cloned(),
+                    to: delta.added.get(i).cloned(),
+                });
+            }
+        }
+        changes
+    }
+}
+
+/// Order versions by semver, falling back to string comparison.
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+/// Split a diff line into its sign (`' '`, `'-'`, `'+'`) and content.
+///
+/// File headers (`---`/`+++`) and hunk headers (`@@`) return `None`.
+fn split_diff_line(line: &str) -> Option<(char, &str)> {
+    if line.starts_with("---") || line.starts_with("+++") || line.starts_with("@@") {
+        return None;
+    }
+    let mut chars = line.chars();
+    match chars.next()? {
+        sign @ (' ' | '-' | '+') => Some((sign, chars.as_str())),
+        _ => None,
+    }
+}
+
+/// Number of leading whitespace characters.
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
 /// Parse a unified diff of `package-lock.json` into dependency changes.
 ///
-/// Stub — returns empty for now. Full implementation deferred.
-const fn parse_package_lock_diff(_diff: &str) -> Vec<DepChange> {
-    Vec::new()
+/// Handles lockfile v2/v3, where the `packages` map is keyed by install
+/// path (`"node_modules/foo"`, `"node_modules/a/node_modules/@s/b"`).
+/// The package name is the segment after the last `node_modules/`. A
+/// `"version"` line belongs to the nearest preceding key at a shallower
+/// indent. Entries without `node_modules/` (the root project, workspace
+/// members, and the legacy v1 `dependencies` map) are ignored.
+fn parse_package_lock_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+    let mut current: Option<(String, usize)> = None;
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+        let indent = indent_of(content);
+        let trimmed = content.trim();
+
+        // Object key: `"node_modules/foo": {`
+        if let Some(key) = trimmed
+            .strip_suffix('{')
+            .and_then(|k| k.trim_end().strip_suffix(':'))
+            .and_then(|k| k.trim().strip_prefix('"'))
+            .and_then(|k| k.strip_suffix('"'))
+        {
+            if current.as_ref().is_none_or(|(_, i)| indent <= *i) {
+                current = key
+                    .rfind("node_modules/")
+                    .map(|pos| (key[pos + "node_modules/".len()..].to_string(), indent));
+            }
+            continue;
+        }
+
+        if sign == ' ' {
+            continue;
+        }
+
+        if let Some((name, key_indent)) = &current
+            && indent > *key_indent
+            && let Some(version) = extract_json_string_value(trimmed, "version")
+        {
+            collector.record(sign, name, &version);
+        }
+    }
+
+    collector.finish()
+}
+
+/// Extract a JSON string value for a given key from a single line.
+///
+/// Matches lines like `"key": "value",` and returns `value`.
+fn extract_json_string_value(line: &str, key: &str) -> Option<String> {
+    let rest = line.trim().strip_prefix('"')?.strip_prefix(key)?;
+    let rest = rest.strip_prefix('"')?.trim_start().strip_prefix(':')?;
+    let rest = rest.trim().trim_end_matches(',').trim_end();
+    let value = rest.strip_prefix('"')?.strip_suffix('"')?;
+    Some(value.to_string())
+}
+
+/// Parse a unified diff of `pnpm-lock.yaml` into dependency changes.
+///
+/// pnpm encodes the resolved version in the package key itself, so only
+/// added and removed key lines under `packages:`/`snapshots:` matter:
+/// - v9: `  foo@1.0.0:`, `  '@scope/foo@1.0.0':`
+/// - v6: `  /foo@1.0.0:`, `  /foo@1.0.0(react@18.2.0):`
+/// - v5: `  /foo/1.0.0:`, `  /foo/1.0.0_react@18.2.0:`
+fn parse_pnpm_lock_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+        if sign == ' ' || indent_of(content) != 2 {
+            continue;
+        }
+        if let Some((name, version)) = parse_pnpm_key(content) {
+            collector.record(sign, &name, &version);
+        }
+    }
+
+    collector.finish()
+}
+
+/// Parse a pnpm package key into `(name, version)`.
+fn parse_pnpm_key(line: &str) -> Option<(String, String)> {
+    let key = line.trim().strip_suffix(':')?;
+    let key = key.trim_matches(|c| c == '\'' || c == '"');
+    let key = key.strip_prefix('/').unwrap_or(key);
+    // Drop peer-dependency suffix: `foo@1.0.0(react@18.2.0)`
+    let key = key.split('(').next()?;
+
+    let is_valid_name = |name: &str| {
+        !name.is_empty()
+            && if name.starts_with('@') {
+                name.matches('/').count() == 1
+            } else {
+                !name.contains('/')
+            }
+    };
+    let is_version = |v: &str| v.starts_with(|c: char| c.is_ascii_digit());
+
+    // v6/v9: `name@version`
+    if let Some(at) = key.get(1..).and_then(|k| k.find('@')).map(|i| i + 1) {
+        let (name, version) = (&key[..at], &key[at + 1..]);
+        if is_valid_name(name) && is_version(version) {
+            return Some((name.to_string(), version.to_string()));
+        }
+    }
+
+    // v5: `name/version[_peer]`
+    let parts: Vec<&str> = key.split('/').collect();
+    let (name, version) = match parts.as_slice() {
+        [scope, name, version, ..] if scope.starts_with('@') => {
+            (format!("{scope}/{name}"), *version)
+        }
+        [name, version, ..] if !name.starts_with('@') => ((*name).to_string(), *version),
+        _ => return None,
+    };
+    let version = version.split('_').next()?;
+    is_version(version).then(|| (name, version.to_string()))
+}
+
+/// Parse a unified diff of `yarn.lock` into dependency changes.
+///
+/// Entries start with an unindented header listing one or more
+/// descriptors (`"lodash@^4.17.20", lodash@^4.17.21:` in classic,
+/// `"lodash@npm:^4.17.21":` in berry), followed by an indented version
+/// line (`version "4.17.21"` in classic, `version: 4.17.21` in berry).
+/// Workspace entries (`@workspace:`) are ignored.
+fn parse_yarn_lock_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+    let mut current: Option<String> = None;
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+
+        if indent_of(content) == 0 {
+            current = parse_yarn_header(content);
+            continue;
+        }
+
+        if sign == ' ' {
+            continue;
+        }
+
+        if let Some(name) = &current
+            && let Some(version) = parse_yarn_version(content)
+        {
+            collector.record(sign, name, &version);
+        }
+    }
+
+    collector.finish()
+}
+
+/// Extract the package name from a yarn.lock entry header.
+fn parse_yarn_header(line: &str) -> Option<String> {
+    let header = line.trim_end().strip_suffix(':')?;
+    let descriptor = header.split(", ").next()?.trim_matches('"');
+    if descriptor.contains("@workspace:") {
+        return None;
+    }
+    let at = descriptor.get(1..)?.find('@')? + 1;
+    Some(descriptor[..at].to_string())
+}
+
+/// Extract the version from a yarn.lock `version` line (classic or berry).
+fn parse_yarn_version(line: &str) -> Option<String> {
+    let rest = line.trim().strip_prefix("version")?;
+    let rest = rest.strip_prefix(':').unwrap_or(rest).trim();
+    let version = rest.trim_matches('"');
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// Parse a unified diff of `requirements.txt` into dependency changes.
+///
+/// Only pinned requirements (`name==version`) carry a version; extras,
+/// environment markers, and `--hash` continuations are ignored. Names are
+/// lowercased, since pip treats them case-insensitively.
+fn parse_requirements_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+        if sign == ' ' {
+            continue;
+        }
+        if let Some((name, version)) = parse_pinned_requirement(content) {
+            collector.record(sign, &name, &version);
+        }
+    }
+
+    collector.finish()
+}
+
+/// Extract `(name, version)` from a `name[extra]==version ; marker` line.
+fn parse_pinned_requirement(line: &str) -> Option<(String, String)> {
+    let requirement = line.split(['#', ';']).next()?.trim();
+    let requirement = requirement.trim_end_matches('\\').trim_end();
+    let (name, version) = requirement.split_once("==")?;
+    let name = name.split('[').next()?.trim();
+    let version = version.split_whitespace().next()?.trim_start_matches('=');
+    if name.is_empty() || name.starts_with('-') || version.is_empty() {
+        return None;
+    }
+    Some((name.to_ascii_lowercase(), version.to_string()))
+}
+
+/// Parse a unified diff of `go.sum` into dependency changes.
+///
+/// Each module version has a line for its source tree and one for its
+/// `go.mod` (`example.com/m v1.2.3/go.mod h1:...`); both map to the same
+/// version. The leading `v` is dropped to match other ecosystems.
+fn parse_go_sum_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+        if sign == ' ' {
+            continue;
+        }
+        let mut fields = content.split_whitespace();
+        if let (Some(module), Some(version)) = (fields.next(), fields.next()) {
+            let version = version.trim_end_matches("/go.mod");
+            collector.record(sign, module, version.strip_prefix('v').unwrap_or(version));
+        }
+    }
+
+    collector.finish()
+}
+
+/// Parse a unified diff of `gradle.lockfile` into dependency changes.
+///
+/// Each line locks one module as `group:artifact:version=configurations`;
+/// the `empty=` line and comments are skipped. The name is
+/// `group:artifact`.
+fn parse_gradle_lockfile_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+        if sign == ' ' || content.starts_with('#') {
+            continue;
+        }
+        let coordinates = content.split('=').next().unwrap_or_default().trim();
+        if let Some((module, version)) = coordinates.rsplit_once(':')
+            && module.contains(':')
+        {
+            collector.record(sign, module, version);
+        }
+    }
+
+    collector.finish()
+}
+
+/// Parse a unified diff of `Gemfile.lock` into dependency changes.
+///
+/// Resolved gems are the `name (version)` lines indented by four spaces
+/// under a `specs:` block; deeper lines are their requirements. A platform
+/// suffix stays part of the version (`1.15.5-x86_64-linux`).
+fn parse_gemfile_lock_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+        if sign == ' ' || indent_of(content) != 4 {
+            continue;
+        }
+        if let Some((name, version)) = content.trim().split_once(" (")
+            && let Some(version) = version.strip_suffix(')')
+            && version.starts_with(|c: char| c.is_ascii_digit())
+        {
+            collector.record(sign, name, version);
+        }
+    }
+
+    collector.finish()
+}
+
+/// Parse a unified diff of `composer.lock` into dependency changes.
+///
+/// Each entry of `packages`/`packages-dev` has a `"name"` line shortly
+/// before its `"version"`; a changed version is paired with the nearest
+/// preceding name, whether that line changed or is context. The leading
+/// `v` of tag-style versions is dropped to match other ecosystems.
+fn parse_composer_lock_diff(diff: &str) -> Vec<DepChange> {
+    let mut collector = DeltaCollector::default();
+    let mut current: Option<String> = None;
+
+    for line in diff.lines() {
+        let Some((sign, content)) = split_diff_line(line) else {
+            continue;
+        };
+        if let Some(name) = extract_json_string_value(content, "name") {
+            current = Some(name);
+            continue;
+        }
+        if sign != ' '
+            && let Some(name) = &current
+            && let Some(version) = extract_json_string_value(content, "version")
+        {
+            collector.record(sign, name, version.strip_prefix('v').unwrap_or(&version));
+        }
+    }
+
+    collector.finish()
 }
 
 #[cfg(test)]
@@ -279,10 +724,322 @@ mod tests {
     }
 
     #[test]
-    fn compute_deps_node_returns_empty() {
-        // Node ecosystem stub always returns empty
-        let changes = parse_package_lock_diff("some diff content");
-        assert!(changes.is_empty());
+    fn parse_package_lock_diff_update_add_remove() {
+        let diff = r#"diff --git a/package-lock.json b/package-lock.json
+--- a/package-lock.json
++++ b/package-lock.json
+@@ -1,7 +1,7 @@
+ {
+   "name": "demo",
+-  "version": "1.0.0",
++  "version": "1.1.0",
+   "lockfileVersion": 3,
+@@ -20,9 +20,9 @@
+     "node_modules/left-pad": {
+-      "version": "1.2.0",
+-      "resolved": "https://registry.npmjs.org/left-pad/-/left-pad-1.2.0.tgz",
++      "version": "1.3.0",
++      "resolved": "https://registry.npmjs.org/left-pad/-/left-pad-1.3.0.tgz",
+       "license": "WTFPL"
+     },
++    "node_modules/@scope/new-dep": {
++      "version": "0.1.0",
++      "dependencies": {
++        "left-pad": "^1.3.0"
++      }
++    },
+-    "node_modules/old/node_modules/gone": {
+-      "version": "2.0.0"
+-    },
+     "packages/workspace-member": {
+-      "version": "0.0.1"
++      "version": "0.0.2"
+     }
+"#;
+        let changes = parse_package_lock_diff(diff);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].name, "@scope/new-dep");
+        assert_eq!(changes[0].from, None);
+        assert_eq!(changes[0].to.as_deref(), Some("0.1.0"));
+        assert_eq!(changes[1].name, "gone");
+        assert_eq!(changes[1].from.as_deref(), Some("2.0.0"));
+        assert_eq!(changes[1].to, None);
+        assert_eq!(changes[2].name, "left-pad");
+        assert_eq!(changes[2].from.as_deref(), Some("1.2.0"));
+        assert_eq!(changes[2].to.as_deref(), Some("1.3.0"));
+    }
+
+    #[test]
+    fn parse_package_lock_diff_dedupes_hoisted_copies() {
+        let diff = r#"
+     "node_modules/a/node_modules/ms": {
+-      "version": "2.0.0"
++      "version": "2.1.3"
+     },
+     "node_modules/b/node_modules/ms": {
+-      "version": "2.0.0"
++      "version": "2.1.3"
+     },
+"#;
+        let changes = parse_package_lock_diff(diff);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].name, "ms");
+    }
+
+    #[test]
+    fn parse_pnpm_lock_diff_v9() {
+        let diff = r#"
+ packages:
+ 
+-  '@types/node@20.1.0':
++  '@types/node@20.2.0':
+     resolution: {integrity: sha512-abc}
+ 
+-  react-dom@18.2.0(react@18.2.0):
++  react-dom@18.3.1(react@18.3.1):
+     dependencies:
++  zod@3.23.0:
++    resolution: {integrity: sha512-def}
+ 
+ snapshots:
+ 
+-  '@types/node@20.1.0': {}
++  '@types/node@20.2.0': {}
+ importers:
+   .:
+     dependencies:
+       react:
+-        version: 18.2.0
++        version: 18.3.1
+"#;
+        let changes = parse_pnpm_lock_diff(diff);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].name, "@types/node");
+        assert_eq!(changes[0].from.as_deref(), Some("20.1.0"));
+        assert_eq!(changes[0].to.as_deref(), Some("20.2.0"));
+        assert_eq!(changes[1].name, "react-dom");
+        assert_eq!(changes[1].to.as_deref(), Some("18.3.1"));
+        assert_eq!(changes[2].name, "zod");
+        assert_eq!(changes[2].from, None);
+    }
+
+    #[test]
+    fn parse_pnpm_key_formats() {
+        let parse = |s: &str| parse_pnpm_key(s).map(|(n, v)| format!("{n} {v}"));
+        assert_eq!(parse("  /foo/1.0.0:").as_deref(), Some("foo 1.0.0"));
+        assert_eq!(
+            parse("  /@scope/foo/1.0.0_react@18.2.0:").as_deref(),
+            Some("@scope/foo 1.0.0")
+        );
+        assert_eq!(parse("  /foo@1.0.0:").as_deref(), Some("foo 1.0.0"));
+        assert_eq!(
+            parse("  '@scope/foo@1.0.0(bar@2.0.0)':").as_deref(),
+            Some("@scope/foo 1.0.0")
+        );
+        assert_eq!(parse("  .:"), None);
+        assert_eq!(parse("  packages/app:"), None);
+        assert_eq!(parse("  foo@link:../foo:"), None);
+    }
+
+    #[test]
+    fn parse_yarn_lock_diff_classic() {
+        let diff = r#"
+-lodash@^4.17.20:
+-  version "4.17.20"
++lodash@^4.17.20, lodash@^4.17.21:
++  version "4.17.21"
+   resolved "https://registry.yarnpkg.com/lodash/-/lodash-4.17.21.tgz"
+ 
+ "@babel/core@^7.0.0":
+-  version "7.22.0"
++  version "7.23.0"
++
++chalk@^5.0.0:
++  version "5.3.0"
+"#;
+        let changes = parse_yarn_lock_diff(diff);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].name, "@babel/core");
+        assert_eq!(changes[0].from.as_deref(), Some("7.22.0"));
+        assert_eq!(changes[0].to.as_deref(), Some("7.23.0"));
+        assert_eq!(changes[1].name, "chalk");
+        assert_eq!(changes[1].from, None);
+        assert_eq!(changes[2].name, "lodash");
+        assert_eq!(changes[2].to.as_deref(), Some("4.17.21"));
+    }
+
+    #[test]
+    fn parse_yarn_lock_diff_berry() {
+        let diff = r#"
+ "lodash@npm:^4.17.21":
+-  version: 4.17.20
+-  resolution: "lodash@npm:4.17.20"
++  version: 4.17.21
++  resolution: "lodash@npm:4.17.21"
+ 
+ "demo@workspace:.":
+-  version: 0.0.0-use.local
++  version: 0.0.1-use.local
+"#;
+        let changes = parse_yarn_lock_diff(diff);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].name, "lodash");
+        assert_eq!(changes[0].from.as_deref(), Some("4.17.20"));
+        assert_eq!(changes[0].to.as_deref(), Some("4.17.21"));
+    }
+
+    #[test]
+    fn delta_collector_pairs_multiple_versions() {
+        let mut collector = DeltaCollector::default();
+        collector.record('-', "ms", "2.0.0");
+        collector.record('-', "ms", "2.1.2");
+        collector.record('+', "ms", "2.1.3");
+        collector.record('-', "same", "1.0.0");
+        collector.record('+', "same", "1.0.0");
+        let changes = collector.finish();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].from.as_deref(), Some("2.0.0"));
+        assert_eq!(changes[0].to.as_deref(), Some("2.1.3"));
+        assert_eq!(changes[1].from.as_deref(), Some("2.1.2"));
+        assert_eq!(changes[1].to, None);
+    }
+
+    #[test]
+    fn parse_uv_lock_diff() {
+        let diff = r#"@@ -10,7 +10,7 @@
+ [[package]]
+ name = "httpx"
+-version = "0.27.0"
++version = "0.28.1"
+ source = { registry = "https://pypi.org/simple" }
+ dependencies = [
+     { name = "anyio" },
+"#;
+        let changes = parse_lockfile_diff("uv.lock", diff);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].name, "httpx");
+        assert_eq!(changes[0].from.as_deref(), Some("0.27.0"));
+        assert_eq!(changes[0].to.as_deref(), Some("0.28.1"));
+    }
+
+    #[test]
+    fn parse_requirements_diff_pins() {
+        let diff = r#"--- a/requirements.txt
++++ b/requirements.txt
+@@ -1,4 +1,4 @@
+-Requests==2.31.0 \
++requests==2.32.3 \
+     --hash=sha256:abc
+ idna==3.7
+-uvicorn[standard]==0.29.0 ; python_version >= "3.8"
++black>=24.0
+"#;
+        let changes = parse_requirements_diff(diff);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].name, "requests");
+        assert_eq!(changes[0].from.as_deref(), Some("2.31.0"));
+        assert_eq!(changes[0].to.as_deref(), Some("2.32.3"));
+        assert_eq!(changes[1].name, "uvicorn");
+        assert_eq!(changes[1].from.as_deref(), Some("0.29.0"));
+        assert_eq!(changes[1].to, None);
+    }
+
+    #[test]
+    fn parse_go_sum_diff_update() {
+        let diff = r#"--- a/go.sum
++++ b/go.sum
+@@ -1,4 +1,6 @@
+-github.com/pkg/errors v0.9.0 h1:aaa=
+-github.com/pkg/errors v0.9.0/go.mod h1:bbb=
++github.com/pkg/errors v0.9.1 h1:ccc=
++github.com/pkg/errors v0.9.1/go.mod h1:ddd=
+ golang.org/x/sys v0.20.0 h1:eee=
++golang.org/x/text v0.15.0/go.mod h1:fff=
+"#;
+        let changes = parse_lockfile_diff("go.sum", diff);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].name, "github.com/pkg/errors");
+        assert_eq!(changes[0].from.as_deref(), Some("0.9.0"));
+        assert_eq!(changes[0].to.as_deref(), Some("0.9.1"));
+        assert_eq!(changes[1].name, "golang.org/x/text");
+        assert_eq!(changes[1].from, None);
+        assert_eq!(changes[1].to.as_deref(), Some("0.15.0"));
+    }
+
+    #[test]
+    fn parse_gradle_lockfile_diff_update() {
+        let diff = r#"--- a/gradle.lockfile
++++ b/gradle.lockfile
+@@ -1,6 +1,6 @@
+ # This is a Gradle generated file for dependency locking.
+-com.google.guava:guava:32.1.3-jre=compileClasspath,runtimeClasspath
++com.google.guava:guava:33.0.0-jre=compileClasspath,runtimeClasspath
++org.slf4j:slf4j-api:2.0.9=runtimeClasspath
+ junit:junit:4.13.2=testCompileClasspath
+-empty=annotationProcessor
++empty=
+"#;
+        let changes = parse_lockfile_diff("gradle.lockfile", diff);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].name, "com.google.guava:guava");
+        assert_eq!(changes[0].from.as_deref(), Some("32.1.3-jre"));
+        assert_eq!(changes[0].to.as_deref(), Some("33.0.0-jre"));
+        assert_eq!(changes[1].name, "org.slf4j:slf4j-api");
+        assert_eq!(changes[1].from, None);
+    }
+
+    #[test]
+    fn parse_gemfile_lock_diff_update() {
+        let diff = r#"--- a/Gemfile.lock
++++ b/Gemfile.lock
+@@ -4,9 +4,10 @@ GEM
+   specs:
+-    nokogiri (1.15.4-x86_64-linux)
++    nokogiri (1.15.5-x86_64-linux)
+       racc (~> 1.4)
+-    rack (2.2.8)
++    rack (3.0.8)
++    zeitwerk (2.6.12)
+ 
+ DEPENDENCIES
+-  rack (~> 2.2)
++  rack (~> 3.0)
+"#;
+        let changes = parse_lockfile_diff("Gemfile.lock", diff);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].name, "nokogiri");
+        assert_eq!(changes[0].to.as_deref(), Some("1.15.5-x86_64-linux"));
+        assert_eq!(changes[1].name, "rack");
+        assert_eq!(changes[1].from.as_deref(), Some("2.2.8"));
+        assert_eq!(changes[1].to.as_deref(), Some("3.0.8"));
+        assert_eq!(changes[2].name, "zeitwerk");
+        assert_eq!(changes[2].from, None);
+    }
+
+    #[test]
+    fn parse_composer_lock_diff_update() {
+        let diff = r#"--- a/composer.lock
++++ b/composer.lock
+@@ -10,12 +10,12 @@
+         {
+             "name": "symfony/console",
+-            "version": "v6.4.1",
++            "version": "v6.4.2",
+             "source": {
+@@ -40,6 +40,20 @@
++        {
++            "name": "psr/log",
++            "version": "3.0.0",
++            "source": {
+"#;
+        let changes = parse_lockfile_diff("composer.lock", diff);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].name, "psr/log");
+        assert_eq!(changes[0].from, None);
+        assert_eq!(changes[0].to.as_deref(), Some("3.0.0"));
+        assert_eq!(changes[1].name, "symfony/console");
+        assert_eq!(changes[1].from.as_deref(), Some("6.4.1"));
+        assert_eq!(changes[1].to.as_deref(), Some("6.4.2"));
     }
 
     #[test]
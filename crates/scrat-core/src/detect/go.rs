@@ -0,0 +1,20 @@
+//! Go ecosystem detection.
+//!
+//! Go modules are released by pushing a tag; the module proxy fetches the
+//! source on demand, so there is no publish step.
+
+use camino::Utf8Path;
+
+use super::detect_changelog_tool;
+use crate::ecosystem::DetectedTools;
+
+/// Detect Go module tooling and its default commands.
+pub fn detect_go(project_root: &Utf8Path) -> DetectedTools {
+    DetectedTools {
+        test_cmd: "go test ./...".into(),
+        build_cmd: "go build ./...".into(),
+        publish_cmd: None,
+        bump_cmd: None,
+        changelog_tool: detect_changelog_tool(project_root),
+    }
+}
@@ -0,0 +1,58 @@
+//! Gradle ecosystem detection.
+//!
+//! Prefers the Gradle wrapper (`./gradlew`) checked into the project, so
+//! the release uses the Gradle version the project pins.
+
+use camino::Utf8Path;
+use tracing::debug;
+
+use super::detect_changelog_tool;
+use crate::ecosystem::DetectedTools;
+
+/// Files whose presence marks a Gradle build, in probe order.
+pub const MARKERS: &[&str] = &[
+    "settings.gradle.kts",
+    "settings.gradle",
+    "build.gradle.kts",
+    "build.gradle",
+];
+
+/// Detect Gradle tooling and its default commands.
+pub fn detect_gradle(project_root: &Utf8Path) -> DetectedTools {
+    let gradle = if project_root.join("gradlew").is_file() {
+        "./gradlew"
+    } else {
+        "gradle"
+    };
+    debug!(gradle, "detected Gradle launcher");
+
+    DetectedTools {
+        test_cmd: format!("{gradle} test"),
+        build_cmd: format!("{gradle} build"),
+        publish_cmd: Some(format!("{gradle} publish")),
+        bump_cmd: None,
+        changelog_tool: detect_changelog_tool(project_root),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    #[test]
+    fn prefers_wrapper() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(detect_gradle(utf8_tmp(&tmp)).build_cmd, "gradle build");
+
+        fs::write(tmp.path().join("gradlew"), "#!/bin/sh\n").unwrap();
+        let tools = detect_gradle(utf8_tmp(&tmp));
+        assert_eq!(tools.test_cmd, "./gradlew test");
+        assert_eq!(tools.publish_cmd.as_deref(), Some("./gradlew publish"));
+    }
+}
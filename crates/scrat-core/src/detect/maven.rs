@@ -0,0 +1,50 @@
+//! Maven ecosystem detection.
+//!
+//! Prefers the Maven wrapper (`./mvnw`) checked into the project, so the
+//! release uses the Maven version the project pins.
+
+use camino::Utf8Path;
+use tracing::debug;
+
+use super::detect_changelog_tool;
+use crate::ecosystem::DetectedTools;
+
+/// Detect Maven tooling and its default commands.
+pub fn detect_maven(project_root: &Utf8Path) -> DetectedTools {
+    let mvn = if project_root.join("mvnw").is_file() {
+        "./mvnw"
+    } else {
+        "mvn"
+    };
+    debug!(mvn, "detected Maven launcher");
+
+    DetectedTools {
+        test_cmd: format!("{mvn} test"),
+        build_cmd: format!("{mvn} package"),
+        publish_cmd: Some(format!("{mvn} deploy")),
+        bump_cmd: None,
+        changelog_tool: detect_changelog_tool(project_root),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    #[test]
+    fn prefers_wrapper() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(detect_maven(utf8_tmp(&tmp)).test_cmd, "mvn test");
+
+        fs::write(tmp.path().join("mvnw"), "#!/bin/sh\n").unwrap();
+        let tools = detect_maven(utf8_tmp(&tmp));
+        assert_eq!(tools.test_cmd, "./mvnw test");
+        assert_eq!(tools.publish_cmd.as_deref(), Some("./mvnw deploy"));
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Walks the working directory for marker files, probes `PATH` for available
 //! tools, and checks for conventional-commit configuration to assemble a
-//! [`ProjectDetection`](crate::ecosystem::ProjectDetection).
+//! [`ProjectDetection`].
 //!
 //! # Example
 //!
@@ -17,21 +17,33 @@
 //! }
 //! ```
 
-mod rust;
+pub(crate) mod go;
+pub(crate) mod gradle;
+pub(crate) mod maven;
+pub(crate) mod node;
+pub(crate) mod php;
+pub(crate) mod python;
+pub(crate) mod ruby;
+pub(crate) mod rust;
 
+use std::fmt;
 use std::process::Command;
 
 use camino::Utf8Path;
+use serde::Serialize;
 use tracing::{debug, instrument};
 
 use crate::ecosystem::{ChangelogTool, Ecosystem, ProjectDetection, VersionStrategy};
+use crate::package::PackageScope;
+use crate::provider;
 
 /// Resolve ecosystem detection, honoring config overrides.
 ///
 /// Priority:
 /// 1. `config.project.project_type` override → build detection for that ecosystem
 /// 2. Auto-detect via marker files → full ecosystem-specific detection
-/// 3. `None` → caller must prompt the user
+/// 3. `None` (nothing found, or several ecosystems match — see
+///    [`detect_ecosystems`]) → caller must prompt the user
 #[instrument(skip(config), fields(root = %project_root))]
 pub fn resolve_detection(
     project_root: &Utf8Path,
@@ -42,53 +54,105 @@ pub fn resolve_detection(
         && let Some(ecosystem) = project.project_type
     {
         debug!(%ecosystem, "using ecosystem from config override");
-        let version_strategy = detect_version_strategy(project_root);
-        let detection = match ecosystem {
-            Ecosystem::Rust => rust::detect_rust(project_root, version_strategy),
-            Ecosystem::Node => detect_node_stub(version_strategy),
-            Ecosystem::Generic => ProjectDetection::generic(version_strategy),
-        };
-        return Some(detection);
+        return Some(build_detection(project_root, ecosystem));
     }
 
     // Fall back to auto-detection
     detect_project(project_root)
 }
 
+/// Resolve detection for a monorepo package.
+///
+/// The ecosystem comes from the package's `type` override or the marker
+/// files in its directory, and the tools are detected there. A `cliff.toml`
+/// or `cog.toml` in the package directory takes precedence; otherwise the
+/// repository root's conventional-commit configuration applies.
+#[instrument(fields(root = %project_root, package = %package.name))]
+pub fn resolve_package_detection(
+    project_root: &Utf8Path,
+    package: &PackageScope,
+) -> Option<ProjectDetection> {
+    let package_root = package.root(project_root);
+    let ecosystem = package
+        .ecosystem
+        .or_else(|| detect_ecosystem(&package_root))?;
+    debug!(%ecosystem, "detected package ecosystem");
+
+    let mut detection = build_detection(&package_root, ecosystem);
+    if detect_changelog_tool(&package_root).is_none() {
+        detection.version_strategy = detect_version_strategy(project_root);
+        detection.tools.changelog_tool = detect_changelog_tool(project_root);
+    }
+    Some(detection)
+}
+
 /// Detect the project ecosystem and available tooling from `project_root`.
 ///
-/// Returns `None` if no recognized marker file is found.
+/// Returns `None` if no recognized marker file is found, or if markers for
+/// several ecosystems are (see [`detect_ecosystems`]).
 /// Prefer [`resolve_detection`] which also honors config overrides.
 #[instrument(fields(root = %project_root))]
 pub fn detect_project(project_root: &Utf8Path) -> Option<ProjectDetection> {
     let ecosystem = detect_ecosystem(project_root)?;
     debug!(%ecosystem, "detected ecosystem");
 
-    let version_strategy = detect_version_strategy(project_root);
-    debug!(%version_strategy, "detected version strategy");
-
-    let detection = match ecosystem {
-        Ecosystem::Rust => rust::detect_rust(project_root, version_strategy),
-        Ecosystem::Node => detect_node_stub(version_strategy),
-        Ecosystem::Generic => ProjectDetection::generic(version_strategy),
-    };
+    let detection = build_detection(project_root, ecosystem);
+    debug!(version_strategy = %detection.version_strategy, "detected version strategy");
 
     Some(detection)
 }
 
-/// Identify the ecosystem by scanning for marker files.
+/// An ecosystem found in a directory, with the files that gave it away.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EcosystemMatch {
+    /// The matching ecosystem.
+    pub ecosystem: Ecosystem,
+    /// Files that identify it (marker file first, then lockfiles).
+    pub evidence: Vec<String>,
+}
+
+impl fmt::Display for EcosystemMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.ecosystem, self.evidence.join(", "))
+    }
+}
+
+/// Find every ecosystem whose [`provider`] recognizes
+/// `project_root`, in detection order (registered providers first).
 ///
-/// Only checks [`Ecosystem::AUTO_DETECTABLE`] variants (those with marker
-/// files). [`Ecosystem::Generic`] is never auto-detected.
+/// [`Ecosystem::Generic`] is never auto-detected.
+pub fn detect_ecosystems(project_root: &Utf8Path) -> Vec<EcosystemMatch> {
+    provider::providers()
+        .into_iter()
+        .filter_map(|p| {
+            let evidence = p.evidence(project_root);
+            if evidence.is_empty() {
+                return None;
+            }
+            Some(EcosystemMatch {
+                ecosystem: Ecosystem::from_name(p.name())?,
+                evidence,
+            })
+        })
+        .collect()
+}
+
+/// Identify the ecosystem when exactly one matches.
+///
+/// Several matches are ambiguous: rather than guess, detection fails so the
+/// caller asks the user (or `project.type` decides).
 fn detect_ecosystem(project_root: &Utf8Path) -> Option<Ecosystem> {
-    for ecosystem in Ecosystem::AUTO_DETECTABLE {
-        if let Some(marker) = ecosystem.marker_file()
-            && project_root.join(marker).is_file()
-        {
-            return Some(*ecosystem);
+    match detect_ecosystems(project_root).as_slice() {
+        [only] => Some(only.ecosystem),
+        [] => None,
+        several => {
+            debug!(
+                candidates = ?several.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                "several ecosystems detected"
+            );
+            None
         }
     }
-    None
 }
 
 /// Determine the version strategy from config files in the project root.
@@ -101,34 +165,28 @@ pub fn detect_version_strategy(project_root: &Utf8Path) -> VersionStrategy {
     if project_root.join("cliff.toml").is_file() {
         debug!("found cliff.toml");
         return VersionStrategy::ConventionalCommits {
-            tool: ChangelogTool::GitCliff,
+            tool: Some(ChangelogTool::GitCliff),
         };
     }
 
     if project_root.join("cog.toml").is_file() {
         debug!("found cog.toml");
         return VersionStrategy::ConventionalCommits {
-            tool: ChangelogTool::Cog,
+            tool: Some(ChangelogTool::Cog),
         };
     }
 
     VersionStrategy::Interactive
 }
 
-/// Stub detection for Node ecosystem (future implementation).
-fn detect_node_stub(version_strategy: VersionStrategy) -> ProjectDetection {
-    use crate::ecosystem::DetectedTools;
-
-    ProjectDetection {
-        ecosystem: Ecosystem::Node,
-        version_strategy,
-        tools: DetectedTools {
-            test_cmd: "npm test".into(),
-            build_cmd: "npm run build".into(),
-            publish_cmd: Some("npm publish".into()),
-            bump_cmd: Some("npm version --no-git-tag-version".into()),
-            changelog_tool: None,
-        },
+/// Check which changelog tool is configured for this project.
+pub fn detect_changelog_tool(project_root: &Utf8Path) -> Option<ChangelogTool> {
+    if project_root.join("cliff.toml").is_file() {
+        Some(ChangelogTool::GitCliff)
+    } else if project_root.join("cog.toml").is_file() {
+        Some(ChangelogTool::Cog)
+    } else {
+        None
     }
 }
 
@@ -137,14 +195,17 @@ fn detect_node_stub(version_strategy: VersionStrategy) -> ProjectDetection {
 /// Called after the CLI prompts the user to choose an ecosystem when
 /// auto-detection returns `None`.
 pub fn build_detection(project_root: &Utf8Path, ecosystem: Ecosystem) -> ProjectDetection {
-    let version_strategy = detect_version_strategy(project_root);
-    match ecosystem {
-        Ecosystem::Rust => rust::detect_rust(project_root, version_strategy),
-        Ecosystem::Node => detect_node_stub(version_strategy),
-        Ecosystem::Generic => ProjectDetection::generic(version_strategy),
+    ProjectDetection {
+        ecosystem,
+        version_strategy: detect_version_strategy(project_root),
+        tools: ecosystem.provider().default_tools(project_root),
     }
 }
 
+/// Shell prelude for publish commands that build into a temporary `$out`
+/// directory (removed on exit), so nothing in the project tree is touched.
+pub(crate) const SCRATCH_DIR: &str = r#"out="$(mktemp -d)" && trap 'rm -rf "$out"' EXIT"#;
+
 /// Check whether a binary is available on `PATH`.
 pub fn has_binary(name: &str) -> bool {
     which::which(name).is_ok()
@@ -252,6 +313,26 @@ mod tests {
         assert_eq!(det.ecosystem, Ecosystem::Node);
     }
 
+    #[test]
+    fn detect_python_ecosystem() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("pyproject.toml"), "[project]").unwrap();
+
+        let det = detect_project(utf8_tmp(&tmp)).unwrap();
+        assert_eq!(det.ecosystem, Ecosystem::Python);
+    }
+
+    #[test]
+    fn detect_go_ecosystem() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("go.mod"), "module example.com/foo\n").unwrap();
+
+        let det = detect_project(utf8_tmp(&tmp)).unwrap();
+        assert_eq!(det.ecosystem, Ecosystem::Go);
+        assert_eq!(det.tools.test_cmd, "go test ./...");
+        assert!(det.tools.publish_cmd.is_none());
+    }
+
     #[test]
     fn detect_unknown_ecosystem() {
         let tmp = TempDir::new().unwrap();
@@ -261,13 +342,69 @@ mod tests {
     }
 
     #[test]
-    fn rust_takes_priority_over_node() {
+    fn several_ecosystems_are_ambiguous() {
         let tmp = TempDir::new().unwrap();
         fs::write(tmp.path().join("Cargo.toml"), "[package]").unwrap();
+        fs::write(tmp.path().join("Cargo.lock"), "").unwrap();
         fs::write(tmp.path().join("package.json"), "{}").unwrap();
 
-        let det = detect_project(utf8_tmp(&tmp)).unwrap();
-        assert_eq!(det.ecosystem, Ecosystem::Rust);
+        let matches = detect_ecosystems(utf8_tmp(&tmp));
+        assert_eq!(
+            matches,
+            vec![
+                EcosystemMatch {
+                    ecosystem: Ecosystem::Rust,
+                    evidence: vec!["Cargo.toml".into(), "Cargo.lock".into()],
+                },
+                EcosystemMatch {
+                    ecosystem: Ecosystem::Node,
+                    evidence: vec!["package.json".into()],
+                },
+            ]
+        );
+        assert_eq!(matches[0].to_string(), "rust (Cargo.toml, Cargo.lock)");
+        assert!(detect_project(utf8_tmp(&tmp)).is_none());
+
+        // `project.type` still decides
+        let config = crate::config::Config {
+            project: Some(crate::config::ProjectConfig {
+                project_type: Some(Ecosystem::Node),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let det = resolve_detection(utf8_tmp(&tmp), &config).unwrap();
+        assert_eq!(det.ecosystem, Ecosystem::Node);
+    }
+
+    #[test]
+    fn package_detection_uses_package_dir_and_root_cliff() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("cliff.toml"), "").unwrap();
+        fs::create_dir_all(tmp.path().join("packages/web")).unwrap();
+        fs::write(tmp.path().join("packages/web/package.json"), "{}").unwrap();
+
+        let package = PackageScope {
+            name: "web".into(),
+            path: "packages/web".into(),
+            ecosystem: None,
+            tag_prefix: "web-v".into(),
+        };
+        let det = resolve_package_detection(utf8_tmp(&tmp), &package).unwrap();
+        assert_eq!(det.ecosystem, Ecosystem::Node);
+        assert_eq!(det.tools.changelog_tool, Some(ChangelogTool::GitCliff));
+        assert!(matches!(
+            det.version_strategy,
+            VersionStrategy::ConventionalCommits {
+                tool: Some(ChangelogTool::GitCliff)
+            }
+        ));
+
+        let missing = PackageScope {
+            path: "packages/missing".into(),
+            ..package
+        };
+        assert!(resolve_package_detection(utf8_tmp(&tmp), &missing).is_none());
     }
 
     #[test]
@@ -280,7 +417,7 @@ mod tests {
         assert_eq!(
             det.version_strategy,
             VersionStrategy::ConventionalCommits {
-                tool: ChangelogTool::GitCliff
+                tool: Some(ChangelogTool::GitCliff)
             }
         );
     }
@@ -295,7 +432,7 @@ mod tests {
         assert_eq!(
             det.version_strategy,
             VersionStrategy::ConventionalCommits {
-                tool: ChangelogTool::Cog
+                tool: Some(ChangelogTool::Cog)
             }
         );
     }
@@ -311,7 +448,7 @@ mod tests {
         assert!(matches!(
             det.version_strategy,
             VersionStrategy::ConventionalCommits {
-                tool: ChangelogTool::GitCliff
+                tool: Some(ChangelogTool::GitCliff)
             }
         ));
     }
@@ -361,4 +498,32 @@ mod tests {
         let det = detect_project(utf8_tmp(&tmp)).unwrap();
         assert_eq!(det.version_strategy, VersionStrategy::Interactive);
     }
+
+    #[test]
+    fn changelog_tool_cliff() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("cliff.toml"), "").unwrap();
+
+        assert_eq!(
+            detect_changelog_tool(utf8_tmp(&tmp)),
+            Some(ChangelogTool::GitCliff)
+        );
+    }
+
+    #[test]
+    fn changelog_tool_cog() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("cog.toml"), "").unwrap();
+
+        assert_eq!(
+            detect_changelog_tool(utf8_tmp(&tmp)),
+            Some(ChangelogTool::Cog)
+        );
+    }
+
+    #[test]
+    fn no_changelog_tool() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(detect_changelog_tool(utf8_tmp(&tmp)), None);
+    }
 }
@@ -0,0 +1,217 @@
+//! Node.js ecosystem detection.
+//!
+//! Picks the package manager from the lockfile present and derives
+//! test/build/publish commands from the `scripts` in `package.json`.
+
+use camino::Utf8Path;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use tracing::debug;
+
+use super::detect_changelog_tool;
+use crate::ecosystem::{DetectedTools, PackageManager};
+
+/// Placeholder test script written by `npm init`.
+const NPM_INIT_TEST_SCRIPT: &str = "echo \"Error: no test specified\" && exit 1";
+
+/// The subset of `package.json` needed for detection.
+#[derive(Debug, Default, Deserialize)]
+struct PackageJson {
+    #[serde(default)]
+    private: bool,
+    #[serde(default)]
+    scripts: BTreeMap<String, String>,
+    #[serde(default, rename = "packageManager")]
+    package_manager: Option<String>,
+}
+
+/// Detect Node.js project tooling and its default commands.
+pub fn detect_node(project_root: &Utf8Path) -> DetectedTools {
+    let manifest = read_package_json(project_root);
+    let pm = detect_package_manager(project_root, &manifest);
+    debug!(%pm, "detected package manager");
+
+    let test_cmd = match manifest.scripts.get("test") {
+        Some(script) if script.trim() != NPM_INIT_TEST_SCRIPT => run_script(pm, "test"),
+        _ => String::new(),
+    };
+
+    let build_cmd = if manifest.scripts.contains_key("build") {
+        run_script(pm, "build")
+    } else {
+        String::new()
+    };
+
+    let publish_cmd = if manifest.private {
+        None
+    } else {
+        Some(publish_command(project_root, pm))
+    };
+
+    DetectedTools {
+        test_cmd,
+        build_cmd,
+        publish_cmd,
+        bump_cmd: None,
+        changelog_tool: detect_changelog_tool(project_root),
+    }
+}
+
+/// Read `package.json`, falling back to defaults if missing or malformed.
+fn read_package_json(project_root: &Utf8Path) -> PackageJson {
+    std::fs::read_to_string(project_root.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Pick the package manager from the lockfile present.
+///
+/// Falls back to the `packageManager` field (corepack), then npm.
+fn detect_package_manager(project_root: &Utf8Path, manifest: &PackageJson) -> PackageManager {
+    for pm in PackageManager::ALL {
+        if pm
+            .lockfiles()
+            .iter()
+            .any(|lockfile| project_root.join(lockfile).is_file())
+        {
+            return *pm;
+        }
+    }
+
+    manifest
+        .package_manager
+        .as_deref()
+        .and_then(|spec| {
+            let name = spec.split('@').next().unwrap_or(spec);
+            PackageManager::ALL
+                .iter()
+                .find(|pm| pm.to_string() == name)
+                .copied()
+        })
+        .unwrap_or(PackageManager::Npm)
+}
+
+/// Command that runs a `package.json` script.
+fn run_script(pm: PackageManager, script: &str) -> String {
+    match (pm, script) {
+        // `bun test` is Bun's built-in runner, not the `test` script
+        (PackageManager::Bun, _) => format!("bun run {script}"),
+        (_, "test") => format!("{pm} test"),
+        _ => format!("{pm} run {script}"),
+    }
+}
+
+/// Registry publish command for the package manager.
+fn publish_command(project_root: &Utf8Path, pm: PackageManager) -> String {
+    match pm {
+        // Yarn Berry moved publishing under `yarn npm`
+        PackageManager::Yarn if project_root.join(".yarnrc.yml").is_file() => {
+            "yarn npm publish".into()
+        }
+        _ => format!("{pm} publish"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn write_package(tmp: &TempDir, json: &str) {
+        fs::write(tmp.path().join("package.json"), json).unwrap();
+    }
+
+    #[test]
+    fn npm_with_scripts() {
+        let tmp = TempDir::new().unwrap();
+        write_package(
+            &tmp,
+            r#"{"scripts": {"test": "vitest run", "build": "tsc"}}"#,
+        );
+        fs::write(tmp.path().join("package-lock.json"), "{}").unwrap();
+
+        let tools = detect_node(utf8_tmp(&tmp));
+        assert_eq!(tools.test_cmd, "npm test");
+        assert_eq!(tools.build_cmd, "npm run build");
+        assert_eq!(tools.publish_cmd.as_deref(), Some("npm publish"));
+        assert!(tools.bump_cmd.is_none());
+    }
+
+    #[test]
+    fn missing_scripts_leave_commands_empty() {
+        let tmp = TempDir::new().unwrap();
+        write_package(
+            &tmp,
+            r#"{"scripts": {"test": "echo \"Error: no test specified\" && exit 1"}}"#,
+        );
+
+        let tools = detect_node(utf8_tmp(&tmp));
+        assert!(tools.test_cmd.is_empty());
+        assert!(tools.build_cmd.is_empty());
+    }
+
+    #[test]
+    fn private_package_has_no_publish() {
+        let tmp = TempDir::new().unwrap();
+        write_package(&tmp, r#"{"private": true}"#);
+
+        let tools = detect_node(utf8_tmp(&tmp));
+        assert!(tools.publish_cmd.is_none());
+    }
+
+    #[test]
+    fn package_manager_from_lockfile() {
+        for (lockfile, expected) in [
+            ("pnpm-lock.yaml", PackageManager::Pnpm),
+            ("yarn.lock", PackageManager::Yarn),
+            ("bun.lockb", PackageManager::Bun),
+            ("package-lock.json", PackageManager::Npm),
+        ] {
+            let tmp = TempDir::new().unwrap();
+            fs::write(tmp.path().join(lockfile), "").unwrap();
+            let pm = detect_package_manager(utf8_tmp(&tmp), &PackageJson::default());
+            assert_eq!(pm, expected, "lockfile {lockfile}");
+        }
+    }
+
+    #[test]
+    fn package_manager_from_corepack_field() {
+        let tmp = TempDir::new().unwrap();
+        let manifest = PackageJson {
+            package_manager: Some("pnpm@9.1.0".into()),
+            ..PackageJson::default()
+        };
+        assert_eq!(
+            detect_package_manager(utf8_tmp(&tmp), &manifest),
+            PackageManager::Pnpm
+        );
+    }
+
+    #[test]
+    fn bun_runs_test_script() {
+        let tmp = TempDir::new().unwrap();
+        write_package(&tmp, r#"{"scripts": {"test": "jest"}}"#);
+        fs::write(tmp.path().join("bun.lock"), "").unwrap();
+
+        let tools = detect_node(utf8_tmp(&tmp));
+        assert_eq!(tools.test_cmd, "bun run test");
+        assert_eq!(tools.publish_cmd.as_deref(), Some("bun publish"));
+    }
+
+    #[test]
+    fn yarn_berry_publish() {
+        let tmp = TempDir::new().unwrap();
+        write_package(&tmp, "{}");
+        fs::write(tmp.path().join("yarn.lock"), "").unwrap();
+        fs::write(tmp.path().join(".yarnrc.yml"), "").unwrap();
+
+        let tools = detect_node(utf8_tmp(&tmp));
+        assert_eq!(tools.publish_cmd.as_deref(), Some("yarn npm publish"));
+    }
+}
@@ -0,0 +1,20 @@
+//! PHP Composer ecosystem detection.
+//!
+//! Composer packages are released by pushing a tag; Packagist picks it up
+//! from the repository, so there is no publish step.
+
+use camino::Utf8Path;
+
+use super::detect_changelog_tool;
+use crate::ecosystem::DetectedTools;
+
+/// Detect Composer tooling and its default commands.
+pub fn detect_php(project_root: &Utf8Path) -> DetectedTools {
+    DetectedTools {
+        test_cmd: "composer test".into(),
+        build_cmd: "composer validate --strict".into(),
+        publish_cmd: None,
+        bump_cmd: None,
+        changelog_tool: detect_changelog_tool(project_root),
+    }
+}
@@ -0,0 +1,145 @@
+//! Python ecosystem detection.
+//!
+//! Prefers [uv](https://docs.astral.sh/uv/) when it is on `PATH` or the
+//! project has a `uv.lock`, then Poetry for projects with a `poetry.lock`,
+//! and otherwise falls back to `python -m build` plus `twine`.
+
+use camino::Utf8Path;
+use toml_edit::{DocumentMut, Item};
+use tracing::debug;
+
+use super::{SCRATCH_DIR, detect_changelog_tool, has_binary};
+use crate::ecosystem::DetectedTools;
+
+/// Trove classifier that makes PyPI reject uploads.
+const PRIVATE_CLASSIFIER: &str = "Private :: Do Not Upload";
+
+/// Tool that builds and uploads the distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frontend {
+    Uv,
+    Poetry,
+    Pip,
+}
+
+/// Detect Python project tooling and its default commands.
+pub fn detect_python(project_root: &Utf8Path) -> DetectedTools {
+    let frontend = detect_frontend(project_root, has_binary("uv"));
+    debug!(?frontend, "detected Python frontend");
+
+    let (test_cmd, build_cmd, release_cmd) = match frontend {
+        Frontend::Uv => (
+            "uv run pytest",
+            "uv build",
+            r#"uv build -o "$out" && uv publish "$out"/*"#,
+        ),
+        Frontend::Poetry => (
+            "poetry run pytest",
+            "poetry build",
+            r#"poetry build --output "$out" && poetry publish --dist-dir "$out""#,
+        ),
+        Frontend::Pip => (
+            "pytest",
+            "python -m build",
+            r#"python -m build --outdir "$out" && twine upload "$out"/*"#,
+        ),
+    };
+
+    // Build into a fresh directory of scrat's own, so only this release's
+    // distributions are uploaded and the project's `dist/` is left alone
+    let publish_cmd =
+        (!is_private(project_root)).then(|| format!(r#"{SCRATCH_DIR} && {release_cmd}"#));
+
+    DetectedTools {
+        test_cmd: test_cmd.into(),
+        build_cmd: build_cmd.into(),
+        publish_cmd,
+        bump_cmd: None,
+        changelog_tool: detect_changelog_tool(project_root),
+    }
+}
+
+/// Pick the build frontend from the lockfile present and `PATH`.
+fn detect_frontend(project_root: &Utf8Path, has_uv: bool) -> Frontend {
+    if project_root.join("uv.lock").is_file() {
+        Frontend::Uv
+    } else if project_root.join("poetry.lock").is_file() {
+        Frontend::Poetry
+    } else if has_uv {
+        Frontend::Uv
+    } else {
+        Frontend::Pip
+    }
+}
+
+/// Whether `pyproject.toml` opts out of uploads with the private classifier.
+fn is_private(project_root: &Utf8Path) -> bool {
+    std::fs::read_to_string(project_root.join("pyproject.toml"))
+        .ok()
+        .and_then(|content| content.parse::<DocumentMut>().ok())
+        .is_some_and(|doc| {
+            doc.get("project")
+                .and_then(|p| p.get("classifiers"))
+                .and_then(Item::as_array)
+                .is_some_and(|classifiers| {
+                    classifiers
+                        .iter()
+                        .any(|c| c.as_str() == Some(PRIVATE_CLASSIFIER))
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn write_pyproject(tmp: &TempDir, toml: &str) {
+        fs::write(tmp.path().join("pyproject.toml"), toml).unwrap();
+    }
+
+    #[test]
+    fn uv_project() {
+        let tmp = TempDir::new().unwrap();
+        write_pyproject(&tmp, "[project]\nname = \"app\"\n");
+        fs::write(tmp.path().join("uv.lock"), "").unwrap();
+
+        let tools = detect_python(utf8_tmp(&tmp));
+        assert_eq!(tools.test_cmd, "uv run pytest");
+        assert_eq!(tools.build_cmd, "uv build");
+        assert_eq!(
+            tools.publish_cmd.as_deref(),
+            Some(
+                r#"out="$(mktemp -d)" && trap 'rm -rf "$out"' EXIT && uv build -o "$out" && uv publish "$out"/*"#
+            )
+        );
+        assert!(tools.bump_cmd.is_none());
+    }
+
+    #[test]
+    fn frontend_from_lockfile_and_path() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(detect_frontend(utf8_tmp(&tmp), false), Frontend::Pip);
+        assert_eq!(detect_frontend(utf8_tmp(&tmp), true), Frontend::Uv);
+
+        fs::write(tmp.path().join("poetry.lock"), "").unwrap();
+        assert_eq!(detect_frontend(utf8_tmp(&tmp), true), Frontend::Poetry);
+    }
+
+    #[test]
+    fn private_classifier_has_no_publish() {
+        let tmp = TempDir::new().unwrap();
+        write_pyproject(
+            &tmp,
+            "[project]\nname = \"app\"\nclassifiers = [\"Private :: Do Not Upload\"]\n",
+        );
+
+        let tools = detect_python(utf8_tmp(&tmp));
+        assert!(tools.publish_cmd.is_none());
+    }
+}
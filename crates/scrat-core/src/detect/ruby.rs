@@ -0,0 +1,68 @@
+//! Ruby gem detection.
+//!
+//! Builds the gem from its gemspec and pushes it to RubyGems. Tests run
+//! through Rake's default task, under Bundler when there is a `Gemfile`.
+
+use camino::Utf8Path;
+use tracing::debug;
+
+use super::{SCRATCH_DIR, detect_changelog_tool};
+use crate::ecosystem::DetectedTools;
+use crate::manifest;
+
+/// Detect Ruby gem tooling and its default commands.
+pub fn detect_ruby(project_root: &Utf8Path) -> DetectedTools {
+    let gemspec = manifest::ruby::gemspec(project_root)
+        .map_or_else(|| "*.gemspec".to_string(), |spec| spec.into_string());
+    let bundler = project_root.join("Gemfile").is_file();
+    debug!(%gemspec, bundler, "detected Ruby gem");
+
+    let test_cmd = if bundler { "bundle exec rake" } else { "rake" };
+    let build_cmd = format!("gem build {gemspec}");
+    // Build into a fresh directory and push exactly that gem, leaving any
+    // `.gem` files in the project alone
+    let gem = format!(
+        "\"$out/{}.gem\"",
+        manifest::ruby::gem_name(project_root)
+            .as_deref()
+            .unwrap_or("release")
+    );
+
+    DetectedTools {
+        test_cmd: test_cmd.into(),
+        publish_cmd: Some(format!(
+            "{SCRATCH_DIR} && {build_cmd} --output {gem} && gem push {gem}"
+        )),
+        build_cmd,
+        bump_cmd: None,
+        changelog_tool: detect_changelog_tool(project_root),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    #[test]
+    fn builds_from_gemspec() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("widgets.gemspec"), "").unwrap();
+        fs::write(tmp.path().join("Gemfile"), "").unwrap();
+
+        let tools = detect_ruby(utf8_tmp(&tmp));
+        assert_eq!(tools.test_cmd, "bundle exec rake");
+        assert_eq!(tools.build_cmd, "gem build widgets.gemspec");
+        assert_eq!(
+            tools.publish_cmd.as_deref(),
+            Some(
+                r#"out="$(mktemp -d)" && trap 'rm -rf "$out"' EXIT && gem build widgets.gemspec --output "$out/widgets.gem" && gem push "$out/widgets.gem""#
+            )
+        );
+    }
+}
@@ -1,20 +1,43 @@
 //! Ecosystem types and smart defaults for release workflows.
 //!
 //! This module defines the project ecosystem types (Rust, Node, etc.) and
-//! the associated tool/command defaults. Detection logic lives in the CLI
-//! crate (`detect` module) — this module is pure types and data.
+//! the associated tool/command defaults. Per-ecosystem behavior lives in
+//! [`provider`]; detection logic in [`detect`](crate::detect).
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
+use crate::provider::{self, EcosystemProvider};
+use crate::version::calver::CalVerFormat;
+
 /// A recognized project ecosystem.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// Serialized by [name](Self::name), so config and JSON output use
+/// `"rust"`, `"node"`, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Ecosystem {
     /// Rust project (detected via `Cargo.toml`).
     Rust,
     /// Node.js project (detected via `package.json`).
     Node,
+    /// Python project (detected via `pyproject.toml`).
+    Python,
+    /// Go module (detected via `go.mod`).
+    ///
+    /// Go modules are versioned by tags alone: there is no version field
+    /// to rewrite and no registry upload.
+    Go,
+    /// Maven project (detected via `pom.xml`), including multi-module builds.
+    Maven,
+    /// Gradle project (detected via `settings.gradle(.kts)` or
+    /// `build.gradle(.kts)`).
+    Gradle,
+    /// Ruby gem (detected via a `*.gemspec`).
+    Ruby,
+    /// PHP Composer package (detected via `composer.json`).
+    ///
+    /// Packagist reads versions from tags, so there is no registry upload.
+    Php,
     /// Generic project — no ecosystem-specific behavior.
     ///
     /// Selected interactively when auto-detection finds no marker files,
@@ -22,72 +45,203 @@ pub enum Ecosystem {
     /// rewrite, deps diff, and registry publish; still does changelog,
     /// git commit/tag/push, GitHub release, and hooks.
     Generic,
+    /// An ecosystem supplied by a [registered](crate::provider::register)
+    /// provider, identified by its name.
+    Custom(&'static str),
 }
 
 impl fmt::Display for Ecosystem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Rust => write!(f, "rust"),
-            Self::Node => write!(f, "node"),
-            Self::Generic => write!(f, "generic"),
-        }
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Serialize for Ecosystem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Ecosystem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::from_name(&name).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "unknown ecosystem `{name}`, expected one of {}",
+                Self::ALL
+                    .iter()
+                    .map(|e| format!("`{e}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        })
     }
 }
 
 impl Ecosystem {
-    /// Filename that signals this ecosystem when found in a directory.
-    ///
-    /// Returns `None` for [`Generic`](Self::Generic) which has no marker file.
-    pub const fn marker_file(self) -> Option<&'static str> {
+    /// Name used in config (`project.type`) and output.
+    pub const fn name(self) -> &'static str {
         match self {
-            Self::Rust => Some("Cargo.toml"),
-            Self::Node => Some("package.json"),
-            Self::Generic => None,
+            Self::Rust => "rust",
+            Self::Node => "node",
+            Self::Python => "python",
+            Self::Go => "go",
+            Self::Maven => "maven",
+            Self::Gradle => "gradle",
+            Self::Ruby => "ruby",
+            Self::Php => "php",
+            Self::Generic => "generic",
+            Self::Custom(name) => name,
         }
     }
 
+    /// Look up an ecosystem by name: a built-in one, or one supplied by a
+    /// registered provider.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|e| e.name() == name)
+            .or_else(|| {
+                provider::registered()
+                    .into_iter()
+                    .find(|p| p.name() == name)
+                    .map(|p| Self::Custom(p.name()))
+            })
+    }
+
+    /// The provider implementing this ecosystem's behavior.
+    pub fn provider(self) -> &'static dyn EcosystemProvider {
+        provider::get(self)
+    }
+
+    /// Filename that signals this ecosystem when found in a directory.
+    ///
+    /// Returns `None` for [`Generic`](Self::Generic), which has no marker
+    /// file, for [`Ruby`](Self::Ruby), whose gemspec is named after the
+    /// gem, and for [`Gradle`](Self::Gradle), which has several.
+    pub fn marker_file(self) -> Option<&'static str> {
+        self.provider().marker_file()
+    }
+
     /// Primary lockfile for this ecosystem, relative to project root.
     ///
     /// Returns `None` for [`Generic`](Self::Generic) which has no lockfile.
-    pub const fn lockfile_path(self) -> Option<&'static str> {
-        match self {
-            Self::Rust => Some("Cargo.lock"),
-            Self::Node => Some("package-lock.json"),
-            Self::Generic => None,
-        }
+    pub fn lockfile_path(self) -> Option<&'static str> {
+        self.lockfile_paths().first().copied()
     }
 
-    /// Ecosystems that can be auto-detected via marker files.
+    /// Lockfiles with parseable dependency diffs, in probe order.
+    ///
+    /// Node and Python projects may use any of several package managers, so
+    /// more than one candidate is listed; the first one that changed is used.
+    pub fn lockfile_paths(self) -> &'static [&'static str] {
+        self.provider().lockfiles()
+    }
+
+    /// Built-in ecosystems that can be auto-detected via marker files.
     ///
     /// [`Generic`](Self::Generic) is excluded — it is only selected
     /// interactively or via config override.
-    pub const AUTO_DETECTABLE: &[Self] = &[Self::Rust, Self::Node];
+    pub const AUTO_DETECTABLE: &[Self] = &[
+        Self::Rust,
+        Self::Node,
+        Self::Python,
+        Self::Go,
+        Self::Maven,
+        Self::Gradle,
+        Self::Ruby,
+        Self::Php,
+    ];
 
-    /// All ecosystem variants, including [`Generic`](Self::Generic).
-    pub const ALL: &[Self] = &[Self::Rust, Self::Node, Self::Generic];
+    /// All built-in ecosystems, including [`Generic`](Self::Generic).
+    pub const ALL: &[Self] = &[
+        Self::Rust,
+        Self::Node,
+        Self::Python,
+        Self::Go,
+        Self::Maven,
+        Self::Gradle,
+        Self::Ruby,
+        Self::Php,
+        Self::Generic,
+    ];
+}
+
+/// Node.js package manager, selected by the lockfile in the project root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageManager {
+    /// [npm](https://docs.npmjs.com/) (`package-lock.json`).
+    Npm,
+    /// [pnpm](https://pnpm.io/) (`pnpm-lock.yaml`).
+    Pnpm,
+    /// [Yarn](https://yarnpkg.com/) (`yarn.lock`).
+    Yarn,
+    /// [Bun](https://bun.sh/) (`bun.lock` or `bun.lockb`).
+    Bun,
+}
+
+impl fmt::Display for PackageManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Npm => write!(f, "npm"),
+            Self::Pnpm => write!(f, "pnpm"),
+            Self::Yarn => write!(f, "yarn"),
+            Self::Bun => write!(f, "bun"),
+        }
+    }
+}
+
+impl PackageManager {
+    /// Lockfiles written by this package manager, relative to project root.
+    pub const fn lockfiles(self) -> &'static [&'static str] {
+        match self {
+            Self::Npm => &["package-lock.json", "npm-shrinkwrap.json"],
+            Self::Pnpm => &["pnpm-lock.yaml"],
+            Self::Yarn => &["yarn.lock"],
+            Self::Bun => &["bun.lock", "bun.lockb"],
+        }
+    }
+
+    /// All package managers, in lockfile probe order.
+    pub const ALL: &[Self] = &[Self::Pnpm, Self::Yarn, Self::Bun, Self::Npm];
 }
 
 /// Version-determination strategy.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum VersionStrategy {
-    /// Auto-compute from conventional commits via a changelog tool.
+    /// Auto-compute from conventional commits.
     ConventionalCommits {
-        /// Which tool drives the CC analysis.
-        tool: ChangelogTool,
+        /// Which tool drives the CC analysis (`None` = the built-in parser).
+        tool: Option<ChangelogTool>,
     },
     /// Interactive semver picker (show recent commits, prompt user).
     Interactive,
     /// Explicit version passed on the CLI (e.g., `--version v1.2.3`).
     Explicit(String),
+    /// Calendar versioning: the next version follows from today's date and
+    /// the latest tag.
+    #[serde(rename = "calver")]
+    CalVer {
+        /// The version format (e.g. `YYYY.MM.MICRO`).
+        format: CalVerFormat,
+    },
 }
 
 impl fmt::Display for VersionStrategy {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::ConventionalCommits { tool } => write!(f, "conventional-commits ({tool})"),
+            Self::ConventionalCommits { tool: Some(tool) } => {
+                write!(f, "conventional-commits ({tool})")
+            }
+            Self::ConventionalCommits { tool: None } => {
+                write!(f, "conventional-commits (built-in)")
+            }
             Self::Interactive => write!(f, "interactive"),
             Self::Explicit(v) => write!(f, "explicit ({v})"),
+            Self::CalVer { format } => write!(f, "calver ({format})"),
         }
     }
 }
@@ -163,6 +317,8 @@ mod tests {
     fn ecosystem_display() {
         assert_eq!(Ecosystem::Rust.to_string(), "rust");
         assert_eq!(Ecosystem::Node.to_string(), "node");
+        assert_eq!(Ecosystem::Python.to_string(), "python");
+        assert_eq!(Ecosystem::Go.to_string(), "go");
         assert_eq!(Ecosystem::Generic.to_string(), "generic");
     }
 
@@ -170,16 +326,27 @@ mod tests {
     fn ecosystem_marker_files() {
         assert_eq!(Ecosystem::Rust.marker_file(), Some("Cargo.toml"));
         assert_eq!(Ecosystem::Node.marker_file(), Some("package.json"));
+        assert_eq!(Ecosystem::Python.marker_file(), Some("pyproject.toml"));
+        assert_eq!(Ecosystem::Go.marker_file(), Some("go.mod"));
+        assert_eq!(Ecosystem::Maven.marker_file(), Some("pom.xml"));
+        assert_eq!(Ecosystem::Php.marker_file(), Some("composer.json"));
+        assert_eq!(Ecosystem::Ruby.marker_file(), None);
         assert_eq!(Ecosystem::Generic.marker_file(), None);
     }
 
     #[test]
     fn version_strategy_display() {
         let cc = VersionStrategy::ConventionalCommits {
-            tool: ChangelogTool::GitCliff,
+            tool: Some(ChangelogTool::GitCliff),
         };
         assert_eq!(cc.to_string(), "conventional-commits (git-cliff)");
+        let builtin = VersionStrategy::ConventionalCommits { tool: None };
+        assert_eq!(builtin.to_string(), "conventional-commits (built-in)");
         assert_eq!(VersionStrategy::Interactive.to_string(), "interactive");
+        let calver = VersionStrategy::CalVer {
+            format: CalVerFormat::default(),
+        };
+        assert_eq!(calver.to_string(), "calver (YYYY.MM.MICRO)");
         assert_eq!(
             VersionStrategy::Explicit("v1.0.0".into()).to_string(),
             "explicit (v1.0.0)"
@@ -197,6 +364,20 @@ mod tests {
         assert_eq!(Ecosystem::Rust.lockfile_path(), Some("Cargo.lock"));
         assert_eq!(Ecosystem::Node.lockfile_path(), Some("package-lock.json"));
         assert_eq!(Ecosystem::Generic.lockfile_path(), None);
+        assert!(Ecosystem::Node.lockfile_paths().contains(&"yarn.lock"));
+        assert!(Ecosystem::Python.lockfile_paths().contains(&"poetry.lock"));
+        assert_eq!(Ecosystem::Gradle.lockfile_path(), Some("gradle.lockfile"));
+        assert!(Ecosystem::Maven.lockfile_paths().is_empty());
+        assert_eq!(Ecosystem::Ruby.lockfile_path(), Some("Gemfile.lock"));
+        assert_eq!(Ecosystem::Php.lockfile_path(), Some("composer.lock"));
+        assert!(Ecosystem::Generic.lockfile_paths().is_empty());
+    }
+
+    #[test]
+    fn package_manager_display_and_lockfiles() {
+        assert_eq!(PackageManager::Pnpm.to_string(), "pnpm");
+        assert_eq!(PackageManager::Npm.lockfiles()[0], "package-lock.json");
+        assert_eq!(PackageManager::Bun.lockfiles(), &["bun.lock", "bun.lockb"]);
     }
 
     #[test]
@@ -210,12 +391,20 @@ mod tests {
         assert_eq!(json, "\"generic\"");
         let parsed: Ecosystem = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed, Ecosystem::Generic);
+
+        let json = serde_json::to_string(&Ecosystem::Custom("terraform")).unwrap();
+        assert_eq!(json, "\"terraform\"");
+        let err = serde_json::from_str::<Ecosystem>("\"cobol\"").unwrap_err();
+        assert!(
+            err.to_string().contains("unknown ecosystem `cobol`"),
+            "{err}"
+        );
     }
 
     #[test]
     fn serde_roundtrip_version_strategy() {
         let strategy = VersionStrategy::ConventionalCommits {
-            tool: ChangelogTool::Cog,
+            tool: Some(ChangelogTool::Cog),
         };
         let json = serde_json::to_string(&strategy).unwrap();
         let parsed: VersionStrategy = serde_json::from_str(&json).unwrap();
@@ -103,35 +103,85 @@ pub fn is_remote_in_sync() -> GitResult<bool> {
     Ok(in_sync)
 }
 
-/// Get the latest semver tag, if any.
+/// List the tags matching a `git tag --list` glob (e.g. `"v*"`).
 ///
-/// Looks for tags matching `v*` and sorts by version.
+/// Returns an empty list outside a repository. Callers pick the latest by
+/// parsing the names, so the order is unspecified.
 #[instrument]
-pub fn latest_version_tag() -> GitResult<Option<String>> {
-    // Use git tag with version sort to find the latest semver tag
-    let output = git(&["tag", "--list", "v*", "--sort=-version:refname"]);
-    let Ok(output) = output else {
-        return Ok(None);
+pub fn list_tags(glob: &str) -> GitResult<Vec<String>> {
+    let Ok(output) = git(&["tag", "--list", glob]) else {
+        return Ok(Vec::new());
     };
 
-    let tag = output.lines().next().map(|s| s.trim().to_string());
-    debug!(?tag, "latest version tag");
-    Ok(tag)
+    let tags: Vec<String> = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    debug!(count = tags.len(), "listed tags");
+    Ok(tags)
+}
+
+/// List the tags matching `glob` that are reachable from `HEAD`.
+///
+/// Like [`list_tags`], but leaves out tags on other branches. Returns an
+/// empty list outside a repository.
+#[instrument]
+pub fn list_reachable_tags(glob: &str) -> GitResult<Vec<String>> {
+    let Ok(output) = git(&["tag", "--list", glob, "--merged", "HEAD"]) else {
+        return Ok(Vec::new());
+    };
+
+    let tags: Vec<String> = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    debug!(count = tags.len(), "listed reachable tags");
+    Ok(tags)
+}
+
+/// Check whether a tag exists locally.
+#[instrument]
+pub fn tag_exists(name: &str) -> GitResult<bool> {
+    let reference = format!("refs/tags/{name}");
+    match git(&["rev-parse", "--verify", "--quiet", &reference]) {
+        Ok(_) => Ok(true),
+        Err(GitError::Command { .. }) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check whether a tag exists on a remote (`git ls-remote`).
+///
+/// Fails when the remote cannot be reached.
+#[instrument]
+pub fn remote_tag_exists(remote: &str, name: &str) -> GitResult<bool> {
+    let reference = format!("refs/tags/{name}");
+    let output = git(&["ls-remote", "--tags", remote, &reference])?;
+    let exists = !output.trim().is_empty();
+    debug!(%remote, %name, exists, "remote tag lookup");
+    Ok(exists)
 }
 
 /// Get recent commits since a ref (or all commits if `None`).
 ///
+/// When `path` is set, only commits touching files under it are listed.
 /// Returns a list of `(short_hash, subject)` tuples, newest first.
 #[instrument]
-pub fn recent_commits(since: Option<&str>, limit: usize) -> GitResult<Vec<(String, String)>> {
+pub fn recent_commits(
+    since: Option<&str>,
+    limit: usize,
+    path: Option<&str>,
+) -> GitResult<Vec<(String, String)>> {
     let range = since.map_or_else(|| "HEAD".to_string(), |tag| format!("{tag}..HEAD"));
+    let max_count = format!("--max-count={limit}");
 
-    let output = git(&[
-        "log",
-        &range,
-        &format!("--max-count={limit}"),
-        "--format=%h %s",
-    ])?;
+    let mut args = vec!["log", &range, &max_count, "--format=%h %s"];
+    args.extend(path_filter(path));
+    let output = git(&args)?;
 
     let commits: Vec<(String, String)> = output
         .lines()
@@ -146,6 +196,35 @@ pub fn recent_commits(since: Option<&str>, limit: usize) -> GitResult<Vec<(Strin
     Ok(commits)
 }
 
+/// Get the full messages of the commits since a ref (or all commits if
+/// `None`).
+///
+/// When `path` is set, only commits touching files under it are listed.
+/// Returns a list of `(hash, message)` tuples, newest first.
+#[instrument]
+pub fn commit_messages(
+    since: Option<&str>,
+    path: Option<&str>,
+) -> GitResult<Vec<(String, String)>> {
+    let range = since.map_or_else(|| "HEAD".to_string(), |tag| format!("{tag}..HEAD"));
+
+    // Unit and record separators keep multi-line bodies intact
+    let mut args = vec!["log", &range, "--format=%H%x1f%B%x1e"];
+    args.extend(path_filter(path));
+    let output = git(&args)?;
+
+    let commits: Vec<(String, String)> = output
+        .split('\x1e')
+        .filter_map(|record| {
+            let (hash, message) = record.trim_start().split_once('\x1f')?;
+            Some((hash.to_string(), message.trim().to_string()))
+        })
+        .collect();
+
+    debug!(count = commits.len(), "commit messages");
+    Ok(commits)
+}
+
 /// Get the remote URL for a named remote (default: `"origin"`).
 #[instrument]
 pub fn remote_url(remote: &str) -> GitResult<Option<String>> {
@@ -161,6 +240,24 @@ pub fn remote_url(remote: &str) -> GitResult<Option<String>> {
     }
 }
 
+/// Get the remote a local branch tracks (`branch.<name>.remote`).
+///
+/// Returns `None` when the branch has no upstream, or tracks another local
+/// branch (remote `.`).
+#[instrument]
+pub fn branch_remote(branch: &str) -> GitResult<Option<String>> {
+    let key = format!("branch.{branch}.remote");
+    match git(&["config", "--get", &key]) {
+        Ok(remote) => {
+            let remote = remote.trim();
+            debug!(%branch, %remote, "branch remote");
+            Ok((!remote.is_empty() && remote != ".").then(|| remote.to_string()))
+        }
+        Err(GitError::Command { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
 /// Parse owner and repo from a git remote URL.
 ///
 /// Handles both HTTPS and SSH formats:
@@ -192,6 +289,21 @@ pub fn parse_owner_repo(url: &str) -> Option<(String, String)> {
     Some((owner.to_string(), repo.to_string()))
 }
 
+/// The web address of the repository behind a git remote URL
+/// (`git@github.com:owner/repo.git` → `https://github.com/owner/repo`).
+///
+/// Returns `None` if the URL cannot be parsed.
+pub fn web_url(url: &str) -> Option<String> {
+    let (owner, repo) = parse_owner_repo(url)?;
+    let authority = url.strip_prefix("git@").map_or_else(
+        || url.split("//").nth(1)?.split('/').next(),
+        |rest| rest.split(':').next(),
+    )?;
+    // Drop any user (`git@`, `token@`) and port
+    let host = authority.rsplit('@').next()?.split(':').next()?;
+    (!host.is_empty()).then(|| format!("https://{host}/{owner}/{repo}"))
+}
+
 /// Get the unified diff for a specific file between a ref and HEAD.
 ///
 /// Returns an empty string if the file doesn't exist in either ref
@@ -268,14 +380,21 @@ pub fn push(remote: &str, branch: &str, push_tags: bool) -> GitResult<()> {
 }
 
 /// Get statistics since a ref: commit count, files changed, insertions, deletions.
+///
+/// When `path` is set, only changes to files under it are counted.
 #[instrument]
-pub fn stats_since(since: &str) -> GitResult<GitStats> {
+pub fn stats_since(since: &str, path: Option<&str>) -> GitResult<GitStats> {
     // Count commits
-    let log_output = git(&["log", &format!("{since}..HEAD"), "--oneline"])?;
+    let range = format!("{since}..HEAD");
+    let mut log_args = vec!["log", &range, "--oneline"];
+    log_args.extend(path_filter(path));
+    let log_output = git(&log_args)?;
     let commit_count = log_output.lines().filter(|l| !l.is_empty()).count();
 
     // Get diff stats
-    let diff_output = git(&["diff", "--stat", since])?;
+    let mut diff_args = vec!["diff", "--stat", since];
+    diff_args.extend(path_filter(path));
+    let diff_output = git(&diff_args)?;
     let (files_changed, insertions, deletions) = parse_diff_stat(&diff_output);
 
     let stats = GitStats {
@@ -290,10 +409,18 @@ pub fn stats_since(since: &str) -> GitResult<GitStats> {
 
 /// Get top contributors since a ref.
 ///
+/// When `path` is set, only commits touching files under it are counted.
 /// Returns `(name, commit_count)` tuples sorted by count descending.
 #[instrument]
-pub fn contributors_since(since: &str, limit: usize) -> GitResult<Vec<(String, usize)>> {
-    let output = git(&["shortlog", "-sn", "--no-merges", &format!("{since}..HEAD")])?;
+pub fn contributors_since(
+    since: &str,
+    limit: usize,
+    path: Option<&str>,
+) -> GitResult<Vec<(String, usize)>> {
+    let range = format!("{since}..HEAD");
+    let mut args = vec!["shortlog", "-sn", "--no-merges", &range];
+    args.extend(path_filter(path));
+    let output = git(&args)?;
 
     let contributors: Vec<(String, usize)> = output
         .lines()
@@ -343,6 +470,11 @@ fn parse_diff_stat(output: &str) -> (usize, usize, usize) {
     (files, insertions, deletions)
 }
 
+/// Pathspec arguments limiting a command to files under `path`.
+fn path_filter(path: Option<&str>) -> Vec<&str> {
+    path.map_or_else(Vec::new, |p| vec!["--", p])
+}
+
 /// Check if we're inside a git repository.
 #[instrument]
 pub fn is_inside_repo() -> GitResult<bool> {
@@ -422,9 +554,9 @@ mod tests {
     }
 
     #[test]
-    fn latest_version_tag_works() {
+    fn list_tags_works() {
         if is_inside_repo().unwrap_or(false) {
-            let result = latest_version_tag();
+            let result = list_tags("v*");
             assert!(result.is_ok());
         }
     }
@@ -432,11 +564,39 @@ mod tests {
     #[test]
     fn recent_commits_works() {
         if is_inside_repo().unwrap_or(false) {
-            let result = recent_commits(None, 5);
+            let result = recent_commits(None, 5, None);
             assert!(result.is_ok());
         }
     }
 
+    #[test]
+    fn recent_commits_path_filter() {
+        if is_inside_repo().unwrap_or(false) {
+            let all = recent_commits(None, 50, None).unwrap();
+            let none = recent_commits(None, 50, Some("definitely/not/a/path")).unwrap();
+            assert!(none.is_empty());
+            assert!(none.len() <= all.len());
+        }
+    }
+
+    #[test]
+    fn commit_messages_match_recent_commits() {
+        if is_inside_repo().unwrap_or(false) {
+            let recent = recent_commits(None, 5, None).unwrap();
+            let messages = commit_messages(None, None).unwrap();
+            for ((short, subject), (hash, message)) in recent.iter().zip(&messages) {
+                assert!(hash.starts_with(short.as_str()));
+                assert_eq!(message.lines().next().unwrap_or_default(), subject);
+            }
+        }
+    }
+
+    #[test]
+    fn path_filter_args() {
+        assert!(path_filter(None).is_empty());
+        assert_eq!(path_filter(Some("crates/core")), vec!["--", "crates/core"]);
+    }
+
     #[test]
     fn diff_file_nonexistent() {
         if is_inside_repo().unwrap_or(false) {
@@ -486,6 +646,19 @@ mod tests {
         assert_eq!(result, Some(("claylo".into(), "scrat".into())));
     }
 
+    #[test]
+    fn web_urls() {
+        let expected = Some("https://github.com/claylo/scrat".to_string());
+        assert_eq!(web_url("git@github.com:claylo/scrat.git"), expected);
+        assert_eq!(web_url("https://github.com/claylo/scrat.git"), expected);
+        assert_eq!(web_url("ssh://git@github.com:22/claylo/scrat"), expected);
+        assert_eq!(
+            web_url("https://token@git.example.com/team/tool").as_deref(),
+            Some("https://git.example.com/team/tool")
+        );
+        assert_eq!(web_url("not-a-url"), None);
+    }
+
     #[test]
     fn parse_owner_repo_invalid() {
         assert!(parse_owner_repo("not-a-url").is_none());
@@ -0,0 +1,117 @@
+//! Wildcard patterns shared by configuration that names several branches
+//! or paths at once.
+//!
+//! One pattern language is used for Cargo workspace members, linked npm
+//! manifests (`version.linked`), and maintenance branches
+//! (`project.release_branches`): `*` matches any run of characters and `?`
+//! exactly one. Paths are expanded one segment at a time, so a wildcard
+//! never crosses a `/` there; a branch name is matched as a whole.
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+/// Match `text` against a pattern where `*` stands for any run of
+/// characters and `?` for exactly one.
+pub fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let (p, t): (Vec<char>, Vec<char>) = (pattern.chars().collect(), text.chars().collect());
+    let (mut pi, mut ti) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        match p.get(pi) {
+            Some('*') => {
+                backtrack = Some((pi, ti));
+                pi += 1;
+            }
+            Some(&c) if c == '?' || c == t[ti] => {
+                pi += 1;
+                ti += 1;
+            }
+            _ => match backtrack {
+                Some((bp, bt)) => {
+                    pi = bp + 1;
+                    ti = bt + 1;
+                    backtrack = Some((bp, bt + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+/// Expand a relative path pattern into the existing paths it names,
+/// relative to `project_root`, sorted within each wildcard segment.
+///
+/// Segments without a wildcard are kept as written, whether or not they
+/// exist; callers check for the file they expect.
+pub fn expand(project_root: &Utf8Path, pattern: &str) -> Vec<Utf8PathBuf> {
+    let mut matches = vec![Utf8PathBuf::new()];
+    for segment in pattern.trim_end_matches('/').split('/') {
+        if segment.is_empty() || segment == "." {
+            continue;
+        }
+        if !segment.contains(['*', '?']) {
+            for m in &mut matches {
+                m.push(segment);
+            }
+            continue;
+        }
+        matches = matches
+            .into_iter()
+            .flat_map(|base| {
+                let mut children: Vec<Utf8PathBuf> = project_root
+                    .join(&base)
+                    .read_dir_utf8()
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .filter(|e| wildcard_match(segment, e.file_name()))
+                    .map(|e| base.join(e.file_name()))
+                    .collect();
+                children.sort();
+                children
+            })
+            .collect();
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("scrat-*", "scrat-linux-x64"));
+        assert!(wildcard_match("*-x64", "scrat-linux-x64"));
+        assert!(wildcard_match("d?mo", "demo"));
+        assert!(wildcard_match("release/*.x", "release/1.4.x"));
+        assert!(wildcard_match("main", "main"));
+        assert!(!wildcard_match("scrat-*-arm64", "scrat-linux-x64"));
+        assert!(!wildcard_match("release/*", "main"));
+        assert!(!wildcard_match("release/*.x", "release/1.4"));
+        assert!(!wildcard_match("main", "main-old"));
+        assert!(!wildcard_match("a*a", "a"));
+        assert!(!wildcard_match("a*ab", "ab"));
+    }
+
+    #[test]
+    fn expands_segment_by_segment() {
+        let tmp = TempDir::new().unwrap();
+        let root = Utf8Path::from_path(tmp.path()).unwrap();
+        for dir in ["crates/b", "crates/a", "npm/x64/bin"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("npm/x64/package.json"), "{}").unwrap();
+
+        assert_eq!(expand(root, "./crates/*/"), vec!["crates/a", "crates/b"]);
+        assert_eq!(
+            expand(root, "npm/*/package.json"),
+            vec!["npm/x64/package.json"]
+        );
+        assert_eq!(expand(root, "npm/?64/*.json"), vec!["npm/x64/package.json"]);
+        assert!(expand(root, "missing/*").is_empty());
+    }
+}
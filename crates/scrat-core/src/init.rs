@@ -81,7 +81,7 @@ pub fn plan_init(project_root: &Utf8Path) -> InitPlan {
     debug!(%version_strategy, "init: detected version strategy");
 
     let changelog_tool = match &version_strategy {
-        VersionStrategy::ConventionalCommits { tool } => Some(*tool),
+        VersionStrategy::ConventionalCommits { tool } => *tool,
         _ => None,
     };
 
@@ -187,7 +187,35 @@ fn build_commands_section(selections: &InitSelections) -> String {
                     lines.push("# build = \"npm run build\"".to_string());
                     lines.push("# publish = \"npm publish\"".to_string());
                 }
-                Ecosystem::Generic => {
+                Ecosystem::Python => {
+                    lines.push("# test = \"uv run pytest\"".to_string());
+                    lines.push("# build = \"uv build\"".to_string());
+                    lines.push("# publish = \"uv publish\"".to_string());
+                }
+                Ecosystem::Go => {
+                    lines.push("# test = \"go test ./...\"".to_string());
+                    lines.push("# build = \"go build ./...\"".to_string());
+                }
+                Ecosystem::Maven => {
+                    lines.push("# test = \"./mvnw test\"".to_string());
+                    lines.push("# build = \"./mvnw package\"".to_string());
+                    lines.push("# publish = \"./mvnw deploy\"".to_string());
+                }
+                Ecosystem::Gradle => {
+                    lines.push("# test = \"./gradlew test\"".to_string());
+                    lines.push("# build = \"./gradlew build\"".to_string());
+                    lines.push("# publish = \"./gradlew publish\"".to_string());
+                }
+                Ecosystem::Ruby => {
+                    lines.push("# test = \"bundle exec rake\"".to_string());
+                    lines.push("# build = \"gem build *.gemspec\"".to_string());
+                    lines.push("# publish = \"gem build *.gemspec --output pkg/release.gem && gem push pkg/release.gem\"".to_string());
+                }
+                Ecosystem::Php => {
+                    lines.push("# test = \"composer test\"".to_string());
+                    lines.push("# build = \"composer validate --strict\"".to_string());
+                }
+                Ecosystem::Generic | Ecosystem::Custom(_) => {
                     lines.push("# test = \"make test\"".to_string());
                     lines.push("# build = \"make build\"".to_string());
                 }
@@ -207,7 +235,35 @@ fn build_commands_section(selections: &InitSelections) -> String {
                     lines.push("#   build: npm run build".to_string());
                     lines.push("#   publish: npm publish".to_string());
                 }
-                Ecosystem::Generic => {
+                Ecosystem::Python => {
+                    lines.push("#   test: uv run pytest".to_string());
+                    lines.push("#   build: uv build".to_string());
+                    lines.push("#   publish: uv publish".to_string());
+                }
+                Ecosystem::Go => {
+                    lines.push("#   test: go test ./...".to_string());
+                    lines.push("#   build: go build ./...".to_string());
+                }
+                Ecosystem::Maven => {
+                    lines.push("#   test: ./mvnw test".to_string());
+                    lines.push("#   build: ./mvnw package".to_string());
+                    lines.push("#   publish: ./mvnw deploy".to_string());
+                }
+                Ecosystem::Gradle => {
+                    lines.push("#   test: ./gradlew test".to_string());
+                    lines.push("#   build: ./gradlew build".to_string());
+                    lines.push("#   publish: ./gradlew publish".to_string());
+                }
+                Ecosystem::Ruby => {
+                    lines.push("#   test: bundle exec rake".to_string());
+                    lines.push("#   build: gem build *.gemspec".to_string());
+                    lines.push("#   publish: gem build *.gemspec --output pkg/release.gem && gem push pkg/release.gem".to_string());
+                }
+                Ecosystem::Php => {
+                    lines.push("#   test: composer test".to_string());
+                    lines.push("#   build: composer validate --strict".to_string());
+                }
+                Ecosystem::Generic | Ecosystem::Custom(_) => {
                     lines.push("#   test: make test".to_string());
                     lines.push("#   build: make build".to_string());
                 }
@@ -320,7 +376,7 @@ mod tests {
         assert_eq!(
             plan.version_strategy,
             VersionStrategy::ConventionalCommits {
-                tool: ChangelogTool::GitCliff
+                tool: Some(ChangelogTool::GitCliff)
             }
         );
         assert_eq!(plan.changelog_tool, Some(ChangelogTool::GitCliff));
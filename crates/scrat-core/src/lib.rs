@@ -12,11 +12,17 @@
 //! - [`ecosystem`] - Ecosystem types and smart defaults
 //! - [`error`] - Error types and result aliases
 //! - [`git`] - Git operations for release workflows
+//! - [`glob`] - Wildcard patterns for branches and paths
 //! - [`hooks`] - Hook executor for shell commands at phase boundaries
 //! - [`init`] - Init command — project discovery and config file generation
+//! - [`manifest`] - Format-preserving version rewrites for project manifests
 //! - [`notes`] - Release notes rendering via git-cliff context injection
+//! - [`package`] - Independent per-package releases for monorepos
 //! - [`pipeline`] - Pipeline context accumulator for structured release data
 //! - [`preflight`] - Release readiness checks
+//! - [`provider`] - Pluggable ecosystem providers
+//! - [`publish`] - Ordered registry publishing for Cargo workspaces
+//! - [`remote`] - The git remote a release is pushed to and published on
 //! - [`ship`] - Ship orchestrator (full release workflow)
 //! - [`stats`] - Release statistics (commits, files changed, contributors)
 //! - [`version`] - Version determination and computation
@@ -48,17 +54,28 @@ pub mod ecosystem;
 pub mod error;
 
 pub mod git;
+pub mod glob;
 
 pub mod hooks;
 
 pub mod init;
 
+pub mod manifest;
+
 pub mod notes;
 
+pub mod package;
+
 pub mod pipeline;
 
 pub mod preflight;
 
+pub mod provider;
+
+pub mod publish;
+
+pub mod remote;
+
 pub mod ship;
 
 pub mod stats;
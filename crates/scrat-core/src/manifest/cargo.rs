@@ -0,0 +1,765 @@
+//! Cargo manifest rewrites (`Cargo.toml`, `Cargo.lock`).
+//!
+//! Understands Cargo workspaces: the version may live in `[package]` or be
+//! inherited from `[workspace.package]`, and members that depend on each
+//! other through `path` dependencies carry a `version` requirement that must
+//! follow the bump for `cargo publish` to accept them.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use semver::Version;
+use toml_edit::{DocumentMut, Item, TableLike};
+use tracing::debug;
+
+use super::{ManifestError, ManifestResult, parse_toml, set_toml_string};
+use crate::glob;
+
+/// Dependency tables that may contain path dependencies on other members.
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// A `Cargo.toml` loaded for editing.
+struct Manifest {
+    /// Path relative to the project root (e.g. `crates/foo/Cargo.toml`).
+    rel_path: String,
+    /// Absolute path.
+    path: Utf8PathBuf,
+    doc: DocumentMut,
+    changed: bool,
+}
+
+impl Manifest {
+    fn load(project_root: &Utf8Path, rel_path: String) -> ManifestResult<Self> {
+        let path = project_root.join(&rel_path);
+        let doc = parse_toml(&path, &super::read(&path)?)?;
+        Ok(Self {
+            rel_path,
+            path,
+            doc,
+            changed: false,
+        })
+    }
+
+    fn package_name(&self) -> Option<&str> {
+        self.doc
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(Item::as_str)
+    }
+
+    /// Whether `[package].version` is `{ workspace = true }`.
+    fn inherits_version(&self) -> bool {
+        self.doc
+            .get("package")
+            .and_then(|p| p.get("version"))
+            .and_then(|v| v.get("workspace"))
+            .and_then(Item::as_bool)
+            == Some(true)
+    }
+}
+
+/// A package in a Cargo workspace (or the single package of a non-workspace
+/// project), as needed for ordered publishing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CargoPackage {
+    /// Package name.
+    pub name: String,
+    /// Resolved version (inherited from `[workspace.package]` if needed).
+    pub version: Option<String>,
+    /// Directory relative to the project root (empty for the root package).
+    pub dir: Utf8PathBuf,
+    /// Whether the package may be published (`publish = false` or
+    /// `publish = []` opt out).
+    pub publishable: bool,
+    /// Names of the packages this one depends on through `path`
+    /// dependencies (regular and build dependencies; dev-dependencies are
+    /// stripped by `cargo publish` and do not constrain the order).
+    pub path_dependencies: Vec<String>,
+}
+
+/// List the packages of the workspace rooted at `project_root`.
+///
+/// Includes the root package (if the root manifest has a `[package]`) and
+/// every member matched by `[workspace].members`.
+pub fn workspace_packages(project_root: &Utf8Path) -> ManifestResult<Vec<CargoPackage>> {
+    let root = Manifest::load(project_root, "Cargo.toml".into())?;
+    let workspace = root.doc.get("workspace");
+    let workspace_version = workspace
+        .and_then(|w| w.get("package"))
+        .and_then(|p| p.get("version"))
+        .and_then(Item::as_str);
+    let workspace_deps = workspace.and_then(|w| w.get("dependencies"));
+
+    let mut dirs = vec![Utf8PathBuf::new()];
+    dirs.extend(workspace_members(project_root, &root.doc));
+    dirs.dedup();
+
+    let mut packages = Vec::new();
+    for dir in dirs {
+        let manifest = if dir.as_str().is_empty() {
+            None
+        } else {
+            Some(Manifest::load(project_root, format!("{dir}/Cargo.toml"))?)
+        };
+        let manifest = manifest.as_ref().unwrap_or(&root);
+        let Some(package) = manifest.doc.get("package") else {
+            continue;
+        };
+        let Some(name) = manifest.package_name() else {
+            continue;
+        };
+
+        let version = if manifest.inherits_version() {
+            workspace_version
+        } else {
+            package.get("version").and_then(Item::as_str)
+        };
+        let publishable = match package.get("publish") {
+            Some(item) if item.as_bool() == Some(false) => false,
+            Some(item) => item.as_array().is_none_or(|a| !a.is_empty()),
+            None => true,
+        };
+
+        packages.push(CargoPackage {
+            name: name.to_string(),
+            version: version.map(str::to_string),
+            dir,
+            publishable,
+            path_dependencies: path_dependency_names(&manifest.doc, workspace_deps),
+        });
+    }
+
+    Ok(packages)
+}
+
+/// Names of packages referenced through `path` in the regular and build
+/// dependency tables (including `target.*`), resolving `workspace = true`
+/// entries against `[workspace.dependencies]`.
+fn path_dependency_names(doc: &DocumentMut, workspace_deps: Option<&Item>) -> Vec<String> {
+    let mut tables: Vec<&Item> = Vec::new();
+    for key in ["dependencies", "build-dependencies"] {
+        tables.extend(doc.get(key));
+        if let Some(targets) = doc.get("target").and_then(Item::as_table_like) {
+            tables.extend(targets.iter().filter_map(|(_, t)| t.get(key)));
+        }
+    }
+
+    let mut names: Vec<String> = tables
+        .into_iter()
+        .filter_map(Item::as_table_like)
+        .flat_map(|deps| deps.iter())
+        .filter_map(|(key, dep)| {
+            let dep = if dep.get("workspace").and_then(Item::as_bool) == Some(true) {
+                workspace_deps?.get(key)?
+            } else {
+                dep
+            };
+            dep.get("path")?;
+            let name = dep.get("package").and_then(Item::as_str).unwrap_or(key);
+            Some(name.to_string())
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Read the current version from `Cargo.toml`.
+///
+/// Returns `[package].version`, or `[workspace.package].version` when the
+/// package inherits it (or the manifest is a virtual workspace root).
+pub fn current_version(project_root: &Utf8Path) -> Option<String> {
+    let content = std::fs::read_to_string(project_root.join("Cargo.toml")).ok()?;
+    let doc = content.parse::<DocumentMut>().ok()?;
+
+    doc.get("package")
+        .and_then(|p| p.get("version"))
+        .and_then(Item::as_str)
+        .or_else(|| {
+            doc.get("workspace")
+                .and_then(|w| w.get("package"))
+                .and_then(|p| p.get("version"))
+                .and_then(Item::as_str)
+        })
+        .map(str::to_string)
+}
+
+/// Set the version in `Cargo.toml` and everything that must follow it.
+///
+/// - `[package].version` and `[workspace.package].version` in the root
+///   manifest, whichever are present as plain strings
+/// - `version` requirements of path dependencies on the bumped packages, in
+///   every workspace member and in `[workspace.dependencies]`, keeping the
+///   requirement's operator and precision (`"0.1"` → `"0.2"`)
+/// - `Cargo.lock` entries for the bumped packages
+///
+/// The bumped packages are the root package (if its version was set) and
+/// every member that inherits `version.workspace = true`. Members with their
+/// own version string are left alone.
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_version(project_root: &Utf8Path, version: &Version) -> ManifestResult<Vec<String>> {
+    let version_str = version.to_string();
+    let mut root = Manifest::load(project_root, "Cargo.toml".into())?;
+
+    let package_bumped = root
+        .doc
+        .get_mut("package")
+        .is_some_and(|p| set_toml_string(p.get_mut("version"), &version_str));
+    let workspace_bumped = root
+        .doc
+        .get_mut("workspace")
+        .and_then(|w| w.get_mut("package"))
+        .is_some_and(|p| set_toml_string(p.get_mut("version"), &version_str));
+    if !package_bumped && !workspace_bumped {
+        return Err(ManifestError::Invalid {
+            path: root.path,
+            message: "no `[package].version` or `[workspace.package].version` to update".into(),
+        });
+    }
+    root.changed = true;
+    debug!(%version, package_bumped, workspace_bumped, "updated root Cargo.toml");
+
+    let manifests = load_members(project_root, root)?;
+
+    // Packages whose version is now `version`
+    let bumped: Vec<String> = manifests
+        .iter()
+        .enumerate()
+        .filter(|(i, m)| (*i == 0 && package_bumped) || (workspace_bumped && m.inherits_version()))
+        .filter_map(|(_, m)| m.package_name().map(str::to_string))
+        .collect();
+    debug!(?bumped, "packages at the new version");
+
+    propagate(project_root, manifests, &bumped, version)
+}
+
+/// Set the version of a single workspace member released on its own.
+///
+/// `member_dir` is relative to the workspace root (`project_root`). The
+/// member must carry its own `[package].version` string; members inheriting
+/// `version.workspace = true` cannot diverge from the workspace version.
+/// Path dependency requirements on the member and its `Cargo.lock` entry
+/// are updated as in [`set_version`].
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_member_version(
+    project_root: &Utf8Path,
+    member_dir: &Utf8Path,
+    version: &Version,
+) -> ManifestResult<Vec<String>> {
+    let root = Manifest::load(project_root, "Cargo.toml".into())?;
+    let mut manifests = load_members(project_root, root)?;
+
+    let rel_path = if member_dir.as_str().is_empty() {
+        "Cargo.toml".to_string()
+    } else {
+        format!("{member_dir}/Cargo.toml")
+    };
+    let Some(member) = manifests.iter_mut().find(|m| m.rel_path == rel_path) else {
+        return Err(ManifestError::Invalid {
+            path: project_root.join(rel_path),
+            message: "not a member of the workspace".into(),
+        });
+    };
+    if member.inherits_version() {
+        return Err(ManifestError::Invalid {
+            path: member.path.clone(),
+            message: "inherits the workspace version; give it its own `version` to release it independently".into(),
+        });
+    }
+
+    let version_str = version.to_string();
+    let bumped = member
+        .doc
+        .get_mut("package")
+        .is_some_and(|p| set_toml_string(p.get_mut("version"), &version_str));
+    let name = member.package_name().map(str::to_string);
+    let (true, Some(name)) = (bumped, name) else {
+        return Err(ManifestError::Invalid {
+            path: member.path.clone(),
+            message: "no `[package].version` to update".into(),
+        });
+    };
+    member.changed = true;
+    debug!(%version, package = %name, "updated member Cargo.toml");
+
+    propagate(project_root, manifests, &[name], version)
+}
+
+/// Load the workspace member manifests, with the root manifest first.
+fn load_members(project_root: &Utf8Path, root: Manifest) -> ManifestResult<Vec<Manifest>> {
+    let mut manifests = vec![root];
+    for member in workspace_members(project_root, &manifests[0].doc) {
+        let rel_path = format!("{member}/Cargo.toml");
+        if rel_path != "Cargo.toml" {
+            manifests.push(Manifest::load(project_root, rel_path)?);
+        }
+    }
+    Ok(manifests)
+}
+
+/// Carry a version change for the `bumped` packages into path dependency
+/// requirements and `Cargo.lock`, then write every changed manifest.
+fn propagate(
+    project_root: &Utf8Path,
+    mut manifests: Vec<Manifest>,
+    bumped: &[String],
+    version: &Version,
+) -> ManifestResult<Vec<String>> {
+    let version_str = version.to_string();
+    for manifest in &mut manifests {
+        if update_path_requirements(&mut manifest.doc, bumped, version) {
+            debug!(path = %manifest.rel_path, "updated path dependency requirements");
+            manifest.changed = true;
+        }
+    }
+
+    let mut modified = Vec::new();
+    for manifest in manifests.into_iter().filter(|m| m.changed) {
+        super::write(&manifest.path, &manifest.doc.to_string())?;
+        modified.push(manifest.rel_path);
+    }
+
+    if update_lockfile(project_root, bumped, &version_str)? {
+        modified.push("Cargo.lock".into());
+    }
+
+    Ok(modified)
+}
+
+/// Expand `[workspace].members` (minus `exclude`) into member directories
+/// relative to `project_root`. Only directories with a `Cargo.toml` count.
+fn workspace_members(project_root: &Utf8Path, doc: &DocumentMut) -> Vec<Utf8PathBuf> {
+    let patterns = |key: &str| -> Vec<String> {
+        doc.get("workspace")
+            .and_then(|w| w.get(key))
+            .and_then(Item::as_array)
+            .map(|a| {
+                a.iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let excluded: Vec<Utf8PathBuf> = patterns("exclude")
+        .iter()
+        .flat_map(|p| glob::expand(project_root, p))
+        .collect();
+
+    let mut members: Vec<Utf8PathBuf> = patterns("members")
+        .iter()
+        .flat_map(|p| glob::expand(project_root, p))
+        .filter(|m| !excluded.contains(m) && project_root.join(m).join("Cargo.toml").is_file())
+        .collect();
+    members.sort();
+    members.dedup();
+    members
+}
+
+/// Update `version` requirements of path dependencies on `names`.
+///
+/// Looks in the regular, `target.*`, and `[workspace.dependencies]` tables.
+/// Returns `true` if anything changed.
+fn update_path_requirements(doc: &mut DocumentMut, names: &[String], version: &Version) -> bool {
+    let mut tables: Vec<&mut Item> = Vec::new();
+    let root = doc.as_table_mut();
+
+    let mut changed = false;
+    for (key, item) in root.iter_mut() {
+        match key.get() {
+            k if DEPENDENCY_TABLES.contains(&k) => tables.push(item),
+            "target" => {
+                if let Some(targets) = item.as_table_like_mut() {
+                    for (_, target) in targets.iter_mut() {
+                        if let Some(target) = target.as_table_like_mut() {
+                            for (key, deps) in target.iter_mut() {
+                                if DEPENDENCY_TABLES.contains(&key.get()) {
+                                    changed |= update_dependency_table(deps, names, version);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "workspace" => {
+                if let Some(deps) = item.get_mut("dependencies") {
+                    tables.push(deps);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for deps in tables {
+        changed |= update_dependency_table(deps, names, version);
+    }
+    changed
+}
+
+/// Update the path dependencies on `names` within one dependency table.
+fn update_dependency_table(deps: &mut Item, names: &[String], version: &Version) -> bool {
+    let Some(deps) = deps.as_table_like_mut() else {
+        return false;
+    };
+
+    let mut changed = false;
+    for (key, dep) in deps.iter_mut() {
+        let Some(dep) = dep.as_table_like_mut() else {
+            continue;
+        };
+        let name = dep
+            .get("package")
+            .and_then(Item::as_str)
+            .unwrap_or_else(|| key.get());
+        if dep.get("path").is_none() || !names.iter().any(|n| n == name) {
+            continue;
+        }
+        changed |= update_requirement(dep, version);
+    }
+    changed
+}
+
+/// Rewrite a dependency's `version` requirement for the new version.
+fn update_requirement(dep: &mut dyn TableLike, version: &Version) -> bool {
+    let Some(req) = dep.get("version").and_then(Item::as_str) else {
+        return false;
+    };
+    match rewrite_requirement(req, version) {
+        Some(new_req) if new_req != req => set_toml_string(dep.get_mut("version"), &new_req),
+        _ => false,
+    }
+}
+
+/// Rewrite a single-comparator requirement, keeping operator and precision.
+///
+/// `"0.1"` → `"0.2"`, `"^1.2.3"` → `"^1.3.0"`, `"=1.0.0"` → `"=1.1.0"`.
+/// Returns `None` for requirements that cannot be carried over
+/// (multiple comparators, wildcards, `<`/`>` bounds).
+fn rewrite_requirement(req: &str, version: &Version) -> Option<String> {
+    let req = req.trim();
+    let body_start = req.find(|c: char| c.is_ascii_digit())?;
+    let (op, body) = req.split_at(body_start);
+    if !matches!(op.trim(), "" | "^" | "~" | "=" | ">=") || body.contains([',', '*', 'x', 'X']) {
+        return None;
+    }
+
+    let core = body.split(['-', '+']).next()?;
+    let rewritten = match core.split('.').count() {
+        1 => version.major.to_string(),
+        2 => format!("{}.{}", version.major, version.minor),
+        _ => version.to_string(),
+    };
+    Some(format!("{op}{rewritten}"))
+}
+
+/// Set the version of local packages (no `source`) named in `names`.
+///
+/// Returns `true` if `Cargo.lock` exists and was changed.
+fn update_lockfile(
+    project_root: &Utf8Path,
+    names: &[String],
+    version: &str,
+) -> ManifestResult<bool> {
+    let lock_path = project_root.join("Cargo.lock");
+    if names.is_empty() || !lock_path.is_file() {
+        return Ok(false);
+    }
+
+    let mut doc = parse_toml(&lock_path, &super::read(&lock_path)?)?;
+    let Some(packages) = doc
+        .get_mut("package")
+        .and_then(Item::as_array_of_tables_mut)
+    else {
+        return Ok(false);
+    };
+
+    let mut changed = false;
+    for package in packages.iter_mut() {
+        let is_ours = package.get("source").is_none()
+            && package
+                .get("name")
+                .and_then(Item::as_str)
+                .is_some_and(|n| names.iter().any(|name| name == n));
+        if is_ours && package.get("version").and_then(Item::as_str) != Some(version) {
+            changed |= set_toml_string(package.get_mut("version"), version);
+        }
+    }
+
+    if changed {
+        super::write(&lock_path, &doc.to_string())?;
+        debug!("updated Cargo.lock");
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    const CARGO_TOML: &str = r#"[package]
+name = "demo"
+version = "0.1.0"   # bumped by scrat
+edition = "2024"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+"#;
+
+    const CARGO_LOCK: &str = r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 4
+
+[[package]]
+name = "demo"
+version = "0.1.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+
+    #[test]
+    fn rewrites_package_version_preserving_format() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("Cargo.toml"), CARGO_TOML).unwrap();
+
+        let modified = set_version(utf8_tmp(&tmp), &Version::new(0, 2, 0)).unwrap();
+        assert_eq!(modified, vec!["Cargo.toml"]);
+
+        let content = fs::read_to_string(tmp.path().join("Cargo.toml")).unwrap();
+        assert_eq!(content, CARGO_TOML.replace("\"0.1.0\"", "\"0.2.0\""));
+    }
+
+    #[test]
+    fn updates_own_lockfile_entry_only() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("Cargo.toml"), CARGO_TOML).unwrap();
+        fs::write(tmp.path().join("Cargo.lock"), CARGO_LOCK).unwrap();
+
+        let modified = set_version(utf8_tmp(&tmp), &Version::new(0, 2, 0)).unwrap();
+        assert_eq!(modified, vec!["Cargo.toml", "Cargo.lock"]);
+
+        let lock = fs::read_to_string(tmp.path().join("Cargo.lock")).unwrap();
+        assert!(lock.contains("name = \"demo\"\nversion = \"0.2.0\""));
+        // Registry package with the same version is untouched
+        assert!(lock.contains("name = \"serde\"\nversion = \"0.1.0\""));
+        assert!(lock.starts_with("# This file is automatically @generated"));
+    }
+
+    #[test]
+    fn rewrites_workspace_package_version() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"1.0.0\"\n",
+        )
+        .unwrap();
+
+        set_version(utf8_tmp(&tmp), &Version::new(1, 1, 0)).unwrap();
+        let content = fs::read_to_string(tmp.path().join("Cargo.toml")).unwrap();
+        assert!(content.contains("[workspace.package]\nversion = \"1.1.0\"\n"));
+    }
+
+    fn write(tmp: &TempDir, rel: &str, content: &str) {
+        let path = tmp.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn read(tmp: &TempDir, rel: &str) -> String {
+        fs::read_to_string(tmp.path().join(rel)).unwrap()
+    }
+
+    /// A workspace shaped like this repo: inherited versions and a path
+    /// dependency from the CLI crate on the core crate.
+    fn workspace_fixture(tmp: &TempDir) {
+        write(
+            tmp,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"xtask\", \"crates/*\"]\n\n[workspace.package]\nversion = \"0.1.0\"\n\n[workspace.dependencies]\ncore = { package = \"demo-core\", path = \"crates/demo-core\", version = \"=0.1.0\" }\n",
+        );
+        write(
+            tmp,
+            "crates/demo/Cargo.toml",
+            "[package]\nname = \"demo\"\nversion.workspace = true\n\n[dependencies]\ndemo-core = { version = \"0.1\", path = \"../demo-core\" }\nserde = \"1.0\"\n\n[target.'cfg(unix)'.dev-dependencies]\ndemo-core = { version = \"^0.1.0\", path = \"../demo-core\" }\n",
+        );
+        write(
+            tmp,
+            "crates/demo-core/Cargo.toml",
+            "[package]\nname = \"demo-core\"\nversion.workspace = true\n",
+        );
+        write(
+            tmp,
+            "xtask/Cargo.toml",
+            "[package]\nname = \"xtask\"\nversion = \"0.0.0\"\npublish = false\n\n[dependencies]\ndemo = { path = \"../crates/demo\" }\n",
+        );
+        write(
+            tmp,
+            "Cargo.lock",
+            "version = 4\n\n[[package]]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[[package]]\nname = \"demo-core\"\nversion = \"0.1.0\"\n\n[[package]]\nname = \"xtask\"\nversion = \"0.0.0\"\n",
+        );
+    }
+
+    #[test]
+    fn workspace_current_version_is_inherited() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+        assert_eq!(current_version(utf8_tmp(&tmp)).as_deref(), Some("0.1.0"));
+
+        let member = utf8_tmp(&tmp).join("crates/demo");
+        // A member directory has no workspace table of its own
+        assert_eq!(current_version(&member), None);
+    }
+
+    #[test]
+    fn workspace_bump_updates_members_and_requirements() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+
+        let modified = set_version(utf8_tmp(&tmp), &Version::new(0, 2, 0)).unwrap();
+        assert_eq!(
+            modified,
+            vec!["Cargo.toml", "crates/demo/Cargo.toml", "Cargo.lock"]
+        );
+
+        let root = read(&tmp, "Cargo.toml");
+        assert!(root.contains("[workspace.package]\nversion = \"0.2.0\""));
+        assert!(root.contains("version = \"=0.2.0\""));
+
+        let cli = read(&tmp, "crates/demo/Cargo.toml");
+        assert!(cli.contains("demo-core = { version = \"0.2\", path = \"../demo-core\" }"));
+        assert!(cli.contains("demo-core = { version = \"^0.2.0\", path = \"../demo-core\" }"));
+        assert!(cli.contains("serde = \"1.0\""));
+
+        let lock = read(&tmp, "Cargo.lock");
+        assert!(lock.contains("name = \"demo\"\nversion = \"0.2.0\""));
+        assert!(lock.contains("name = \"demo-core\"\nversion = \"0.2.0\""));
+        // Members with their own version are not part of the bump
+        assert!(lock.contains("name = \"xtask\"\nversion = \"0.0.0\""));
+        assert!(read(&tmp, "xtask/Cargo.toml").contains("version = \"0.0.0\""));
+    }
+
+    #[test]
+    fn member_bump_leaves_workspace_version_alone() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+        write(
+            &tmp,
+            "crates/demo-core/Cargo.toml",
+            "[package]\nname = \"demo-core\"\nversion = \"0.1.0\"\n",
+        );
+
+        let modified = set_member_version(
+            utf8_tmp(&tmp),
+            Utf8Path::new("crates/demo-core"),
+            &Version::new(0, 3, 0),
+        )
+        .unwrap();
+        assert_eq!(
+            modified,
+            vec![
+                "Cargo.toml",
+                "crates/demo/Cargo.toml",
+                "crates/demo-core/Cargo.toml",
+                "Cargo.lock"
+            ]
+        );
+
+        let root = read(&tmp, "Cargo.toml");
+        assert!(root.contains("[workspace.package]\nversion = \"0.1.0\""));
+        assert!(root.contains("version = \"=0.3.0\""));
+        assert!(read(&tmp, "crates/demo-core/Cargo.toml").contains("version = \"0.3.0\""));
+        assert!(read(&tmp, "crates/demo/Cargo.toml").contains("version = \"0.3\""));
+
+        let lock = read(&tmp, "Cargo.lock");
+        assert!(lock.contains("name = \"demo\"\nversion = \"0.1.0\""));
+        assert!(lock.contains("name = \"demo-core\"\nversion = \"0.3.0\""));
+    }
+
+    #[test]
+    fn member_bump_rejects_inherited_version() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+
+        let err = set_member_version(
+            utf8_tmp(&tmp),
+            Utf8Path::new("crates/demo"),
+            &Version::new(0, 2, 0),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("inherits the workspace version"));
+    }
+
+    #[test]
+    fn workspace_packages_resolve_versions_and_path_deps() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+
+        let packages = workspace_packages(utf8_tmp(&tmp)).unwrap();
+        let names: Vec<&str> = packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["demo", "demo-core", "xtask"]);
+
+        let demo = &packages[0];
+        assert_eq!(demo.version.as_deref(), Some("0.1.0"));
+        assert_eq!(demo.dir, "crates/demo");
+        // The target-specific entry is a dev-dependency and does not count
+        assert_eq!(demo.path_dependencies, vec!["demo-core"]);
+
+        let xtask = &packages[2];
+        assert!(!xtask.publishable);
+        assert_eq!(xtask.path_dependencies, vec!["demo"]);
+    }
+
+    #[test]
+    fn workspace_dependency_inheritance_resolves_path() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+        write(
+            &tmp,
+            "crates/demo/Cargo.toml",
+            "[package]\nname = \"demo\"\nversion.workspace = true\n\n[dependencies]\ncore.workspace = true\n",
+        );
+
+        let packages = workspace_packages(utf8_tmp(&tmp)).unwrap();
+        assert_eq!(packages[0].path_dependencies, vec!["demo-core"]);
+    }
+
+    #[test]
+    fn rewrite_requirement_keeps_operator_and_precision() {
+        let v = Version::new(1, 4, 2);
+        assert_eq!(rewrite_requirement("1", &v).as_deref(), Some("1"));
+        assert_eq!(rewrite_requirement("0.1", &v).as_deref(), Some("1.4"));
+        assert_eq!(rewrite_requirement("^0.1.0", &v).as_deref(), Some("^1.4.2"));
+        assert_eq!(rewrite_requirement("~1.2", &v).as_deref(), Some("~1.4"));
+        assert_eq!(
+            rewrite_requirement("= 1.0.0", &v).as_deref(),
+            Some("= 1.4.2")
+        );
+        assert_eq!(rewrite_requirement(">=1, <2", &v), None);
+        assert_eq!(rewrite_requirement("1.*", &v), None);
+        assert_eq!(rewrite_requirement("<2", &v), None);
+    }
+
+    #[test]
+    fn inherited_version_is_an_error_without_workspace() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion.workspace = true\n",
+        )
+        .unwrap();
+
+        let err = set_version(utf8_tmp(&tmp), &Version::new(1, 0, 0)).unwrap_err();
+        assert!(matches!(err, ManifestError::Invalid { .. }));
+    }
+}
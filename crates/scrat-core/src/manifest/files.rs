@@ -0,0 +1,338 @@
+//! Declarative version-file rewrites (`[[version.files]]`).
+//!
+//! Each configured file names one locator — a regex capture group, a TOML
+//! key path, a JSON pointer, or a YAML key path — that must match exactly
+//! one place. All edits are computed before anything is written, so a
+//! locator that matches zero or several places leaves every file untouched.
+
+use std::ops::Range;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use regex::Regex;
+use toml_edit::{DocumentMut, Item};
+
+use super::{ManifestError, ManifestResult, json, set_toml_string, yaml};
+use crate::config::VersionFileConfig;
+
+/// How a version is found within a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Locator<'a> {
+    Regex(&'a str),
+    Toml(&'a str),
+    Json(&'a str),
+    Yaml(&'a str),
+}
+
+impl<'a> Locator<'a> {
+    fn from_config(file: &'a VersionFileConfig) -> Result<Self, String> {
+        let locators: Vec<Self> = [
+            file.regex.as_deref().map(Self::Regex),
+            file.toml.as_deref().map(Self::Toml),
+            file.json.as_deref().map(Self::Json),
+            file.yaml.as_deref().map(Self::Yaml),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        match locators.as_slice() {
+            [locator] => Ok(*locator),
+            _ => Err("set exactly one of `regex`, `toml`, `json`, or `yaml`".into()),
+        }
+    }
+}
+
+/// Rewrite the version in every configured file.
+///
+/// `version` is written as given: the release's own rendering, so a
+/// zero-padded CalVer version stays padded (`2026.05.1`) as in the tag.
+///
+/// Returns the paths (as configured, relative to `project_root`) of the
+/// files modified, in config order without duplicates.
+pub fn set_version(
+    project_root: &Utf8Path,
+    files: &[VersionFileConfig],
+    version: &str,
+) -> ManifestResult<Vec<String>> {
+    // Several entries may target the same file, so edits are applied to
+    // the pending content rather than to what is on disk.
+    let mut pending: Vec<(Utf8PathBuf, String)> = Vec::new();
+    for file in files {
+        let index = match pending.iter().position(|(path, _)| *path == file.path) {
+            Some(index) => index,
+            None => {
+                let content = super::read(&project_root.join(&file.path))?;
+                pending.push((file.path.clone(), content));
+                pending.len() - 1
+            }
+        };
+        let updated = rewrite(&pending[index].1, file, version).map_err(|message| {
+            ManifestError::Invalid {
+                path: project_root.join(&file.path),
+                message,
+            }
+        })?;
+        pending[index].1 = updated;
+    }
+
+    let mut modified = Vec::with_capacity(pending.len());
+    for (path, content) in pending {
+        super::write(&project_root.join(&path), &content)?;
+        modified.push(path.into_string());
+    }
+    Ok(modified)
+}
+
+/// Apply one entry's locator to `content`.
+fn rewrite(content: &str, file: &VersionFileConfig, version: &str) -> Result<String, String> {
+    match Locator::from_config(file)? {
+        Locator::Regex(pattern) => {
+            let re = Regex::new(pattern).map_err(|e| format!("invalid regex: {e}"))?;
+            if re.captures_len() < 2 {
+                return Err(format!("regex `{pattern}` has no capture group"));
+            }
+            let ranges = re
+                .captures_iter(content)
+                .filter_map(|caps| caps.get(1))
+                .map(|m| m.range())
+                .collect();
+            replace_single(content, ranges, &format!("regex `{pattern}`"), version)
+        }
+        Locator::Toml(key_path) => {
+            let mut doc = content.parse::<DocumentMut>().map_err(|e| e.to_string())?;
+            let mut item: &mut Item = doc.as_item_mut();
+            for segment in key_path.split('.') {
+                let next = match segment.parse::<usize>() {
+                    Ok(index) => item.get_mut(index),
+                    Err(_) => item.get_mut(segment),
+                };
+                // Indexing a table by key inserts an empty item when missing
+                item = next
+                    .filter(|next| !next.is_none())
+                    .ok_or_else(|| format!("TOML key `{key_path}` matched nothing"))?;
+            }
+            if !set_toml_string(Some(item), version) {
+                return Err(format!("TOML key `{key_path}` is not a string"));
+            }
+            Ok(doc.to_string())
+        }
+        Locator::Json(pointer) => {
+            let segments = json_pointer_segments(pointer)
+                .ok_or_else(|| format!("invalid JSON pointer `{pointer}` (must start with `/`)"))?;
+            let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+            json::replace_string(content, &segments, version)
+                .ok_or_else(|| format!("JSON pointer `{pointer}` matched no string value"))
+        }
+        Locator::Yaml(key_path) => {
+            let segments: Vec<&str> = key_path.split('.').collect();
+            let ranges = yaml::find_scalars(content, &segments);
+            replace_single(content, ranges, &format!("YAML key `{key_path}`"), version)
+        }
+    }
+}
+
+/// Replace the single matched range with `version`, or explain why the
+/// locator is ambiguous or stale.
+fn replace_single(
+    content: &str,
+    ranges: Vec<Range<usize>>,
+    locator: &str,
+    version: &str,
+) -> Result<String, String> {
+    match ranges.as_slice() {
+        [range] => Ok(format!(
+            "{}{version}{}",
+            &content[..range.start],
+            &content[range.end..]
+        )),
+        [] => Err(format!("{locator} matched nothing")),
+        _ => Err(format!(
+            "{locator} matched {} places; it must match exactly one",
+            ranges.len()
+        )),
+    }
+}
+
+/// Split an RFC 6901 JSON pointer into unescaped reference tokens.
+fn json_pointer_segments(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    let rest = pointer.strip_prefix('/')?;
+    Some(
+        rest.split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn entry(path: &str) -> VersionFileConfig {
+        VersionFileConfig {
+            path: path.into(),
+            ..VersionFileConfig::default()
+        }
+    }
+
+    fn apply(content: &str, file: &VersionFileConfig) -> Result<String, String> {
+        rewrite(content, file, "2.0.0")
+    }
+
+    #[test]
+    fn regex_locator() {
+        let file = VersionFileConfig {
+            regex: Some(r#"const Version = "([^"]+)""#.into()),
+            ..entry("version.go")
+        };
+        assert_eq!(
+            apply("package main\n\nconst Version = \"1.4.0\"\n", &file).unwrap(),
+            "package main\n\nconst Version = \"2.0.0\"\n"
+        );
+    }
+
+    #[test]
+    fn regex_must_match_exactly_once() {
+        let file = VersionFileConfig {
+            regex: Some(r"version-(\d+\.\d+\.\d+)".into()),
+            ..entry("README.md")
+        };
+        let err = apply("version-1.0.0 and version-1.0.0", &file).unwrap_err();
+        assert!(err.contains("matched 2 places"), "{err}");
+        let err = apply("no badge here", &file).unwrap_err();
+        assert!(err.contains("matched nothing"), "{err}");
+
+        let no_group = VersionFileConfig {
+            regex: Some(r"\d+".into()),
+            ..entry("VERSION")
+        };
+        assert!(
+            apply("1", &no_group)
+                .unwrap_err()
+                .contains("no capture group")
+        );
+    }
+
+    #[test]
+    fn toml_locator() {
+        let file = VersionFileConfig {
+            toml: Some("tool.demo.versions.0".into()),
+            ..entry("pyproject.toml")
+        };
+        let content = "[tool.demo]\nversions = [\"1.0.0\", \"0.9.0\"] # kept\n";
+        assert_eq!(
+            apply(content, &file).unwrap(),
+            "[tool.demo]\nversions = [\"2.0.0\", \"0.9.0\"] # kept\n"
+        );
+
+        let missing = VersionFileConfig {
+            toml: Some("tool.other.version".into()),
+            ..entry("pyproject.toml")
+        };
+        assert!(
+            apply(content, &missing)
+                .unwrap_err()
+                .contains("matched nothing")
+        );
+    }
+
+    #[test]
+    fn json_pointer_locator() {
+        let file = VersionFileConfig {
+            json: Some("/plugins/0/version".into()),
+            ..entry("manifest.json")
+        };
+        let content = "{\n  \"plugins\": [{ \"version\": \"1.0.0\" }]\n}\n";
+        assert_eq!(
+            apply(content, &file).unwrap(),
+            "{\n  \"plugins\": [{ \"version\": \"2.0.0\" }]\n}\n"
+        );
+        assert_eq!(
+            json_pointer_segments("/a~1b/c~0d").unwrap(),
+            vec!["a/b", "c~d"]
+        );
+        assert!(json_pointer_segments("version").is_none());
+    }
+
+    #[test]
+    fn yaml_locator() {
+        let file = VersionFileConfig {
+            yaml: Some("appVersion".into()),
+            ..entry("Chart.yaml")
+        };
+        assert_eq!(
+            apply("version: 0.3.0\nappVersion: \"1.0.0\"\n", &file).unwrap(),
+            "version: 0.3.0\nappVersion: \"2.0.0\"\n"
+        );
+    }
+
+    #[test]
+    fn requires_exactly_one_locator() {
+        let none = entry("VERSION");
+        assert!(apply("1.0.0", &none).unwrap_err().contains("exactly one"));
+
+        let both = VersionFileConfig {
+            regex: Some("(.+)".into()),
+            yaml: Some("version".into()),
+            ..entry("VERSION")
+        };
+        assert!(apply("1.0.0", &both).unwrap_err().contains("exactly one"));
+    }
+
+    #[test]
+    fn rewrites_files_and_leaves_all_untouched_on_failure() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("VERSION"), "1.0.0\n").unwrap();
+        fs::write(
+            tmp.path().join("Chart.yaml"),
+            "version: 1.0.0\nappVersion: 1.0.0\n",
+        )
+        .unwrap();
+        let root = utf8_tmp(&tmp);
+
+        let files = vec![
+            VersionFileConfig {
+                regex: Some(r"^(\S+)".into()),
+                ..entry("VERSION")
+            },
+            VersionFileConfig {
+                yaml: Some("version".into()),
+                ..entry("Chart.yaml")
+            },
+            VersionFileConfig {
+                yaml: Some("appVersion".into()),
+                ..entry("Chart.yaml")
+            },
+        ];
+        let modified = set_version(root, &files, "1.1.0").unwrap();
+        assert_eq!(modified, vec!["VERSION", "Chart.yaml"]);
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("Chart.yaml")).unwrap(),
+            "version: 1.1.0\nappVersion: 1.1.0\n"
+        );
+
+        let broken = vec![
+            files[0].clone(),
+            VersionFileConfig {
+                yaml: Some("missing".into()),
+                ..entry("Chart.yaml")
+            },
+        ];
+        let err = set_version(root, &broken, "1.2.0").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("YAML key `missing` matched nothing")
+        );
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("VERSION")).unwrap(),
+            "1.1.0\n"
+        );
+    }
+}
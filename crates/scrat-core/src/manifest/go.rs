@@ -0,0 +1,132 @@
+//! Go module checks (`go.mod`).
+//!
+//! Go modules carry no version field — the tag is the version — so nothing
+//! is rewritten. What `go.mod` does constrain is the major version: from v2
+//! on, the module path must end in a matching `/vN` suffix (`.vN` for
+//! `gopkg.in` paths), or the Go toolchain refuses the release.
+
+use camino::Utf8Path;
+use semver::Version;
+
+use super::{ManifestError, ManifestResult};
+
+/// Check that the module path's major-version suffix matches `version`.
+///
+/// v0 and v1 releases must not have a suffix; v2 and later need `/vN`
+/// with `N` equal to the major version.
+pub fn check_module_path(project_root: &Utf8Path, version: &Version) -> ManifestResult<()> {
+    let path = project_root.join("go.mod");
+    let content = super::read(&path)?;
+    let module = parse_module_path(&content).ok_or_else(|| ManifestError::Invalid {
+        path: path.clone(),
+        message: "no `module` directive".into(),
+    })?;
+
+    let suffix = major_suffix(&module);
+    let gopkg = module.starts_with("gopkg.in/");
+    let message = match (suffix, version.major) {
+        (Some(n), major) if n == major => return Ok(()),
+        // gopkg.in paths always carry the suffix, even for v0/v1
+        (None, 0 | 1) if !gopkg => return Ok(()),
+        (None, major) => {
+            let sep = if gopkg { '.' } else { '/' };
+            format!(
+                "releasing v{version} requires the module path to end in `{sep}v{major}` (found `{module}`)"
+            )
+        }
+        (Some(n), _) => {
+            format!("module path `{module}` is for v{n} releases, not v{version}")
+        }
+    };
+    Err(ManifestError::Invalid { path, message })
+}
+
+/// Extract the module path from `go.mod` content.
+fn parse_module_path(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let line = line.split("//").next()?.trim();
+        let path = line.strip_prefix("module")?;
+        if !path.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let path = path.trim().trim_matches(|c| c == '"' || c == '`');
+        (!path.is_empty()).then(|| path.to_string())
+    })
+}
+
+/// The major version encoded in a module path (`/v2`, or `.v3` for
+/// `gopkg.in`), if any.
+fn major_suffix(module: &str) -> Option<u64> {
+    let sep = if module.starts_with("gopkg.in/") {
+        '.'
+    } else {
+        '/'
+    };
+    let (_, last) = module.rsplit_once(sep)?;
+    let major: u64 = last.strip_prefix('v')?.parse().ok()?;
+    // `/v0` and `/v1` are not valid major-version suffixes
+    (major >= 2 || sep == '.').then_some(major)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn check(go_mod: &str, version: &str) -> ManifestResult<()> {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("go.mod"), go_mod).unwrap();
+        check_module_path(utf8_tmp(&tmp), &Version::parse(version).unwrap())
+    }
+
+    #[test]
+    fn parses_module_directive() {
+        assert_eq!(
+            parse_module_path("// comment\nmodule example.com/foo/v2 // trailing\n\ngo 1.22\n")
+                .as_deref(),
+            Some("example.com/foo/v2")
+        );
+        assert_eq!(
+            parse_module_path("module \"example.com/quoted\"\n").as_deref(),
+            Some("example.com/quoted")
+        );
+        assert_eq!(parse_module_path("modules example.com/nope\n"), None);
+    }
+
+    #[test]
+    fn v0_and_v1_need_no_suffix() {
+        assert!(check("module example.com/foo\n", "0.4.0").is_ok());
+        assert!(check("module example.com/foo\n", "1.9.0").is_ok());
+        assert!(check("module example.com/foo/v2\n", "1.9.0").is_err());
+    }
+
+    #[test]
+    fn v2_requires_matching_suffix() {
+        assert!(check("module example.com/foo/v2\n", "2.1.0").is_ok());
+
+        let err = check("module example.com/foo\n", "2.0.0").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("requires the module path to end in `/v2`"),
+            "{err}"
+        );
+
+        let err = check("module example.com/foo/v2\n", "3.0.0").unwrap_err();
+        assert!(
+            err.to_string().contains("is for v2 releases, not v3.0.0"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn gopkg_in_suffix() {
+        assert!(check("module gopkg.in/yaml.v3\n", "3.0.1").is_ok());
+        assert!(check("module gopkg.in/yaml.v1\n", "1.2.0").is_ok());
+        assert!(check("module gopkg.in/yaml\n", "1.2.0").is_err());
+    }
+}
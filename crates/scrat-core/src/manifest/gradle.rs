@@ -0,0 +1,169 @@
+//! Gradle project versions.
+//!
+//! The version lives in the first of these files that declares it:
+//!
+//! - `gradle.properties`: `version=1.2.3`
+//! - `build.gradle.kts`: `version = "1.2.3"`
+//! - `build.gradle`: `version = '1.2.3'` or `version '1.2.3'`
+//!
+//! A declaration must be unique within its file. Projects that derive the
+//! version elsewhere (a settings plugin, git tags) have nothing to rewrite.
+
+use std::ops::Range;
+
+use camino::Utf8Path;
+use regex::Regex;
+use semver::Version;
+use tracing::debug;
+
+use super::{ManifestError, ManifestResult};
+
+/// Files probed for a version declaration, with the pattern that finds it.
+const SOURCES: &[(&str, &str)] = &[
+    (
+        "gradle.properties",
+        r"(?m)^[ \t]*version[ \t]*[=:][ \t]*([^\s#!]+)",
+    ),
+    (
+        "build.gradle.kts",
+        r#"(?m)^[ \t]*version[ \t]*=[ \t]*"([^"$]+)""#,
+    ),
+    (
+        "build.gradle",
+        r#"(?m)^[ \t]*version[ \t]*=?[ \t]*["']([^"'$]+)["']"#,
+    ),
+];
+
+/// Read the project version from the first of `gradle.properties`,
+/// `build.gradle.kts`, and `build.gradle` that declares it.
+pub fn current_version(project_root: &Utf8Path) -> Option<String> {
+    let (_, content, range) = locate(project_root).ok()??;
+    Some(content[range].to_string())
+}
+
+/// The file declaring the version: the first of `gradle.properties`,
+/// `build.gradle.kts`, and `build.gradle` that does, if any.
+pub fn version_file(project_root: &Utf8Path) -> Option<&'static str> {
+    let (file, _, _) = locate(project_root).ok()??;
+    Some(file)
+}
+
+/// Rewrite the version declaration.
+///
+/// Returns the path (relative to `project_root`) of the file modified, or
+/// nothing if no file declares a version.
+pub fn set_version(project_root: &Utf8Path, version: &Version) -> ManifestResult<Vec<String>> {
+    let Some((file, mut content, range)) = locate(project_root)? else {
+        debug!("no Gradle version declaration — nothing to bump");
+        return Ok(Vec::new());
+    };
+    content.replace_range(range, &version.to_string());
+    super::write(&project_root.join(file), &content)?;
+    debug!(file, %version, "updated Gradle version");
+    Ok(vec![file.to_string()])
+}
+
+/// Find the file declaring the version, its content, and the value's range.
+fn locate(project_root: &Utf8Path) -> ManifestResult<Option<(&'static str, String, Range<usize>)>> {
+    for (file, pattern) in SOURCES {
+        let path = project_root.join(file);
+        if !path.is_file() {
+            continue;
+        }
+        let content = super::read(&path)?;
+        let re = Regex::new(pattern).expect("version pattern is valid");
+        let ranges: Vec<Range<usize>> = re
+            .captures_iter(&content)
+            .filter_map(|caps| caps.get(1))
+            .map(|m| m.range())
+            .collect();
+        match ranges.as_slice() {
+            [] => {}
+            [range] => {
+                let range = range.clone();
+                return Ok(Some((file, content, range)));
+            }
+            _ => {
+                return Err(ManifestError::Invalid {
+                    path,
+                    message: format!(
+                        "version is declared in {} places; it must be declared once",
+                        ranges.len()
+                    ),
+                });
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn bump(files: &[(&str, &str)]) -> (TempDir, ManifestResult<Vec<String>>) {
+        let tmp = TempDir::new().unwrap();
+        for (name, content) in files {
+            fs::write(tmp.path().join(name), content).unwrap();
+        }
+        let result = set_version(utf8_tmp(&tmp), &Version::new(2, 0, 0));
+        (tmp, result)
+    }
+
+    fn read(tmp: &TempDir, rel: &str) -> String {
+        fs::read_to_string(tmp.path().join(rel)).unwrap()
+    }
+
+    #[test]
+    fn gradle_properties_take_priority() {
+        let (tmp, result) = bump(&[
+            (
+                "gradle.properties",
+                "group=com.acme\nversion = 1.3.0-SNAPSHOT\norg.gradle.caching=true\n",
+            ),
+            ("build.gradle.kts", "plugins { `java-library` }\n"),
+        ]);
+        assert_eq!(result.unwrap(), vec!["gradle.properties"]);
+        assert_eq!(
+            read(&tmp, "gradle.properties"),
+            "group=com.acme\nversion = 2.0.0\norg.gradle.caching=true\n"
+        );
+    }
+
+    #[test]
+    fn kotlin_and_groovy_build_scripts() {
+        let (tmp, result) = bump(&[(
+            "build.gradle.kts",
+            "group = \"com.acme\"\nversion = \"1.3.0\"\n\ndependencies {\n    implementation(\"x:y:1.0\")\n}\n",
+        )]);
+        assert_eq!(result.unwrap(), vec!["build.gradle.kts"]);
+        assert!(read(&tmp, "build.gradle.kts").contains("version = \"2.0.0\""));
+
+        let (tmp, result) = bump(&[("build.gradle", "group 'com.acme'\nversion '1.3.0'\n")]);
+        assert_eq!(result.unwrap(), vec!["build.gradle"]);
+        assert_eq!(
+            read(&tmp, "build.gradle"),
+            "group 'com.acme'\nversion '2.0.0'\n"
+        );
+        assert_eq!(current_version(utf8_tmp(&tmp)).as_deref(), Some("2.0.0"));
+    }
+
+    #[test]
+    fn undeclared_and_ambiguous_versions() {
+        let (_tmp, result) = bump(&[("build.gradle.kts", "version = rootProject.version\n")]);
+        assert!(result.unwrap().is_empty());
+
+        let (_tmp, result) = bump(&[(
+            "build.gradle",
+            "version = '1.0.0'\nsubprojects {\n    version = '1.0.0'\n}\n",
+        )]);
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("declared in 2 places"), "{err}");
+    }
+}
@@ -0,0 +1,257 @@
+//! Format-preserving JSON value lookup and replacement.
+//!
+//! A minimal scanner that locates the byte range of a value addressed by a
+//! key path, without re-serializing the document. Everything outside the
+//! replaced value (indentation, key order, trailing newline) is untouched.
+//!
+//! Path segments are object keys, or array indices when the value at that
+//! level is an array.
+
+use std::ops::Range;
+
+/// Locate the byte range of the value at `path`.
+///
+/// Returns `None` if the document is malformed or any segment is missing.
+/// An empty path addresses the root value.
+pub fn find_value(text: &str, path: &[&str]) -> Option<Range<usize>> {
+    let mut scanner = Scanner::new(text);
+    scanner.skip_ws();
+    scanner.find(path)
+}
+
+/// Read the string value at `path`.
+///
+/// Returns `None` if the path is missing or the value is not a string.
+pub fn get_string(text: &str, path: &[&str]) -> Option<String> {
+    let range = find_value(text, path)?;
+    serde_json::from_str::<String>(&text[range]).ok()
+}
+
+/// Replace the string value at `path` with `value`, preserving all other bytes.
+///
+/// Returns `None` if the path is missing or the existing value is not a string.
+pub fn replace_string(text: &str, path: &[&str], value: &str) -> Option<String> {
+    let range = find_value(text, path)?;
+    if !text[range.clone()].starts_with('"') {
+        return None;
+    }
+    let encoded = serde_json::to_string(value).ok()?;
+    let mut out = String::with_capacity(text.len() + encoded.len());
+    out.push_str(&text[..range.start]);
+    out.push_str(&encoded);
+    out.push_str(&text[range.end..]);
+    Some(out)
+}
+
+/// Byte-level cursor over a JSON document.
+struct Scanner<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            bytes: text.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        self.skip_ws();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Descend into the value at the cursor following `path`.
+    fn find(&mut self, path: &[&str]) -> Option<Range<usize>> {
+        self.skip_ws();
+        let Some((segment, rest)) = path.split_first() else {
+            let start = self.pos;
+            self.skip_value()?;
+            return Some(start..self.pos);
+        };
+
+        match self.peek()? {
+            b'{' => {
+                self.pos += 1;
+                self.skip_ws();
+                if self.peek() == Some(b'}') {
+                    return None;
+                }
+                loop {
+                    self.skip_ws();
+                    let key = self.parse_string()?;
+                    self.expect(b':')?;
+                    if key == *segment {
+                        return self.find(rest);
+                    }
+                    self.skip_ws();
+                    self.skip_value()?;
+                    self.skip_ws();
+                    match self.peek()? {
+                        b',' => self.pos += 1,
+                        _ => return None,
+                    }
+                }
+            }
+            b'[' => {
+                let index: usize = segment.parse().ok()?;
+                self.pos += 1;
+                self.skip_ws();
+                if self.peek() == Some(b']') {
+                    return None;
+                }
+                let mut current = 0;
+                loop {
+                    self.skip_ws();
+                    if current == index {
+                        return self.find(rest);
+                    }
+                    self.skip_value()?;
+                    self.skip_ws();
+                    match self.peek()? {
+                        b',' => self.pos += 1,
+                        _ => return None,
+                    }
+                    current += 1;
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a string at the cursor and return its decoded contents.
+    fn parse_string(&mut self) -> Option<String> {
+        let start = self.pos;
+        self.skip_string()?;
+        serde_json::from_str(&self.text[start..self.pos]).ok()
+    }
+
+    fn skip_string(&mut self) -> Option<()> {
+        if self.peek()? != b'"' {
+            return None;
+        }
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Some(());
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_value(&mut self) -> Option<()> {
+        match self.peek()? {
+            b'"' => self.skip_string(),
+            open @ (b'{' | b'[') => {
+                let close = if open == b'{' { b'}' } else { b']' };
+                self.pos += 1;
+                self.skip_ws();
+                if self.peek() == Some(close) {
+                    self.pos += 1;
+                    return Some(());
+                }
+                loop {
+                    self.skip_ws();
+                    if open == b'{' {
+                        self.skip_string()?;
+                        self.expect(b':')?;
+                        self.skip_ws();
+                    }
+                    self.skip_value()?;
+                    self.skip_ws();
+                    match self.peek()? {
+                        b',' => self.pos += 1,
+                        c if c == close => {
+                            self.pos += 1;
+                            return Some(());
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+            _ => {
+                // Number or literal (true/false/null)
+                let start = self.pos;
+                while let Some(c) = self.peek() {
+                    if matches!(c, b',' | b'}' | b']') || c.is_ascii_whitespace() {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                (self.pos > start).then_some(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = r#"{
+  "name": "demo",
+  "version": "1.0.0",
+  "nested": { "version": "9.9.9", "list": [1, "two", {"x": "y"}] },
+  "escaped \"key\"": "v"
+}
+"#;
+
+    #[test]
+    fn finds_top_level_string() {
+        assert_eq!(get_string(DOC, &["version"]).as_deref(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn finds_nested_and_array_values() {
+        assert_eq!(
+            get_string(DOC, &["nested", "version"]).as_deref(),
+            Some("9.9.9")
+        );
+        assert_eq!(
+            get_string(DOC, &["nested", "list", "2", "x"]).as_deref(),
+            Some("y")
+        );
+        assert_eq!(get_string(DOC, &["escaped \"key\""]).as_deref(), Some("v"));
+    }
+
+    #[test]
+    fn missing_path_is_none() {
+        assert!(find_value(DOC, &["nope"]).is_none());
+        assert!(find_value(DOC, &["nested", "list", "7"]).is_none());
+    }
+
+    #[test]
+    fn replace_preserves_formatting() {
+        let out = replace_string(DOC, &["version"], "2.0.0").unwrap();
+        assert_eq!(out, DOC.replace("\"1.0.0\"", "\"2.0.0\""));
+        // The nested version must not be touched
+        assert!(out.contains("\"9.9.9\""));
+    }
+
+    #[test]
+    fn replace_rejects_non_string() {
+        assert!(replace_string(DOC, &["nested"], "x").is_none());
+    }
+}
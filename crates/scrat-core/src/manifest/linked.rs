@@ -0,0 +1,297 @@
+//! Linked npm manifests (`version.linked`).
+//!
+//! Projects that ship a native binary through npm keep a wrapper package
+//! plus one package per platform next to the primary manifest. These
+//! secondary `package.json` files follow the primary version, and so do the
+//! dependency pins between them (e.g. the wrapper's `optionalDependencies`).
+//!
+//! Patterns name a `package.json` or its directory, relative to the project
+//! root; a `*` path segment matches any directory entry
+//! (`npm/platforms/*`).
+
+use std::collections::BTreeSet;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use semver::Version;
+use tracing::debug;
+
+use super::{ManifestError, ManifestResult, json};
+use crate::glob;
+
+/// Dependency sections whose pins on linked packages are rewritten.
+const DEPENDENCY_SECTIONS: &[&str] = &[
+    "dependencies",
+    "optionalDependencies",
+    "peerDependencies",
+    "devDependencies",
+];
+
+/// A secondary `package.json` that follows the primary version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedManifest {
+    /// Path of the `package.json`, relative to the project root.
+    pub path: Utf8PathBuf,
+    /// Package name.
+    pub name: String,
+    /// Current version, if the manifest has one.
+    pub version: Option<String>,
+    /// Whether the package is marked `"private": true`.
+    pub private: bool,
+    /// Other linked packages this one depends on.
+    pub dependencies: Vec<String>,
+}
+
+impl LinkedManifest {
+    /// Directory of the package, relative to the project root.
+    pub fn dir(&self) -> &Utf8Path {
+        self.path.parent().unwrap_or_else(|| Utf8Path::new(""))
+    }
+}
+
+/// Resolve `patterns` and read every linked manifest, in path order.
+pub fn read_linked(
+    project_root: &Utf8Path,
+    patterns: &[Utf8PathBuf],
+) -> ManifestResult<Vec<LinkedManifest>> {
+    let mut paths = BTreeSet::new();
+    for pattern in patterns {
+        let matched = expand(project_root, pattern);
+        if matched.is_empty() {
+            return Err(ManifestError::Invalid {
+                path: project_root.join(pattern),
+                message: "linked manifest pattern matched no package.json".into(),
+            });
+        }
+        paths.extend(matched);
+    }
+
+    let contents = paths
+        .into_iter()
+        .map(|path| {
+            let content = super::read(&project_root.join(&path))?;
+            let name =
+                json::get_string(&content, &["name"]).ok_or_else(|| ManifestError::Invalid {
+                    path: project_root.join(&path),
+                    message: "no string `name` field".into(),
+                })?;
+            Ok((path, name, content))
+        })
+        .collect::<ManifestResult<Vec<_>>>()?;
+
+    let names: Vec<&str> = contents.iter().map(|(_, name, _)| name.as_str()).collect();
+    Ok(contents
+        .iter()
+        .map(|(path, name, content)| LinkedManifest {
+            path: path.clone(),
+            name: name.clone(),
+            version: json::get_string(content, &["version"]),
+            private: json::find_value(content, &["private"])
+                .is_some_and(|range| &content[range] == "true"),
+            dependencies: names
+                .iter()
+                .filter(|dep| {
+                    DEPENDENCY_SECTIONS
+                        .iter()
+                        .any(|section| json::find_value(content, &[section, dep]).is_some())
+                })
+                .map(|dep| (*dep).to_string())
+                .collect(),
+        })
+        .collect())
+}
+
+/// Set `version` in every linked manifest, along with the pins between them.
+///
+/// Pins keep their range operator (`^`, `~`, `=`); specs that are not a
+/// plain version (`workspace:*`, `file:…`, compound ranges) are left alone.
+/// All manifests are checked before any is written.
+///
+/// npm requires semver, so a zero-padded CalVer version is written
+/// unpadded (`26.5.1`), like the primary manifest.
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_version(
+    project_root: &Utf8Path,
+    patterns: &[Utf8PathBuf],
+    version: &Version,
+) -> ManifestResult<Vec<String>> {
+    if patterns.is_empty() {
+        return Ok(Vec::new());
+    }
+    let manifests = read_linked(project_root, patterns)?;
+    let version = version.to_string();
+
+    let mut pending = Vec::with_capacity(manifests.len());
+    for manifest in &manifests {
+        let path = project_root.join(&manifest.path);
+        let mut content = super::read(&path)?;
+        content = json::replace_string(&content, &["version"], &version).ok_or_else(|| {
+            ManifestError::Invalid {
+                path: path.clone(),
+                message: "no string `version` field".into(),
+            }
+        })?;
+        for dep in &manifest.dependencies {
+            for section in DEPENDENCY_SECTIONS {
+                let key_path = [*section, dep.as_str()];
+                if let Some(spec) = json::get_string(&content, &key_path)
+                    && let Some(pin) = repin(&spec, &version)
+                    && let Some(updated) = json::replace_string(&content, &key_path, &pin)
+                {
+                    content = updated;
+                }
+            }
+        }
+        pending.push((path, manifest.path.to_string(), content));
+    }
+
+    let mut modified = Vec::with_capacity(pending.len());
+    for (path, relative, content) in pending {
+        super::write(&path, &content)?;
+        debug!(path = %relative, "updated linked manifest");
+        modified.push(relative);
+    }
+    Ok(modified)
+}
+
+/// Rewrite a dependency spec to `version`, keeping its range operator.
+fn repin(spec: &str, version: &str) -> Option<String> {
+    let start = spec.find(|c: char| c.is_ascii_digit())?;
+    let (operator, current) = spec.split_at(start);
+    if !operator.chars().all(|c| matches!(c, '^' | '~' | '=' | 'v')) {
+        return None;
+    }
+    Version::parse(current).ok()?;
+    Some(format!("{operator}{version}"))
+}
+
+/// Expand one pattern into `package.json` paths relative to `project_root`.
+fn expand(project_root: &Utf8Path, pattern: &Utf8Path) -> Vec<Utf8PathBuf> {
+    glob::expand(project_root, pattern.as_str())
+        .into_iter()
+        .map(|path| {
+            if project_root.join(&path).is_dir() {
+                path.join("package.json")
+            } else {
+                path
+            }
+        })
+        .filter(|path| project_root.join(path).is_file())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn write(tmp: &TempDir, rel: &str, content: &str) {
+        let path = tmp.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn platform(name: &str) -> String {
+        format!(
+            "{{\n  \"name\": \"@acme/{name}\",\n  \"version\": \"0.1.0\",\n  \"os\": [\"linux\"]\n}}\n"
+        )
+    }
+
+    const WRAPPER: &str = r#"{
+  "name": "@acme/tool",
+  "version": "0.1.0",
+  "optionalDependencies": {
+    "@acme/tool-linux-x64": "0.1.0",
+    "@acme/tool-darwin-arm64": "^0.1.0",
+    "left-pad": "1.3.0"
+  }
+}
+"#;
+
+    fn npm_layout() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        write(&tmp, "npm/tool/package.json", WRAPPER);
+        write(
+            &tmp,
+            "npm/platforms/tool-linux-x64/package.json",
+            &platform("tool-linux-x64"),
+        );
+        write(
+            &tmp,
+            "npm/platforms/tool-darwin-arm64/package.json",
+            &platform("tool-darwin-arm64"),
+        );
+        tmp
+    }
+
+    fn patterns() -> Vec<Utf8PathBuf> {
+        vec!["npm/tool".into(), "npm/platforms/*".into()]
+    }
+
+    #[test]
+    fn reads_manifests_and_links_between_them() {
+        let tmp = npm_layout();
+        let manifests = read_linked(utf8_tmp(&tmp), &patterns()).unwrap();
+        let names: Vec<&str> = manifests.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "@acme/tool-darwin-arm64",
+                "@acme/tool-linux-x64",
+                "@acme/tool"
+            ]
+        );
+        assert_eq!(manifests[2].dir(), "npm/tool");
+        assert_eq!(
+            manifests[2].dependencies,
+            vec!["@acme/tool-darwin-arm64", "@acme/tool-linux-x64"]
+        );
+        assert!(manifests[0].dependencies.is_empty());
+    }
+
+    #[test]
+    fn bumps_versions_and_pins() {
+        let tmp = npm_layout();
+        let modified = set_version(utf8_tmp(&tmp), &patterns(), &Version::new(0, 2, 0)).unwrap();
+        assert_eq!(modified.len(), 3);
+
+        let wrapper = fs::read_to_string(tmp.path().join("npm/tool/package.json")).unwrap();
+        assert_eq!(
+            wrapper,
+            WRAPPER
+                .replace("\"0.1.0\"", "\"0.2.0\"")
+                .replace("^0.1.0", "^0.2.0")
+        );
+        assert!(wrapper.contains("\"left-pad\": \"1.3.0\""));
+
+        let linux =
+            fs::read_to_string(tmp.path().join("npm/platforms/tool-linux-x64/package.json"))
+                .unwrap();
+        assert!(linux.contains("\"version\": \"0.2.0\""));
+    }
+
+    #[test]
+    fn unmatched_pattern_is_an_error() {
+        let tmp = npm_layout();
+        let err = set_version(
+            utf8_tmp(&tmp),
+            &["npm/missing/*".into()],
+            &Version::new(0, 2, 0),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("matched no package.json"), "{err}");
+    }
+
+    #[test]
+    fn repin_keeps_operator() {
+        assert_eq!(repin("1.0.0", "2.0.0").as_deref(), Some("2.0.0"));
+        assert_eq!(repin("~1.0.0", "2.0.0").as_deref(), Some("~2.0.0"));
+        assert_eq!(repin("workspace:*", "2.0.0"), None);
+        assert_eq!(repin(">=1.0.0 <2.0.0", "2.0.0"), None);
+    }
+}
@@ -0,0 +1,305 @@
+//! Maven project versions (`pom.xml`).
+//!
+//! The version is the `<version>` child of `<project>`. In a multi-module
+//! build every module listed under `<modules>` is rewritten too: its own
+//! `<version>` if it declares one, and its `<parent><version>` when the
+//! parent belongs to the same build. A version given as a property
+//! reference (`${revision}`, Maven's CI-friendly versions) is set through
+//! that property in `<properties>` instead.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use semver::Version;
+use tracing::debug;
+
+use super::{ManifestError, ManifestResult, xml};
+
+const PROJECT_VERSION: &[&str] = &["project", "version"];
+const PARENT_VERSION: &[&str] = &["project", "parent", "version"];
+const PARENT_ARTIFACT: &[&str] = &["project", "parent", "artifactId"];
+const ARTIFACT: &[&str] = &["project", "artifactId"];
+const MODULES: &[&str] = &["project", "modules", "module"];
+
+/// A `pom.xml` of the build, relative to the project root.
+struct Pom {
+    path: Utf8PathBuf,
+    content: String,
+}
+
+impl Pom {
+    /// Text of the single element at `path`.
+    fn single(&self, path: &[&str]) -> Option<Range<usize>> {
+        match xml::find_text(&self.content, path).as_slice() {
+            [range] => Some(range.clone()),
+            _ => None,
+        }
+    }
+
+    /// Range of the project version, following a property reference.
+    fn version_range(&self) -> Option<Range<usize>> {
+        let range = self.single(PROJECT_VERSION)?;
+        property_name(&self.content[range.clone()]).map_or(Some(range), |property| {
+            self.single(&["project", "properties", property])
+        })
+    }
+}
+
+/// Read the project version from `pom.xml`, resolving a property reference.
+///
+/// Returns `None` if the file is missing or the version is inherited or
+/// defined outside the POM.
+pub fn current_version(project_root: &Utf8Path) -> Option<String> {
+    let pom = read_pom(project_root, Utf8PathBuf::from("pom.xml")).ok()?;
+    let range = pom.version_range()?;
+    let version = &pom.content[range];
+    (!version.contains("${")).then(|| version.to_string())
+}
+
+/// Set the version in `pom.xml` and in every module of the build.
+///
+/// All POMs are checked before any is written. Returns the paths (relative
+/// to `project_root`) of the files modified.
+pub fn set_version(project_root: &Utf8Path, version: &Version) -> ManifestResult<Vec<String>> {
+    let poms = read_reactor(project_root)?;
+    let artifacts: BTreeSet<&str> = poms
+        .iter()
+        .filter_map(|pom| pom.single(ARTIFACT).map(|range| &pom.content[range]))
+        .collect();
+    let version = version.to_string();
+
+    let mut pending = Vec::with_capacity(poms.len());
+    for (index, pom) in poms.iter().enumerate() {
+        let mut ranges = Vec::new();
+        let invalid = |message: &str| ManifestError::Invalid {
+            path: project_root.join(&pom.path),
+            message: message.into(),
+        };
+        match pom.single(PROJECT_VERSION) {
+            Some(range) => match property_name(&pom.content[range.clone()]) {
+                Some(property) => match pom.single(&["project", "properties", property]) {
+                    Some(range) => ranges.push(range),
+                    // Modules see the property defined by their parent
+                    None if index > 0 => {}
+                    None => {
+                        return Err(invalid(&format!(
+                            "`<version>` refers to `{property}`, which is not set in `<properties>`"
+                        )));
+                    }
+                },
+                None => ranges.push(range),
+            },
+            // Modules may inherit the version from their parent
+            None if index > 0 => {}
+            None => return Err(invalid("no `<version>` in `<project>`")),
+        }
+        let parent_in_build = pom
+            .single(PARENT_ARTIFACT)
+            .is_some_and(|range| artifacts.contains(&pom.content[range]));
+        if parent_in_build
+            && let Some(range) = pom.single(PARENT_VERSION)
+            && property_name(&pom.content[range.clone()]).is_none()
+        {
+            ranges.push(range);
+        }
+        if ranges.is_empty() {
+            continue;
+        }
+
+        // Replace back to front so earlier ranges stay valid
+        ranges.sort_by_key(|range| std::cmp::Reverse(range.start));
+        let mut content = pom.content.clone();
+        for range in ranges {
+            content.replace_range(range, &version);
+        }
+        if content != pom.content {
+            pending.push((&pom.path, content));
+        }
+    }
+
+    let mut modified = Vec::with_capacity(pending.len());
+    for (path, content) in pending {
+        super::write(&project_root.join(path), &content)?;
+        debug!(%path, %version, "updated pom.xml");
+        modified.push(path.to_string());
+    }
+    Ok(modified)
+}
+
+/// Read the root POM and, recursively, the POMs of its modules.
+fn read_reactor(project_root: &Utf8Path) -> ManifestResult<Vec<Pom>> {
+    let mut poms: Vec<Pom> = Vec::new();
+    let mut queue = vec![Utf8PathBuf::from("pom.xml")];
+    while let Some(path) = queue.pop() {
+        if poms.iter().any(|pom| pom.path == path) {
+            continue;
+        }
+        let pom = read_pom(project_root, path)?;
+        let dir = pom.path.parent().unwrap_or_else(|| Utf8Path::new(""));
+        let mut modules: Vec<Utf8PathBuf> = xml::find_text(&pom.content, MODULES)
+            .into_iter()
+            .map(|range| module_pom(dir, &pom.content[range]))
+            .collect();
+        // Visit modules in declaration order
+        modules.reverse();
+        queue.extend(modules);
+        poms.push(pom);
+    }
+    Ok(poms)
+}
+
+fn read_pom(project_root: &Utf8Path, path: Utf8PathBuf) -> ManifestResult<Pom> {
+    let content = super::read(&project_root.join(&path))?;
+    Ok(Pom { path, content })
+}
+
+/// Path of a module's POM: `<module>` names a directory or a POM file.
+fn module_pom(dir: &Utf8Path, module: &str) -> Utf8PathBuf {
+    let module = module.trim_end_matches('/');
+    let path = if dir.as_str().is_empty() {
+        Utf8PathBuf::from(module)
+    } else {
+        dir.join(module)
+    };
+    if module.ends_with(".xml") {
+        path
+    } else {
+        path.join("pom.xml")
+    }
+}
+
+/// The property named by a `${name}` reference.
+fn property_name(value: &str) -> Option<&str> {
+    value.strip_prefix("${")?.strip_suffix('}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn write(tmp: &TempDir, rel: &str, content: &str) {
+        let path = tmp.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn read(tmp: &TempDir, rel: &str) -> String {
+        fs::read_to_string(tmp.path().join(rel)).unwrap()
+    }
+
+    const ROOT: &str = r#"<project>
+  <groupId>com.acme</groupId>
+  <artifactId>acme-parent</artifactId>
+  <version>1.4.0-SNAPSHOT</version>
+  <packaging>pom</packaging>
+  <modules>
+    <module>core</module>
+    <module>cli</module>
+  </modules>
+</project>
+"#;
+
+    const CORE: &str = r#"<project>
+  <parent>
+    <groupId>com.acme</groupId>
+    <artifactId>acme-parent</artifactId>
+    <version>1.4.0-SNAPSHOT</version>
+  </parent>
+  <artifactId>acme-core</artifactId>
+  <dependencies>
+    <dependency>
+      <artifactId>guava</artifactId>
+      <version>33.0.0-jre</version>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+    const CLI: &str = r#"<project>
+  <parent>
+    <groupId>org.springframework.boot</groupId>
+    <artifactId>spring-boot-starter-parent</artifactId>
+    <version>3.2.0</version>
+  </parent>
+  <artifactId>acme-cli</artifactId>
+  <version>1.4.0-SNAPSHOT</version>
+</project>
+"#;
+
+    #[test]
+    fn single_module() {
+        let tmp = TempDir::new().unwrap();
+        write(&tmp, "pom.xml", CLI);
+        let root = utf8_tmp(&tmp);
+        assert_eq!(current_version(root).as_deref(), Some("1.4.0-SNAPSHOT"));
+
+        let modified = set_version(root, &Version::new(1, 4, 0)).unwrap();
+        assert_eq!(modified, vec!["pom.xml"]);
+        assert_eq!(
+            read(&tmp, "pom.xml"),
+            CLI.replace("<version>1.4.0-SNAPSHOT", "<version>1.4.0")
+        );
+    }
+
+    #[test]
+    fn multi_module_parents_and_children() {
+        let tmp = TempDir::new().unwrap();
+        write(&tmp, "pom.xml", ROOT);
+        write(&tmp, "core/pom.xml", CORE);
+        write(&tmp, "cli/pom.xml", CLI);
+
+        let modified = set_version(utf8_tmp(&tmp), &Version::new(1, 4, 0)).unwrap();
+        assert_eq!(modified, vec!["pom.xml", "core/pom.xml", "cli/pom.xml"]);
+
+        assert!(read(&tmp, "pom.xml").contains("<version>1.4.0</version>"));
+        // Inherited version: only the parent reference changes
+        assert_eq!(
+            read(&tmp, "core/pom.xml"),
+            CORE.replace("1.4.0-SNAPSHOT", "1.4.0")
+        );
+        // External parent is left alone
+        let cli = read(&tmp, "cli/pom.xml");
+        assert!(cli.contains("<version>3.2.0</version>"));
+        assert!(cli.contains("<version>1.4.0</version>"));
+    }
+
+    #[test]
+    fn ci_friendly_revision_property() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            &tmp,
+            "pom.xml",
+            "<project>\n  <artifactId>demo</artifactId>\n  <version>${revision}</version>\n  <properties>\n    <revision>0.9.0</revision>\n  </properties>\n</project>\n",
+        );
+        let root = utf8_tmp(&tmp);
+        assert_eq!(current_version(root).as_deref(), Some("0.9.0"));
+
+        set_version(root, &Version::new(1, 0, 0)).unwrap();
+        let pom = read(&tmp, "pom.xml");
+        assert!(pom.contains("<version>${revision}</version>"));
+        assert!(pom.contains("<revision>1.0.0</revision>"));
+    }
+
+    #[test]
+    fn missing_version_is_an_error() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            &tmp,
+            "pom.xml",
+            "<project>\n  <artifactId>demo</artifactId>\n</project>\n",
+        );
+        let err = set_version(utf8_tmp(&tmp), &Version::new(1, 0, 0)).unwrap_err();
+        assert!(err.to_string().contains("no `<version>`"), "{err}");
+
+        write(&tmp, "pom.xml", ROOT);
+        let err = set_version(utf8_tmp(&tmp), &Version::new(1, 0, 0)).unwrap_err();
+        assert!(err.to_string().contains("core/pom.xml"), "{err}");
+    }
+}
@@ -0,0 +1,112 @@
+//! Format-preserving version rewrites for project manifests.
+//!
+//! Each ecosystem submodule knows which files carry the project version and
+//! how to rewrite them without disturbing surrounding formatting.
+//! [`files`] rewrites the extra files declared in `[[version.files]]`, and
+//! [`linked`] keeps secondary npm manifests (`version.linked`) in step.
+
+pub mod cargo;
+pub mod files;
+pub mod go;
+pub mod gradle;
+pub mod json;
+pub mod linked;
+pub mod maven;
+pub mod node;
+pub mod php;
+pub mod python;
+pub mod ruby;
+pub mod xml;
+pub mod yaml;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use thiserror::Error;
+use toml_edit::{DocumentMut, Item, Value};
+
+use crate::config::Config;
+use crate::ecosystem::Ecosystem;
+
+/// Errors from reading or rewriting manifest files.
+#[derive(Error, Debug)]
+pub enum ManifestError {
+    /// Failed to read or write a manifest file.
+    #[error("failed to access {path}: {source}")]
+    Io {
+        /// The file being accessed.
+        path: Utf8PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+
+    /// A manifest could not be parsed or lacks the expected field.
+    #[error("{path}: {message}")]
+    Invalid {
+        /// The offending file.
+        path: Utf8PathBuf,
+        /// What was wrong with it.
+        message: String,
+    },
+}
+
+/// Result alias for manifest operations.
+pub type ManifestResult<T> = Result<T, ManifestError>;
+
+/// Read the current version from the ecosystem's manifest, via its
+/// [provider](crate::provider::EcosystemProvider::current_version).
+///
+/// Returns `None` if the manifest is missing, unparseable, or carries no
+/// version (and always for [`Ecosystem::Go`] and [`Ecosystem::Generic`]).
+/// Python projects whose version is derived from git tags also return
+/// `None`. A Python project's configured `project.version_module` is read
+/// too, as `bump` writes it.
+pub fn current_version(
+    project_root: &Utf8Path,
+    ecosystem: Ecosystem,
+    config: &Config,
+) -> Option<String> {
+    let version_module = config
+        .project
+        .as_ref()
+        .and_then(|p| p.version_module.as_deref());
+    ecosystem
+        .provider()
+        .current_version(project_root, version_module)
+}
+
+/// Read a manifest file to a string.
+pub(crate) fn read(path: &Utf8Path) -> ManifestResult<String> {
+    std::fs::read_to_string(path).map_err(|source| ManifestError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Write a manifest file.
+pub(crate) fn write(path: &Utf8Path, content: &str) -> ManifestResult<()> {
+    std::fs::write(path, content).map_err(|source| ManifestError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Parse a TOML document, mapping errors to [`ManifestError::Invalid`].
+pub(crate) fn parse_toml(path: &Utf8Path, content: &str) -> ManifestResult<DocumentMut> {
+    content
+        .parse::<DocumentMut>()
+        .map_err(|e| ManifestError::Invalid {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+}
+
+/// Replace a TOML string value in place, keeping its surrounding whitespace
+/// and comments. Returns `false` if the item is missing or not a string.
+pub(crate) fn set_toml_string(item: Option<&mut Item>, value: &str) -> bool {
+    let Some(Item::Value(existing @ Value::String(_))) = item else {
+        return false;
+    };
+    let decor = existing.decor().clone();
+    *existing = Value::from(value);
+    *existing.decor_mut() = decor;
+    true
+}
@@ -0,0 +1,207 @@
+//! Node.js manifest rewrites (`package.json`, `package-lock.json`).
+
+use camino::Utf8Path;
+use semver::Version;
+use tracing::debug;
+
+use super::{ManifestError, ManifestResult, json};
+
+/// Lockfiles that record the root package version.
+const NPM_LOCKFILES: &[&str] = &["package-lock.json", "npm-shrinkwrap.json"];
+
+/// Read the `version` field from `package.json`.
+pub fn current_version(project_root: &Utf8Path) -> Option<String> {
+    let content = std::fs::read_to_string(project_root.join("package.json")).ok()?;
+    json::get_string(&content, &["version"])
+}
+
+/// Set the version in `package.json` and the root entries of npm lockfiles.
+///
+/// `package.json` must already contain a string `version` field. In
+/// `package-lock.json` (and `npm-shrinkwrap.json`), the top-level
+/// `version` and `packages[""].version` are updated when present.
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_version(project_root: &Utf8Path, version: &Version) -> ManifestResult<Vec<String>> {
+    let version = version.to_string();
+    let manifest_path = project_root.join("package.json");
+    let content = super::read(&manifest_path)?;
+    let updated = json::replace_string(&content, &["version"], &version).ok_or_else(|| {
+        ManifestError::Invalid {
+            path: manifest_path.clone(),
+            message: "no string `version` field".into(),
+        }
+    })?;
+    super::write(&manifest_path, &updated)?;
+    debug!(%version, "updated package.json");
+
+    let mut modified = vec!["package.json".to_string()];
+
+    for lockfile in NPM_LOCKFILES {
+        let path = project_root.join(lockfile);
+        if !path.is_file() {
+            continue;
+        }
+        let mut content = super::read(&path)?;
+        let mut changed = false;
+        for key_path in [&["version"][..], &["packages", "", "version"][..]] {
+            if let Some(updated) = json::replace_string(&content, key_path, &version) {
+                content = updated;
+                changed = true;
+            }
+        }
+        if changed {
+            super::write(&path, &content)?;
+            debug!(%lockfile, "updated lockfile root version");
+            modified.push((*lockfile).to_string());
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Set the version of a workspace package released on its own.
+///
+/// Rewrites `package.json` (and any lockfile) in `package_dir`, relative to
+/// the repository root, plus the package's `packages["<dir>"].version` entry
+/// in the root npm lockfile, which is where npm workspaces record it.
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_workspace_version(
+    project_root: &Utf8Path,
+    package_dir: &Utf8Path,
+    version: &Version,
+) -> ManifestResult<Vec<String>> {
+    let mut modified: Vec<String> = set_version(&project_root.join(package_dir), version)?
+        .into_iter()
+        .map(|file| package_dir.join(file).into_string())
+        .collect();
+
+    let version = version.to_string();
+    for lockfile in NPM_LOCKFILES {
+        let path = project_root.join(lockfile);
+        if !path.is_file() || modified.iter().any(|m| m == lockfile) {
+            continue;
+        }
+        let content = super::read(&path)?;
+        if let Some(updated) = json::replace_string(
+            &content,
+            &["packages", package_dir.as_str(), "version"],
+            &version,
+        ) {
+            super::write(&path, &updated)?;
+            debug!(%lockfile, %package_dir, "updated workspace entry in lockfile");
+            modified.push((*lockfile).to_string());
+        }
+    }
+
+    Ok(modified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    const PACKAGE_JSON: &str = "{\n    \"name\": \"demo\",\n    \"version\": \"1.2.3\",\n    \"dependencies\": {\n        \"left-pad\": \"^1.3.0\"\n    }\n}\n";
+
+    const PACKAGE_LOCK: &str = r#"{
+  "name": "demo",
+  "version": "1.2.3",
+  "lockfileVersion": 3,
+  "packages": {
+    "": {
+      "name": "demo",
+      "version": "1.2.3"
+    },
+    "node_modules/left-pad": {
+      "version": "1.2.3"
+    }
+  }
+}
+"#;
+
+    #[test]
+    fn rewrites_package_json_preserving_format() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("package.json"), PACKAGE_JSON).unwrap();
+
+        let modified = set_version(utf8_tmp(&tmp), &Version::new(2, 0, 0)).unwrap();
+        assert_eq!(modified, vec!["package.json"]);
+
+        let content = fs::read_to_string(tmp.path().join("package.json")).unwrap();
+        assert_eq!(content, PACKAGE_JSON.replace("1.2.3", "2.0.0"));
+    }
+
+    #[test]
+    fn rewrites_lockfile_root_entries_only() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("package.json"), PACKAGE_JSON).unwrap();
+        fs::write(tmp.path().join("package-lock.json"), PACKAGE_LOCK).unwrap();
+
+        let modified = set_version(utf8_tmp(&tmp), &Version::new(2, 0, 0)).unwrap();
+        assert_eq!(modified, vec!["package.json", "package-lock.json"]);
+
+        let lock = fs::read_to_string(tmp.path().join("package-lock.json")).unwrap();
+        assert_eq!(lock.matches("\"2.0.0\"").count(), 2);
+        // Dependency entry with the same version is untouched
+        assert!(lock.contains("\"node_modules/left-pad\": {\n      \"version\": \"1.2.3\""));
+    }
+
+    #[test]
+    fn rewrites_workspace_entry_in_root_lockfile() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("packages/ui")).unwrap();
+        fs::write(tmp.path().join("packages/ui/package.json"), PACKAGE_JSON).unwrap();
+        fs::write(
+            tmp.path().join("package-lock.json"),
+            r#"{
+  "name": "monorepo",
+  "version": "1.2.3",
+  "packages": {
+    "": { "name": "monorepo", "version": "1.2.3" },
+    "packages/ui": { "name": "demo", "version": "1.2.3" }
+  }
+}
+"#,
+        )
+        .unwrap();
+
+        let modified = set_workspace_version(
+            utf8_tmp(&tmp),
+            Utf8Path::new("packages/ui"),
+            &Version::new(1, 3, 0),
+        )
+        .unwrap();
+        assert_eq!(
+            modified,
+            vec!["packages/ui/package.json", "package-lock.json"]
+        );
+
+        let lock = fs::read_to_string(tmp.path().join("package-lock.json")).unwrap();
+        assert!(lock.contains("\"packages/ui\": { \"name\": \"demo\", \"version\": \"1.3.0\" }"));
+        // The root package keeps its own version
+        assert_eq!(lock.matches("\"1.2.3\"").count(), 2);
+    }
+
+    #[test]
+    fn reads_current_version() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("package.json"), PACKAGE_JSON).unwrap();
+        assert_eq!(current_version(utf8_tmp(&tmp)).as_deref(), Some("1.2.3"));
+    }
+
+    #[test]
+    fn missing_version_field_is_an_error() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("package.json"), "{\"name\": \"demo\"}").unwrap();
+
+        let err = set_version(utf8_tmp(&tmp), &Version::new(1, 0, 0)).unwrap_err();
+        assert!(matches!(err, ManifestError::Invalid { .. }));
+    }
+}
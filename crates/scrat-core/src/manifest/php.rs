@@ -0,0 +1,80 @@
+//! PHP Composer versions (`composer.json`).
+//!
+//! Packagist reads versions from tags, and Composer discourages a
+//! `version` field in `composer.json`. When a project does carry one, it
+//! is kept in step; otherwise there is nothing to rewrite.
+
+use camino::Utf8Path;
+use semver::Version;
+use tracing::debug;
+
+use super::{ManifestResult, json};
+
+/// Read the `version` field from `composer.json`, if it has one.
+pub fn current_version(project_root: &Utf8Path) -> Option<String> {
+    let content = std::fs::read_to_string(project_root.join("composer.json")).ok()?;
+    json::get_string(&content, &["version"])
+}
+
+/// Set the `version` field in `composer.json` when it is present.
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_version(project_root: &Utf8Path, version: &Version) -> ManifestResult<Vec<String>> {
+    let path = project_root.join("composer.json");
+    let content = super::read(&path)?;
+    let Some(updated) = json::replace_string(&content, &["version"], &version.to_string()) else {
+        debug!("composer.json has no version — Packagist reads it from the tag");
+        return Ok(Vec::new());
+    };
+    super::write(&path, &updated)?;
+    debug!(%version, "updated composer.json");
+    Ok(vec!["composer.json".into()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    #[test]
+    fn version_field_is_optional() {
+        let tmp = TempDir::new().unwrap();
+        let root = utf8_tmp(&tmp);
+        let manifest = "{\n    \"name\": \"acme/widgets\",\n    \"type\": \"library\"\n}\n";
+        fs::write(tmp.path().join("composer.json"), manifest).unwrap();
+
+        assert_eq!(current_version(root), None);
+        assert!(
+            set_version(root, &Version::new(1, 0, 0))
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("composer.json")).unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    fn bumps_declared_version() {
+        let tmp = TempDir::new().unwrap();
+        let root = utf8_tmp(&tmp);
+        fs::write(
+            tmp.path().join("composer.json"),
+            "{\n    \"name\": \"acme/widgets\",\n    \"version\": \"1.0.0\"\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(current_version(root).as_deref(), Some("1.0.0"));
+        assert_eq!(
+            set_version(root, &Version::new(1, 1, 0)).unwrap(),
+            vec!["composer.json"]
+        );
+        assert_eq!(current_version(root).as_deref(), Some("1.1.0"));
+    }
+}
@@ -0,0 +1,460 @@
+//! Python manifest rewrites (`pyproject.toml`, `__version__` modules, `uv.lock`).
+//!
+//! The version may live in PEP 621 `[project].version`, Poetry's
+//! `[tool.poetry].version`, or a `__version__ = "..."` assignment in a
+//! module. Projects that derive it from git at build time (hatch-vcs,
+//! setuptools-scm, poetry-dynamic-versioning) have nothing to rewrite: the
+//! release tag is the source of truth.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use semver::Version;
+use toml_edit::{DocumentMut, Item};
+use tracing::debug;
+
+use super::{ManifestError, ManifestResult, parse_toml, set_toml_string};
+
+/// Read the current version.
+///
+/// Checks `[project].version`, then `[tool.poetry].version`, then the
+/// `__version__` of `version_module` (or the module named by
+/// `[tool.hatch.version].path` or setuptools' `attr:` directive). Returns
+/// `None` when the version is derived from git tags.
+pub fn current_version(
+    project_root: &Utf8Path,
+    version_module: Option<&Utf8Path>,
+) -> Option<String> {
+    let content = std::fs::read_to_string(project_root.join("pyproject.toml")).ok()?;
+    let doc = content.parse::<DocumentMut>().ok()?;
+
+    if let Some(version) = project_version(&doc).or_else(|| poetry_version(&doc)) {
+        return Some(version.to_string());
+    }
+
+    let module = version_module
+        .map(Utf8Path::to_owned)
+        .or_else(|| discover_version_module(project_root, &doc))?;
+    let source = std::fs::read_to_string(project_root.join(module)).ok()?;
+    read_dunder_version(&source)
+}
+
+/// Whether `pyproject.toml` declares a dynamic version (`dynamic =
+/// ["version"]` or poetry-dynamic-versioning).
+pub fn is_dynamic_version(project_root: &Utf8Path) -> bool {
+    std::fs::read_to_string(project_root.join("pyproject.toml"))
+        .ok()
+        .and_then(|content| content.parse::<DocumentMut>().ok())
+        .is_some_and(|doc| declares_dynamic_version(&doc))
+}
+
+/// Set the version in every place the project keeps it.
+///
+/// - `[project].version` and `[tool.poetry].version` in `pyproject.toml`,
+///   whichever are present as plain strings
+/// - `__version__` in `version_module`, or in the module discovered from
+///   `[tool.hatch.version].path` / `[tool.setuptools.dynamic]`
+/// - the project's own entry in `uv.lock`
+///
+/// A dynamic version with no module to rewrite is left to the build
+/// backend, which reads it from the release tag; nothing is modified.
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_version(
+    project_root: &Utf8Path,
+    version: &Version,
+    version_module: Option<&Utf8Path>,
+) -> ManifestResult<Vec<String>> {
+    let version_str = version.to_string();
+    let manifest_path = project_root.join("pyproject.toml");
+    let mut doc = parse_toml(&manifest_path, &super::read(&manifest_path)?)?;
+    let dynamic = declares_dynamic_version(&doc);
+    let mut modified = Vec::new();
+
+    let project_bumped = doc
+        .get_mut("project")
+        .is_some_and(|p| set_toml_string(p.get_mut("version"), &version_str));
+    let poetry_bumped = !dynamic
+        && doc
+            .get_mut("tool")
+            .and_then(|t| t.get_mut("poetry"))
+            .is_some_and(|p| set_toml_string(p.get_mut("version"), &version_str));
+    if project_bumped || poetry_bumped {
+        super::write(&manifest_path, &doc.to_string())?;
+        debug!(%version, project_bumped, poetry_bumped, "updated pyproject.toml");
+        modified.push("pyproject.toml".to_string());
+    }
+
+    let module = version_module
+        .map(Utf8Path::to_owned)
+        .or_else(|| discover_version_module(project_root, &doc));
+    if let Some(module) = module {
+        let path = project_root.join(&module);
+        let source = super::read(&path)?;
+        let updated = replace_dunder_version(&source, &version_str).ok_or_else(|| {
+            ManifestError::Invalid {
+                path: path.clone(),
+                message: "no `__version__ = \"...\"` assignment".into(),
+            }
+        })?;
+        super::write(&path, &updated)?;
+        debug!(%module, "updated __version__");
+        modified.push(module.into_string());
+    }
+
+    if modified.is_empty() {
+        if dynamic {
+            debug!("dynamic version — the release tag is the source of truth");
+            return Ok(modified);
+        }
+        return Err(ManifestError::Invalid {
+            path: manifest_path,
+            message: "no `[project].version`, `[tool.poetry].version`, or `__version__` to update"
+                .into(),
+        });
+    }
+
+    if let Some(name) = project_name(&doc)
+        && update_uv_lock(project_root, &name, &version_str)?
+    {
+        modified.push("uv.lock".into());
+    }
+
+    Ok(modified)
+}
+
+/// `[project].version` as a plain string.
+fn project_version(doc: &DocumentMut) -> Option<&str> {
+    doc.get("project")
+        .and_then(|p| p.get("version"))
+        .and_then(Item::as_str)
+}
+
+/// `[tool.poetry].version`, unless poetry-dynamic-versioning replaces it.
+fn poetry_version(doc: &DocumentMut) -> Option<&str> {
+    if declares_dynamic_version(doc) {
+        return None;
+    }
+    doc.get("tool")
+        .and_then(|t| t.get("poetry"))
+        .and_then(|p| p.get("version"))
+        .and_then(Item::as_str)
+}
+
+/// The project name from `[project]` or `[tool.poetry]`.
+fn project_name(doc: &DocumentMut) -> Option<String> {
+    doc.get("project")
+        .and_then(|p| p.get("name"))
+        .or_else(|| {
+            doc.get("tool")
+                .and_then(|t| t.get("poetry"))
+                .and_then(|p| p.get("name"))
+        })
+        .and_then(Item::as_str)
+        .map(normalize_name)
+}
+
+/// Whether the version is computed by the build backend.
+fn declares_dynamic_version(doc: &DocumentMut) -> bool {
+    let listed = doc
+        .get("project")
+        .and_then(|p| p.get("dynamic"))
+        .and_then(Item::as_array)
+        .is_some_and(|fields| fields.iter().any(|f| f.as_str() == Some("version")));
+    let poetry_dynamic = doc
+        .get("tool")
+        .and_then(|t| t.get("poetry-dynamic-versioning"))
+        .and_then(|p| p.get("enable"))
+        .and_then(Item::as_bool)
+        == Some(true);
+    listed || poetry_dynamic
+}
+
+/// Find the module whose `__version__` the build backend reads.
+///
+/// Hatch names the file directly (`[tool.hatch.version].path`); setuptools
+/// names an attribute (`version = { attr = "pkg.__version__" }`), which is
+/// looked up in both the `src/` and flat layouts.
+fn discover_version_module(project_root: &Utf8Path, doc: &DocumentMut) -> Option<Utf8PathBuf> {
+    let tool = doc.get("tool")?;
+
+    if let Some(path) = tool
+        .get("hatch")
+        .and_then(|h| h.get("version"))
+        .and_then(|v| v.get("path"))
+        .and_then(Item::as_str)
+    {
+        return Some(Utf8PathBuf::from(path));
+    }
+
+    let attr = tool
+        .get("setuptools")
+        .and_then(|s| s.get("dynamic"))
+        .and_then(|d| d.get("version"))
+        .and_then(|v| v.get("attr"))
+        .and_then(Item::as_str)?;
+    let module = attr.strip_suffix(".__version__")?.replace('.', "/");
+    [
+        format!("src/{module}/__init__.py"),
+        format!("{module}/__init__.py"),
+        format!("src/{module}.py"),
+        format!("{module}.py"),
+    ]
+    .into_iter()
+    .map(Utf8PathBuf::from)
+    .find(|candidate| project_root.join(candidate).is_file())
+}
+
+/// Read the string assigned to `__version__`.
+fn read_dunder_version(source: &str) -> Option<String> {
+    source.lines().find_map(|line| {
+        let (start, end) = dunder_version_span(line)?;
+        Some(line[start..end].to_string())
+    })
+}
+
+/// Replace the string assigned to `__version__`, keeping its quotes.
+fn replace_dunder_version(source: &str, version: &str) -> Option<String> {
+    let mut replaced = false;
+    let lines: Vec<String> = source
+        .split_inclusive('\n')
+        .map(|line| match dunder_version_span(line) {
+            Some((start, end)) if !replaced => {
+                replaced = true;
+                format!("{}{version}{}", &line[..start], &line[end..])
+            }
+            _ => line.to_string(),
+        })
+        .collect();
+    replaced.then(|| lines.concat())
+}
+
+/// Byte range of the quoted value in `__version__ = "..."` (optionally
+/// annotated, `__version__: str = "..."`).
+fn dunder_version_span(line: &str) -> Option<(usize, usize)> {
+    let (target, value) = line.strip_prefix("__version__")?.split_once('=')?;
+    let target = target.trim();
+    if !(target.is_empty() || target.starts_with(':')) {
+        return None;
+    }
+    let value = value.trim_start();
+    let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let start = line.len() - value.len() + 1;
+    let len = line[start..].find(quote)?;
+    Some((start, start + len))
+}
+
+/// Normalize a distribution name per PEP 503 (`My_Package` → `my-package`).
+fn normalize_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+/// Set the version of the project's own `uv.lock` entry (the one with an
+/// `editable` or `virtual` source).
+///
+/// Returns `true` if `uv.lock` exists and was changed.
+fn update_uv_lock(project_root: &Utf8Path, name: &str, version: &str) -> ManifestResult<bool> {
+    let lock_path = project_root.join("uv.lock");
+    if !lock_path.is_file() {
+        return Ok(false);
+    }
+
+    let mut doc = parse_toml(&lock_path, &super::read(&lock_path)?)?;
+    let Some(packages) = doc
+        .get_mut("package")
+        .and_then(Item::as_array_of_tables_mut)
+    else {
+        return Ok(false);
+    };
+
+    let mut changed = false;
+    for package in packages.iter_mut() {
+        let is_ours = package.get("name").and_then(Item::as_str) == Some(name)
+            && package
+                .get("source")
+                .is_some_and(|s| s.get("editable").is_some() || s.get("virtual").is_some());
+        if is_ours && package.get("version").and_then(Item::as_str) != Some(version) {
+            changed |= set_toml_string(package.get_mut("version"), version);
+        }
+    }
+
+    if changed {
+        super::write(&lock_path, &doc.to_string())?;
+        debug!("updated uv.lock");
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn read(tmp: &TempDir, rel: &str) -> String {
+        fs::read_to_string(tmp.path().join(rel)).unwrap()
+    }
+
+    const UV_LOCK: &str = r#"version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "httpx"
+version = "0.27.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "my-app"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "httpx" },
+]
+"#;
+
+    #[test]
+    fn pep621_version_and_uv_lock() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("pyproject.toml"),
+            "[project]\nname = \"My_App\"\nversion = \"0.1.0\" # bumped by scrat\n",
+        )
+        .unwrap();
+        fs::write(tmp.path().join("uv.lock"), UV_LOCK).unwrap();
+        let root = utf8_tmp(&tmp);
+
+        assert_eq!(current_version(root, None).as_deref(), Some("0.1.0"));
+        let modified = set_version(root, &Version::new(0, 2, 0), None).unwrap();
+        assert_eq!(modified, vec!["pyproject.toml", "uv.lock"]);
+
+        assert!(read(&tmp, "pyproject.toml").contains("version = \"0.2.0\" # bumped by scrat"));
+        let lock = read(&tmp, "uv.lock");
+        assert!(lock.contains("name = \"my-app\"\nversion = \"0.2.0\""));
+        assert!(lock.contains("name = \"httpx\"\nversion = \"0.27.0\""));
+    }
+
+    #[test]
+    fn poetry_version() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("pyproject.toml"),
+            "[tool.poetry]\nname = \"app\"\nversion = \"1.4.2\"\n",
+        )
+        .unwrap();
+        let root = utf8_tmp(&tmp);
+
+        assert_eq!(current_version(root, None).as_deref(), Some("1.4.2"));
+        set_version(root, &Version::new(1, 5, 0), None).unwrap();
+        assert_eq!(current_version(root, None).as_deref(), Some("1.5.0"));
+    }
+
+    #[test]
+    fn configured_version_module() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("pyproject.toml"),
+            "[project]\nname = \"app\"\nversion = \"1.0.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(tmp.path().join("src/app")).unwrap();
+        fs::write(
+            tmp.path().join("src/app/__init__.py"),
+            "\"\"\"App.\"\"\"\n\n__version__ = '1.0.0'\n",
+        )
+        .unwrap();
+        let root = utf8_tmp(&tmp);
+
+        let module = Utf8Path::new("src/app/__init__.py");
+        let modified = set_version(root, &Version::new(1, 1, 0), Some(module)).unwrap();
+        assert_eq!(modified, vec!["pyproject.toml", "src/app/__init__.py"]);
+        assert_eq!(
+            read(&tmp, "src/app/__init__.py"),
+            "\"\"\"App.\"\"\"\n\n__version__ = '1.1.0'\n"
+        );
+    }
+
+    #[test]
+    fn dynamic_version_from_setuptools_attr() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("pyproject.toml"),
+            "[project]\nname = \"app\"\ndynamic = [\"version\"]\n\n\
+             [tool.setuptools.dynamic]\nversion = { attr = \"app.__version__\" }\n",
+        )
+        .unwrap();
+        fs::create_dir_all(tmp.path().join("app")).unwrap();
+        fs::write(
+            tmp.path().join("app/__init__.py"),
+            "__version__: str = \"0.3.0\"\n",
+        )
+        .unwrap();
+        let root = utf8_tmp(&tmp);
+
+        assert!(is_dynamic_version(root));
+        assert_eq!(current_version(root, None).as_deref(), Some("0.3.0"));
+        let modified = set_version(root, &Version::new(0, 4, 0), None).unwrap();
+        assert_eq!(modified, vec!["app/__init__.py"]);
+        assert_eq!(
+            read(&tmp, "app/__init__.py"),
+            "__version__: str = \"0.4.0\"\n"
+        );
+    }
+
+    #[test]
+    fn vcs_version_is_left_to_the_tag() {
+        let tmp = TempDir::new().unwrap();
+        let pyproject = "[project]\nname = \"app\"\ndynamic = [\"version\"]\n\n\
+                         [tool.hatch.version]\nsource = \"vcs\"\n";
+        fs::write(tmp.path().join("pyproject.toml"), pyproject).unwrap();
+        let root = utf8_tmp(&tmp);
+
+        assert_eq!(current_version(root, None), None);
+        let modified = set_version(root, &Version::new(2, 0, 0), None).unwrap();
+        assert!(modified.is_empty());
+        assert_eq!(read(&tmp, "pyproject.toml"), pyproject);
+    }
+
+    #[test]
+    fn static_project_without_version_errors() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("pyproject.toml"),
+            "[project]\nname = \"app\"\n",
+        )
+        .unwrap();
+        let err = set_version(utf8_tmp(&tmp), &Version::new(1, 0, 0), None).unwrap_err();
+        assert!(err.to_string().contains("no `[project].version`"));
+    }
+
+    #[test]
+    fn dunder_version_parsing() {
+        assert_eq!(
+            read_dunder_version("__version__ = \"1.2.3\"\n").as_deref(),
+            Some("1.2.3")
+        );
+        assert_eq!(read_dunder_version("__version_info__ = (1, 2)\n"), None);
+        assert_eq!(read_dunder_version("__version__ = get_version()\n"), None);
+        assert_eq!(
+            replace_dunder_version("x = 1\n__version__='0.1'\n", "0.2.0").as_deref(),
+            Some("x = 1\n__version__='0.2.0'\n")
+        );
+    }
+
+    #[test]
+    fn name_normalization() {
+        assert_eq!(normalize_name("My_App.core"), "my-app-core");
+        assert_eq!(normalize_name("a--b__c"), "a-b-c");
+    }
+}
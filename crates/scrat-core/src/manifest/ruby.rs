@@ -0,0 +1,300 @@
+//! Ruby gem versions.
+//!
+//! A gem's version conventionally lives in `lib/<name>/version.rb` as
+//! `VERSION = "1.2.3"`; every such file under `lib/` is rewritten. Gems
+//! without one declare it in the gemspec (`spec.version = "1.2.3"`),
+//! which is rewritten instead. The gem's own entry in the `PATH` section
+//! of `Gemfile.lock` follows along.
+
+use std::ops::Range;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use regex::Regex;
+use semver::Version;
+use tracing::debug;
+
+use super::{ManifestError, ManifestResult};
+
+const VERSION_CONSTANT: &str = r#"(?m)^[ \t]*VERSION[ \t]*=[ \t]*["']([^"']+)["']"#;
+const GEMSPEC_VERSION: &str = r#"(?m)^[ \t]*\w+\.version[ \t]*=[ \t]*["']([^"']+)["']"#;
+const GEMSPEC_NAME: &str = r#"(?m)^[ \t]*\w+\.name[ \t]*=[ \t]*["']([^"']+)["']"#;
+
+/// A file declaring the version, with the ranges of the version strings.
+struct VersionSource {
+    path: Utf8PathBuf,
+    content: String,
+    ranges: Vec<Range<usize>>,
+}
+
+/// The gemspec in `dir` (the first by name if there are several).
+pub fn gemspec(dir: &Utf8Path) -> Option<Utf8PathBuf> {
+    let mut specs: Vec<Utf8PathBuf> = dir
+        .read_dir_utf8()
+        .ok()?
+        .flatten()
+        .map(|entry| Utf8PathBuf::from(entry.file_name()))
+        .filter(|name| name.extension() == Some("gemspec"))
+        .collect();
+    specs.sort();
+    specs.into_iter().next()
+}
+
+/// Read the gem version from `lib/**/version.rb` or the gemspec.
+pub fn current_version(project_root: &Utf8Path) -> Option<String> {
+    let source = version_sources(project_root).ok()?.into_iter().next()?;
+    Some(source.content[source.ranges.first()?.clone()].to_string())
+}
+
+/// Rewrite the gem version.
+///
+/// Returns the paths (relative to `project_root`) of the files modified.
+pub fn set_version(project_root: &Utf8Path, version: &Version) -> ManifestResult<Vec<String>> {
+    let version = version.to_string();
+    let sources = version_sources(project_root)?;
+    if sources.is_empty() {
+        return Err(ManifestError::Invalid {
+            path: project_root.join("lib"),
+            message: "no `VERSION = \"...\"` in lib/**/version.rb and no `version` in the gemspec"
+                .into(),
+        });
+    }
+
+    let mut modified = Vec::with_capacity(sources.len() + 1);
+    for source in sources {
+        let updated = replace_all(&source.content, &source.ranges, &version);
+        super::write(&project_root.join(&source.path), &updated)?;
+        debug!(path = %source.path, %version, "updated gem version");
+        modified.push(source.path.into_string());
+    }
+
+    if let Some(lockfile) = update_gemfile_lock(project_root, &version)? {
+        modified.push(lockfile);
+    }
+    Ok(modified)
+}
+
+/// Files declaring the version, with their content and the value ranges.
+///
+/// `lib/**/version.rb` files win; the gemspec is the fallback.
+fn version_sources(project_root: &Utf8Path) -> ManifestResult<Vec<VersionSource>> {
+    let mut version_files = Vec::new();
+    find_version_files(project_root, Utf8Path::new("lib"), &mut version_files);
+    version_files.sort();
+
+    let mut sources = Vec::new();
+    for path in version_files {
+        let content = super::read(&project_root.join(&path))?;
+        let ranges = capture_ranges(VERSION_CONSTANT, &content);
+        if !ranges.is_empty() {
+            sources.push(VersionSource {
+                path,
+                content,
+                ranges,
+            });
+        }
+    }
+    if sources.is_empty()
+        && let Some(spec) = gemspec(project_root)
+    {
+        let content = super::read(&project_root.join(&spec))?;
+        let ranges = capture_ranges(GEMSPEC_VERSION, &content);
+        if !ranges.is_empty() {
+            sources.push(VersionSource {
+                path: spec,
+                content,
+                ranges,
+            });
+        }
+    }
+    Ok(sources)
+}
+
+/// Collect `version.rb` files under `dir` (relative to `project_root`).
+fn find_version_files(project_root: &Utf8Path, dir: &Utf8Path, found: &mut Vec<Utf8PathBuf>) {
+    let Ok(entries) = project_root.join(dir).read_dir_utf8() else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = dir.join(entry.file_name());
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            find_version_files(project_root, &path, found);
+        } else if entry.file_name() == "version.rb" {
+            found.push(path);
+        }
+    }
+}
+
+/// Update the gem's own spec line in the `PATH` section of `Gemfile.lock`.
+fn update_gemfile_lock(project_root: &Utf8Path, version: &str) -> ManifestResult<Option<String>> {
+    let path = project_root.join("Gemfile.lock");
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let Some(name) = gem_name(project_root) else {
+        return Ok(None);
+    };
+    let content = super::read(&path)?;
+
+    let mut ranges = Vec::new();
+    let mut in_path = false;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let text = line.trim_end();
+        if !text.starts_with(' ') {
+            in_path = text == "PATH";
+            continue;
+        }
+        if in_path
+            && let Some(rest) = text.strip_prefix("    ")
+            && let Some(spec_version) = rest
+                .strip_prefix(name.as_str())
+                .and_then(|r| r.strip_prefix(" ("))
+                .and_then(|r| r.strip_suffix(')'))
+        {
+            let start = line_start + 4 + name.len() + 2;
+            ranges.push(start..start + spec_version.len());
+        }
+    }
+    if ranges.is_empty() {
+        return Ok(None);
+    }
+    super::write(&path, &replace_all(&content, &ranges, version))?;
+    debug!(gem = %name, "updated Gemfile.lock");
+    Ok(Some("Gemfile.lock".into()))
+}
+
+/// The gem name from the gemspec's `name`, or the gemspec's file stem.
+pub fn gem_name(project_root: &Utf8Path) -> Option<String> {
+    let spec = gemspec(project_root)?;
+    let declared = std::fs::read_to_string(project_root.join(&spec))
+        .ok()
+        .and_then(|content| {
+            capture_ranges(GEMSPEC_NAME, &content)
+                .first()
+                .map(|range| content[range.clone()].to_string())
+        });
+    declared.or_else(|| spec.file_stem().map(str::to_string))
+}
+
+/// Ranges of the first capture group of every match of `pattern`.
+fn capture_ranges(pattern: &str, content: &str) -> Vec<Range<usize>> {
+    Regex::new(pattern)
+        .expect("version pattern is valid")
+        .captures_iter(content)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| m.range())
+        .collect()
+}
+
+/// Replace every range (in ascending order) with `value`.
+fn replace_all(content: &str, ranges: &[Range<usize>], value: &str) -> String {
+    let mut updated = content.to_string();
+    for range in ranges.iter().rev() {
+        updated.replace_range(range.clone(), value);
+    }
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn write(tmp: &TempDir, rel: &str, content: &str) {
+        let path = tmp.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn read(tmp: &TempDir, rel: &str) -> String {
+        fs::read_to_string(tmp.path().join(rel)).unwrap()
+    }
+
+    const GEMFILE_LOCK: &str = "PATH
+  remote: .
+  specs:
+    acme-widgets (0.3.0)
+      rack (>= 2.0)
+
+GEM
+  remote: https://rubygems.org/
+  specs:
+    rack (3.0.8)
+
+BUNDLED WITH
+   2.5.3
+";
+
+    /// A gem laid out the way `bundle gem` generates it.
+    fn gem_fixture() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        write(
+            &tmp,
+            "acme-widgets.gemspec",
+            "Gem::Specification.new do |spec|\n  spec.name = \"acme-widgets\"\n  spec.version = Acme::Widgets::VERSION\nend\n",
+        );
+        write(
+            &tmp,
+            "lib/acme/widgets/version.rb",
+            "# frozen_string_literal: true\n\nmodule Acme\n  module Widgets\n    VERSION = \"0.3.0\"\n  end\nend\n",
+        );
+        write(
+            &tmp,
+            "lib/acme/widgets.rb",
+            "require_relative \"widgets/version\"\n",
+        );
+        write(&tmp, "Gemfile.lock", GEMFILE_LOCK);
+        tmp
+    }
+
+    #[test]
+    fn bumps_version_rb_and_gemfile_lock() {
+        let tmp = gem_fixture();
+        let root = utf8_tmp(&tmp);
+        assert_eq!(
+            gemspec(root).as_deref(),
+            Some(Utf8Path::new("acme-widgets.gemspec"))
+        );
+        assert_eq!(current_version(root).as_deref(), Some("0.3.0"));
+
+        let modified = set_version(root, &Version::new(0, 4, 0)).unwrap();
+        assert_eq!(
+            modified,
+            vec!["lib/acme/widgets/version.rb", "Gemfile.lock"]
+        );
+        assert!(read(&tmp, "lib/acme/widgets/version.rb").contains("VERSION = \"0.4.0\""));
+        assert_eq!(
+            read(&tmp, "Gemfile.lock"),
+            GEMFILE_LOCK.replace("acme-widgets (0.3.0)", "acme-widgets (0.4.0)")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_gemspec_version() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            &tmp,
+            "tiny.gemspec",
+            "Gem::Specification.new do |s|\n  s.name    = 'tiny'\n  s.version = '1.0.0'\nend\n",
+        );
+        let root = utf8_tmp(&tmp);
+        let modified = set_version(root, &Version::new(1, 1, 0)).unwrap();
+        assert_eq!(modified, vec!["tiny.gemspec"]);
+        assert!(read(&tmp, "tiny.gemspec").contains("s.version = '1.1.0'"));
+    }
+
+    #[test]
+    fn missing_version_is_an_error() {
+        let tmp = TempDir::new().unwrap();
+        write(&tmp, "tiny.gemspec", "Gem::Specification.new do |s|\nend\n");
+        let err = set_version(utf8_tmp(&tmp), &Version::new(1, 0, 0)).unwrap_err();
+        assert!(err.to_string().contains("no `VERSION"), "{err}");
+    }
+}
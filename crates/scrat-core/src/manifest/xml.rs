@@ -0,0 +1,128 @@
+//! Format-preserving XML element lookup.
+//!
+//! A small scanner for manifests such as Maven's `pom.xml`. It tracks the
+//! chain of open elements and reports the byte range of text-only
+//! elements, so a value can be replaced without re-serializing the
+//! document.
+//!
+//! Path segments are element names (including any namespace prefix).
+//! Comments, processing instructions, CDATA sections, and doctype
+//! declarations are skipped; attributes containing `>` are not supported.
+
+use std::ops::Range;
+
+/// Locate the byte ranges of the text of every element at `path`, in
+/// document order. Ranges exclude surrounding whitespace; elements with
+/// child elements or no text are skipped.
+pub fn find_text(text: &str, path: &[&str]) -> Vec<Range<usize>> {
+    let mut found = Vec::new();
+    let mut open: Vec<&str> = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = text[pos..].find('<') {
+        let start = pos + offset;
+        let rest = &text[start..];
+        let skip_to = |terminator: &str| {
+            rest.find(terminator)
+                .map_or(text.len(), |end| start + end + terminator.len())
+        };
+        if rest.starts_with("<!--") {
+            pos = skip_to("-->");
+            continue;
+        }
+        if rest.starts_with("<![CDATA[") {
+            pos = skip_to("]]>");
+            continue;
+        }
+        if rest.starts_with("<?") || rest.starts_with("<!") {
+            pos = skip_to(">");
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        pos = start + end + 1;
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            if let Some(at) = open.iter().rposition(|open| *open == name) {
+                open.truncate(at);
+            }
+            continue;
+        }
+        if tag.ends_with('/') {
+            continue;
+        }
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        open.push(name);
+
+        if open.as_slice() == path {
+            let text_end = text[pos..].find('<').map_or(text.len(), |i| pos + i);
+            let closes = text[text_end..]
+                .strip_prefix("</")
+                .is_some_and(|close| close.starts_with(name));
+            let raw = &text[pos..text_end];
+            let value = raw.trim();
+            if closes && !value.is_empty() {
+                let value_start = pos + (raw.len() - raw.trim_start().len());
+                found.push(value_start..value_start + value.len());
+            }
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <!-- <version>0.0.0</version> -->
+  <parent>
+    <artifactId>acme-parent</artifactId>
+    <version>3.1.0</version>
+  </parent>
+  <artifactId>demo</artifactId>
+  <version>
+    1.2.0-SNAPSHOT
+  </version>
+  <dependencies>
+    <dependency>
+      <artifactId>junit</artifactId>
+      <version>4.13.2</version>
+    </dependency>
+  </dependencies>
+  <build><plugins/></build>
+</project>
+"#;
+
+    fn values<'a>(text: &'a str, path: &[&str]) -> Vec<&'a str> {
+        find_text(text, path)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect()
+    }
+
+    #[test]
+    fn finds_elements_by_path() {
+        assert_eq!(values(POM, &["project", "version"]), vec!["1.2.0-SNAPSHOT"]);
+        assert_eq!(
+            values(POM, &["project", "parent", "version"]),
+            vec!["3.1.0"]
+        );
+        assert_eq!(
+            values(POM, &["project", "dependencies", "dependency", "version"]),
+            vec!["4.13.2"]
+        );
+        assert!(values(POM, &["version"]).is_empty());
+    }
+
+    #[test]
+    fn skips_elements_with_children() {
+        assert!(values(POM, &["project", "parent"]).is_empty());
+        assert!(values(POM, &["project", "build"]).is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+//! Format-preserving YAML scalar lookup.
+//!
+//! A line-based scanner for block-style mappings such as Helm's
+//! `Chart.yaml`. It tracks the chain of parent keys by indentation and
+//! reports the byte range of plain or quoted scalars, so a value can be
+//! replaced without re-serializing the document.
+//!
+//! Path segments are mapping keys. Sequences, flow collections, and
+//! multi-line scalars are not addressed. Every document of a
+//! multi-document stream (`---`) is searched.
+
+use std::ops::Range;
+
+/// Locate the byte ranges of the scalar values at `path`, one per document
+/// that has it. Ranges of quoted scalars exclude the quotes.
+pub fn find_scalars(text: &str, path: &[&str]) -> Vec<Range<usize>> {
+    let mut found = Vec::new();
+    let mut parents: Vec<(usize, String)> = Vec::new();
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.starts_with("---") || content.starts_with("...") {
+            parents.clear();
+            continue;
+        }
+        let trimmed = content.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = content.len() - trimmed.len();
+        while parents.last().is_some_and(|(i, _)| *i >= indent) {
+            parents.pop();
+        }
+
+        // Keys under a sequence item are out of reach of a key path
+        if trimmed == "-" || trimmed.starts_with("- ") {
+            parents.push((indent, "-".into()));
+            continue;
+        }
+
+        let Some((key, rest_at)) = split_key(trimmed) else {
+            continue;
+        };
+        let rest = &trimmed[rest_at..];
+        let value = rest.trim_start();
+        if value.is_empty() || value.starts_with('#') {
+            parents.push((indent, key));
+            continue;
+        }
+
+        let is_match = parents.len() + 1 == path.len()
+            && parents.iter().zip(path).all(|((_, k), p)| k == p)
+            && path.last() == Some(&key.as_str());
+        if is_match && let Some(range) = scalar_range(value) {
+            let value_start = line_start + indent + rest_at + (rest.len() - value.len());
+            found.push(value_start + range.start..value_start + range.end);
+        }
+    }
+
+    found
+}
+
+/// Split `key: value` into the key and the byte offset just past the colon.
+///
+/// Returns `None` for lines that are not a mapping entry.
+fn split_key(line: &str) -> Option<(String, usize)> {
+    if let Some(quote @ ('"' | '\'')) = line.chars().next() {
+        let close = line[1..].find(quote)? + 1;
+        let after = &line[close + 1..];
+        let colon = after.find(':')?;
+        if !after[..colon].trim().is_empty() {
+            return None;
+        }
+        return Some((line[1..close].to_string(), close + 1 + colon + 1));
+    }
+    let colon = line
+        .match_indices(':')
+        .map(|(i, _)| i)
+        .find(|&i| line[i + 1..].is_empty() || line[i + 1..].starts_with([' ', '\t']))?;
+    Some((line[..colon].trim_end().to_string(), colon + 1))
+}
+
+/// Byte range of a scalar within `value` (which starts at the scalar),
+/// excluding quotes and any trailing comment.
+fn scalar_range(value: &str) -> Option<Range<usize>> {
+    match value.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let len = value[1..].find(quote)?;
+            Some(1..1 + len)
+        }
+        // Block scalars, flow collections, anchors, aliases, and tags
+        '|' | '>' | '[' | '{' | '&' | '*' | '!' => None,
+        _ => {
+            let end = value.find(" #").unwrap_or(value.len());
+            Some(0..value[..end].trim_end().len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHART: &str = r#"apiVersion: v2
+name: demo
+version: 1.2.3 # chart version
+appVersion: "1.2.3"
+image:
+  repository: ghcr.io/acme/demo
+  tag: '1.2.3'
+dependencies:
+  - name: redis
+    version: 18.0.0
+"#;
+
+    fn values<'a>(text: &'a str, path: &[&str]) -> Vec<&'a str> {
+        find_scalars(text, path)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect()
+    }
+
+    #[test]
+    fn finds_top_level_and_nested_scalars() {
+        assert_eq!(values(CHART, &["version"]), vec!["1.2.3"]);
+        assert_eq!(values(CHART, &["appVersion"]), vec!["1.2.3"]);
+        assert_eq!(values(CHART, &["image", "tag"]), vec!["1.2.3"]);
+        assert!(values(CHART, &["tag"]).is_empty());
+        assert!(values(CHART, &["image"]).is_empty());
+    }
+
+    #[test]
+    fn sequence_items_are_not_addressed() {
+        assert!(values(CHART, &["dependencies", "version"]).is_empty());
+    }
+
+    #[test]
+    fn searches_every_document() {
+        let text = "kind: A\nversion: 1.0.0\n---\nkind: B\nversion: 1.0.0\n";
+        assert_eq!(find_scalars(text, &["version"]).len(), 2);
+    }
+
+    #[test]
+    fn quoted_keys_and_urls() {
+        let text = "\"my.key\": 0.1.0\nurl: https://example.com\n";
+        assert_eq!(values(text, &["my.key"]), vec!["0.1.0"]);
+        assert_eq!(values(text, &["url"]), vec!["https://example.com"]);
+    }
+}
@@ -7,17 +7,29 @@
 //!
 //! This keeps scrat thin — git-cliff owns commit parsing, grouping, and
 //! template rendering. scrat only injects its sidecar data.
+//!
+//! Without git-cliff installed, notes are rendered from the built-in
+//! [conventional-commit analysis](crate::version::commits) in the same
+//! layout as the built-in template.
 
+use std::fmt::Write as _;
 use std::process::Command;
 
 use camino::Utf8Path;
+use semver::Version;
 use serde::Serialize;
 use thiserror::Error;
 use tracing::{debug, warn};
 
-use crate::config::Config;
+use crate::config::{BumpRulesConfig, Config};
+use crate::package::PackageScope;
 use crate::pipeline::{PipelineContext, PipelineContextInit};
-use crate::{deps, detect, git, stats, version};
+use crate::remote::Remote;
+use crate::version::BumpLevel;
+use crate::version::commits::{self, ClassifiedCommit, CommitAnalysis};
+use crate::version::conventional::cliff_tag_args;
+use crate::version::tag::TagTemplate;
+use crate::{deps, detect, git, manifest, stats, version};
 
 /// Errors from the release notes rendering pipeline.
 #[derive(Error, Debug)]
@@ -30,6 +42,11 @@ pub enum NotesError {
     #[error("git-cliff rendering failed: {0}")]
     CliffRender(String),
 
+    /// Failed to resolve the tag template or analyze the commits for
+    /// built-in rendering.
+    #[error(transparent)]
+    Version(#[from] version::VersionError),
+
     /// Failed to read a custom template file.
     #[error("failed to read template at {path}: {source}")]
     ReadTemplate {
@@ -51,12 +68,17 @@ pub struct PreviewNotesOptions {
     pub from: Option<String>,
     /// Override the version to render (default: read from project files).
     pub version: Option<String>,
+    /// Render notes for the previous version bumped by this level instead of
+    /// the version in the project files.
+    pub bump: Option<BumpLevel>,
     /// Path to a custom git-cliff template (overrides config + built-in).
     pub template: Option<String>,
     /// Skip dependency diff.
     pub no_deps: bool,
     /// Skip stats collection.
     pub no_stats: bool,
+    /// Render notes for a single monorepo package.
+    pub package: Option<PackageScope>,
 }
 
 /// The result of a notes preview.
@@ -81,8 +103,15 @@ pub fn preview_notes(
     config: &Config,
     options: PreviewNotesOptions,
 ) -> Result<PreviewNotesResult, NotesError> {
+    let package = options.package.as_ref();
+    let package_dir = package.map(|p| p.path.as_str());
+    let tags = TagTemplate::resolve(config, package)?;
+
     // Detect ecosystem (config override > auto-detect)
-    let detection = detect::resolve_detection(project_root, config);
+    let detection = package.map_or_else(
+        || detect::resolve_detection(project_root, config),
+        |p| detect::resolve_package_detection(project_root, p),
+    );
     let ecosystem_name = detection
         .as_ref()
         .map(|d| d.ecosystem.to_string())
@@ -91,44 +120,61 @@ pub fn preview_notes(
     // Resolve previous version tag
     let previous_tag = match options.from {
         Some(ref tag) => tag.clone(),
-        None => git::latest_version_tag()
-            .map_err(|e| NotesError::CliffContext(format!("failed to query git tags: {e}")))?
-            .unwrap_or_default(),
+        None => tags.latest()?.map(|(tag, _)| tag).unwrap_or_default(),
+    };
+
+    let read = |s: &str| {
+        tags.scheme()
+            .parse(s)
+            .map_or_else(|| version::parse_version(s), Ok)
     };
 
     // Parse previous version from tag
+    let previous = tags
+        .parse(&previous_tag)
+        .or_else(|| read(&previous_tag).ok());
     let previous_version = if previous_tag.is_empty() {
         "0.0.0".to_string()
     } else {
-        let v_str = previous_tag.strip_prefix('v').unwrap_or(&previous_tag);
-        version::parse_version(v_str)
-            .map(|v| v.to_string())
-            .unwrap_or_else(|_| previous_tag.clone())
+        previous
+            .as_ref()
+            .map_or_else(|| previous_tag.clone(), |v| tags.scheme().format(v))
     };
 
-    // Resolve current version
-    let current_version = match options.version {
-        Some(ref v) => {
+    // Resolve current version, written the way it is released
+    let current_version = match (options.version.as_deref(), options.bump) {
+        (Some(v), _) => {
             let v_str = v.strip_prefix('v').unwrap_or(v);
-            version::parse_version(v_str)
-                .map(|v| v.to_string())
+            read(v_str)
+                .map(|v| tags.scheme().format(&v))
                 .map_err(|e| NotesError::CliffContext(format!("invalid version: {e}")))?
         }
-        None => detect_current_version(project_root, &ecosystem_name)
-            .unwrap_or_else(|| "unreleased".into()),
+        // A forced level applies to the previous release
+        (None, Some(level)) => {
+            let base = previous.unwrap_or_else(|| Version::new(0, 0, 0));
+            tags.scheme().format(&version::next_version(&base, level))
+        }
+        (None, None) => {
+            let root = package.map_or_else(|| project_root.to_owned(), |p| p.root(project_root));
+            detection
+                .as_ref()
+                .and_then(|d| manifest::current_version(&root, d.ecosystem, config))
+                .map(|v| read(&v).map_or(v, |parsed| tags.scheme().format(&parsed)))
+                .unwrap_or_else(|| "unreleased".into())
+        }
     };
 
-    let tag = format!("v{current_version}");
+    let tag = read(&current_version).map_or_else(|_| current_version.clone(), |v| tags.format(&v));
+    let changelog_path = package.map_or_else(
+        || project_root.join("CHANGELOG.md"),
+        |p| p.root(project_root).join("CHANGELOG.md"),
+    );
 
-    // Build repo info
-    let (owner, repo, repo_url) = {
-        let remote = git::remote_url("origin").ok().flatten();
-        let (o, r) = remote
-            .as_deref()
-            .and_then(git::parse_owner_repo)
-            .unwrap_or_else(|| ("unknown".into(), "unknown".into()));
-        (o, r, remote)
-    };
+    // Build repo info from the release remote
+    let remote = Remote::resolve(config);
+    let (owner, repo) = remote
+        .owner_repo()
+        .unwrap_or_else(|| ("unknown".into(), "unknown".into()));
 
     // Build pipeline context
     let mut ctx = PipelineContext::new(PipelineContextInit {
@@ -138,10 +184,10 @@ pub fn preview_notes(
         previous_tag: previous_tag.clone(),
         owner,
         repo,
-        repo_url,
+        repo_url: remote.url,
         branch: git::current_branch().ok().flatten(),
         ecosystem: ecosystem_name,
-        changelog_path: project_root.join("CHANGELOG.md").to_string(),
+        changelog_path: changelog_path.to_string(),
         dry_run: true,
     });
 
@@ -149,7 +195,7 @@ pub fn preview_notes(
     if !options.no_deps
         && let Some(ref det) = detection
     {
-        ctx.dependencies = deps::compute_deps(det.ecosystem, &ctx.previous_tag);
+        ctx.dependencies = deps::compute_deps(det.ecosystem, &ctx.previous_tag, package_dir);
         if !ctx.dependencies.is_empty() {
             debug!(count = ctx.dependencies.len(), "deps computed");
         }
@@ -157,7 +203,7 @@ pub fn preview_notes(
 
     // Compute stats
     if !options.no_stats && !previous_tag.is_empty() {
-        ctx.stats = stats::compute_stats(&ctx.previous_tag);
+        ctx.stats = stats::compute_stats(&ctx.previous_tag, package_dir);
         if ctx.stats.is_some() {
             debug!("stats computed");
         }
@@ -172,7 +218,7 @@ pub fn preview_notes(
     });
 
     // Render
-    let notes = render_notes(project_root, &ctx, template)?;
+    let notes = render_notes(project_root, &ctx, template, &tags, package)?;
 
     Ok(PreviewNotesResult {
         notes,
@@ -182,43 +228,6 @@ pub fn preview_notes(
     })
 }
 
-/// Read the current version from project manifest files.
-///
-/// Quick extraction without full TOML/JSON parsing crate deps.
-fn detect_current_version(project_root: &Utf8Path, ecosystem: &str) -> Option<String> {
-    match ecosystem {
-        "rust" => {
-            let cargo_toml = project_root.join("Cargo.toml");
-            let content = std::fs::read_to_string(&cargo_toml).ok()?;
-            let mut in_package = false;
-            for line in content.lines() {
-                let trimmed = line.trim();
-                if trimmed.starts_with('[') {
-                    in_package = trimmed == "[package]";
-                    continue;
-                }
-                if in_package
-                    && trimmed.starts_with("version")
-                    && let Some((_, val)) = trimmed.split_once('=')
-                {
-                    let val = val.trim().trim_matches('"');
-                    if !val.contains("workspace") {
-                        return Some(val.to_string());
-                    }
-                }
-            }
-            None
-        }
-        "node" => {
-            let pkg_json = project_root.join("package.json");
-            let content = std::fs::read_to_string(&pkg_json).ok()?;
-            let parsed: serde_json::Value = serde_json::from_str(&content).ok()?;
-            parsed["version"].as_str().map(String::from)
-        }
-        _ => None,
-    }
-}
-
 // ──────────────────────────────────────────────
 // Low-level rendering
 // ──────────────────────────────────────────────
@@ -228,10 +237,15 @@ const BUILTIN_TEMPLATE: &str = include_str!("../templates/release-notes.tera");
 
 /// Render release notes by injecting pipeline data into git-cliff's context.
 ///
+/// Falls back to [built-in rendering](render_builtin) when git-cliff is not
+/// installed; a custom template is ignored then.
+///
 /// # Arguments
 /// - `project_root` — working directory for git-cliff commands
 /// - `ctx` — the pipeline context with stats, deps, metadata
 /// - `custom_template` — optional path to a user-provided cliff template
+/// - `tags` — how release tags are named, for git-cliff's tag pattern
+/// - `package` — monorepo package whose commits to consider
 ///
 /// # Returns
 /// Rendered markdown string on success, or `NotesError` on failure.
@@ -239,10 +253,22 @@ pub fn render_notes(
     project_root: &Utf8Path,
     ctx: &PipelineContext,
     custom_template: Option<&str>,
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
 ) -> Result<String, NotesError> {
+    if !detect::has_binary("git-cliff") {
+        if let Some(path) = custom_template {
+            warn!(%path, "git-cliff is not installed, ignoring custom notes template");
+        }
+        debug!("rendering release notes from the built-in commit analysis");
+        let since = Some(ctx.previous_tag.as_str()).filter(|t| !t.is_empty());
+        let analysis = commits::analyze_commits_since(since, package, &BumpRulesConfig::default())?;
+        return Ok(render_builtin(ctx, &analysis));
+    }
+
     // Pass 1: Extract git-cliff's native context as JSON
     debug!("extracting git-cliff context (pass 1)");
-    let context_json = run_cliff_context(project_root)?;
+    let context_json = run_cliff_context(project_root, tags, package)?;
 
     // Parse and inject our extra data
     let enriched_json = inject_extra(&context_json, ctx)?;
@@ -276,7 +302,8 @@ pub fn render_notes(
 /// {
 ///   "stats": { "files_changed": N, "insertions": N, "deletions": N, "contributors": [...] },
 ///   "deps": [ { "name": "...", "from": "...", "to": "..." }, ... ],
-///   "metadata": { ... }
+///   "metadata": { ... },
+///   "repository": "https://github.com/owner/repo"
 /// }
 /// ```
 pub fn build_extra(ctx: &PipelineContext) -> serde_json::Value {
@@ -311,13 +338,159 @@ pub fn build_extra(ctx: &PipelineContext) -> serde_json::Value {
         );
     }
 
+    // Repository web address, for compare and commit links
+    if let Some(url) = repository_url(ctx) {
+        extra.insert("repository".into(), url.into());
+    }
+
     serde_json::Value::Object(extra)
 }
 
+/// The web address of the release remote's repository, if it is known.
+fn repository_url(ctx: &PipelineContext) -> Option<String> {
+    ctx.repo_url.as_deref().and_then(git::web_url)
+}
+
+/// Section headings of the built-in layout, by commit type.
+const SECTIONS: &[(&str, &str)] = &[
+    ("feat", "✨ Added"),
+    ("fix", "🩹 Fixed"),
+    ("perf", "⚡ Performance"),
+    ("refactor", "🧰 Refactor"),
+    ("docs", "📚 Docs"),
+    ("test", "🧪 Tests"),
+    ("style", "🎨 Style"),
+    ("chore", "🧹 Chores"),
+];
+
+/// Render release notes from classified commits, following the layout of
+/// the built-in template: breaking changes, changes grouped by type,
+/// dependencies, stats, and the full commit list.
+pub fn render_builtin(ctx: &PipelineContext, analysis: &CommitAnalysis) -> String {
+    let repository = repository_url(ctx).unwrap_or_else(|| "..".into());
+    let mut out = format!("## {} — {}\n", ctx.version, ctx.date);
+    if !ctx.previous_version.is_empty() && !ctx.previous_tag.is_empty() {
+        let _ = writeln!(
+            out,
+            "\n[Compare]({repository}/compare/{}...{})",
+            ctx.previous_tag, ctx.tag
+        );
+    }
+
+    let conventional: Vec<(&ClassifiedCommit, &commits::ConventionalCommit)> = analysis
+        .commits
+        .iter()
+        .filter_map(|c| c.conventional.as_ref().map(|cc| (c, cc)))
+        .collect();
+
+    let breaking: Vec<_> = conventional.iter().filter(|(_, cc)| cc.breaking).collect();
+    if !breaking.is_empty() {
+        out.push_str("\n> [!WARNING]\n> **Breaking changes in this release**\n");
+        for (_, cc) in breaking {
+            let _ = write!(out, "> - {}", cc.description);
+            if let Some(ref detail) = cc.breaking_description {
+                let _ = write!(out, " — {detail}");
+            }
+            out.push('\n');
+        }
+    }
+
+    // Known types in a fixed order, then the rest in order of appearance
+    let mut kinds: Vec<&str> = SECTIONS.iter().map(|(kind, _)| *kind).collect();
+    for (_, cc) in &conventional {
+        if !kinds.contains(&cc.kind.as_str()) {
+            kinds.push(&cc.kind);
+        }
+    }
+    for kind in kinds {
+        let group: Vec<_> = conventional
+            .iter()
+            .filter(|(_, cc)| cc.kind == kind)
+            .collect();
+        if group.is_empty() {
+            continue;
+        }
+        let heading = SECTIONS
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map_or(kind, |(_, heading)| heading);
+        let _ = writeln!(out, "\n### {heading}\n");
+        for (commit, cc) in group {
+            out.push_str("- ");
+            if let Some(ref scope) = cc.scope {
+                let _ = write!(out, "*({scope})* ");
+            }
+            let _ = writeln!(
+                out,
+                "{} ([{}]({repository}/commit/{}))",
+                cc.description,
+                short_id(&commit.id),
+                commit.id
+            );
+        }
+    }
+
+    if !ctx.dependencies.is_empty() {
+        out.push_str("\n### 📦 Dependencies\n\n");
+        for dep in &ctx.dependencies {
+            let _ = match (&dep.from, &dep.to) {
+                (Some(from), Some(to)) => writeln!(out, "- **{}**: `{from}` → `{to}`", dep.name),
+                (None, Some(to)) => writeln!(out, "- **{}** `{to}` *(added)*", dep.name),
+                (Some(from), None) => writeln!(out, "- ~~{}~~ `{from}` *(removed)*", dep.name),
+                (None, None) => Ok(()),
+            };
+        }
+    }
+
+    if let Some(ref stats) = ctx.stats {
+        let _ = write!(
+            out,
+            "\n### 📊 Stats\n\n| Metric | Value |\n|---|---:|\n| Commits | {} |\n| Files changed | {} |\n| Insertions | +{} |\n| Deletions | -{} |\n",
+            analysis.commits.len(),
+            stats.files_changed,
+            stats.insertions,
+            stats.deletions
+        );
+    }
+
+    out.push_str("\n<details>\n<summary>Full commit list</summary>\n\n");
+    for commit in &analysis.commits {
+        let _ = writeln!(
+            out,
+            "- [`{}`]({repository}/commit/{}) {}",
+            short_id(&commit.id),
+            commit.id,
+            commit.subject
+        );
+    }
+    if let Some(ref stats) = ctx.stats
+        && !stats.contributors.is_empty()
+    {
+        let names: Vec<String> = stats
+            .contributors
+            .iter()
+            .map(|c| format!("{} ({})", c.name, c.count))
+            .collect();
+        let _ = writeln!(out, "\n**Contributors:** {}", names.join(", "));
+    }
+    out.push_str("</details>\n");
+    out
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..7).unwrap_or(id)
+}
+
 /// Run `git-cliff --unreleased --context` and capture JSON output.
-fn run_cliff_context(project_root: &Utf8Path) -> Result<String, NotesError> {
-    let output = Command::new("git-cliff")
-        .args(["--unreleased", "--context"])
+fn run_cliff_context(
+    project_root: &Utf8Path,
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
+) -> Result<String, NotesError> {
+    let mut cmd = Command::new("git-cliff");
+    cmd.args(["--unreleased", "--context"]);
+    cmd.args(cliff_tag_args(tags, package));
+    let output = cmd
         .current_dir(project_root.as_std_path())
         .output()
         .map_err(|e| NotesError::CliffContext(format!("failed to execute git-cliff: {e}")))?;
@@ -509,6 +682,29 @@ mod tests {
         assert!(!obj.contains_key("stats"));
         assert!(!obj.contains_key("deps"));
         assert!(!obj.contains_key("metadata"));
+        assert_eq!(obj["repository"], "https://github.com/claylo/scrat");
+    }
+
+    #[test]
+    fn links_stay_relative_without_a_remote() {
+        let mut ctx = test_ctx();
+        ctx.repo_url = None;
+        assert!(
+            !build_extra(&ctx)
+                .as_object()
+                .unwrap()
+                .contains_key("repository")
+        );
+
+        let messages = vec![("0abcdef0123".to_string(), "fix: a".to_string())];
+        let analysis = CommitAnalysis::from_messages(
+            Some("v1.1.0".into()),
+            &messages,
+            &BumpRulesConfig::default(),
+        );
+        let notes = render_builtin(&ctx, &analysis);
+        assert!(notes.contains("[Compare](../compare/v1.1.0...v1.2.3)"));
+        assert!(notes.contains("([0abcdef](../commit/0abcdef0123))"));
     }
 
     #[test]
@@ -610,6 +806,51 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("not a JSON array"));
     }
 
+    #[test]
+    fn render_builtin_groups_commits() {
+        let mut ctx = test_ctx();
+        ctx.dependencies = vec![DepChange {
+            name: "serde".into(),
+            from: Some("1.0.0".into()),
+            to: Some("1.0.1".into()),
+        }];
+        let messages: Vec<(String, String)> = [
+            "feat(cli): add --pre",
+            "fix: handle empty tags",
+            "refactor!: rename config keys\n\nBREAKING CHANGE: `strategy` moved under `version`",
+            "update readme",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, m)| (format!("{i}abcdef0123"), (*m).to_string()))
+        .collect();
+        let analysis = CommitAnalysis::from_messages(
+            Some("v1.1.0".into()),
+            &messages,
+            &BumpRulesConfig::default(),
+        );
+
+        let notes = render_builtin(&ctx, &analysis);
+        assert!(notes.starts_with("## 1.2.3 — "));
+        assert!(
+            notes.contains("[Compare](https://github.com/claylo/scrat/compare/v1.1.0...v1.2.3)")
+        );
+        assert!(notes.contains("> - rename config keys — `strategy` moved under `version`"));
+        let added = notes.find("### ✨ Added").unwrap();
+        let fixed = notes.find("### 🩹 Fixed").unwrap();
+        let refactor = notes.find("### 🧰 Refactor").unwrap();
+        assert!(added < fixed && fixed < refactor);
+        assert!(notes.contains(
+            "- *(cli)* add --pre ([0abcdef](https://github.com/claylo/scrat/commit/0abcdef0123))"
+        ));
+        assert!(notes.contains("- **serde**: `1.0.0` → `1.0.1`"));
+        // Non-conventional commits only appear in the full list
+        assert!(notes.contains(
+            "- [`3abcdef`](https://github.com/claylo/scrat/commit/3abcdef0123) update readme"
+        ));
+        assert_eq!(notes.matches("update readme").count(), 1);
+    }
+
     #[test]
     fn builtin_template_is_non_empty() {
         assert!(!BUILTIN_TEMPLATE.is_empty());
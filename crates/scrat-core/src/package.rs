@@ -0,0 +1,254 @@
+//! Independent per-package releases for monorepos.
+//!
+//! With `[[packages]]` configured, each package is versioned and tagged on
+//! its own schedule (`scrat-core-v0.2.0`). Version computation, changelog,
+//! dependency diff, and stats for a package only consider commits that
+//! touched files under its path.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use semver::Version;
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{debug, instrument};
+
+use crate::config::{Config, PackageConfig};
+use crate::ecosystem::Ecosystem;
+use crate::git::{self, GitError};
+use crate::version::VersionError;
+use crate::version::tag::TagTemplate;
+
+/// Errors from resolving monorepo packages.
+#[derive(Error, Debug)]
+pub enum PackageError {
+    /// `--package` named a package that is not configured.
+    #[error("unknown package `{name}` (configured: {available})")]
+    Unknown {
+        /// The requested package name.
+        name: String,
+        /// Comma-separated names of the configured packages.
+        available: String,
+    },
+
+    /// `--package` was used without any `[[packages]]` in config.
+    #[error("no packages configured — add `[[packages]]` entries to your config")]
+    NotConfigured,
+
+    /// A git query failed.
+    #[error(transparent)]
+    Git(#[from] GitError),
+
+    /// Reading the package's version tags failed.
+    #[error(transparent)]
+    Version(#[from] VersionError),
+}
+
+/// Result alias for package operations.
+pub type PackageResult<T> = Result<T, PackageError>;
+
+/// A package released independently, resolved from [`PackageConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PackageScope {
+    /// Package name.
+    pub name: String,
+    /// Package directory relative to the repository root.
+    pub path: Utf8PathBuf,
+    /// Ecosystem override (`None` = detect from marker files in `path`).
+    pub ecosystem: Option<Ecosystem>,
+    /// Prefix of the package's tags (e.g. `"scrat-core-v"`).
+    pub tag_prefix: String,
+}
+
+impl PackageScope {
+    /// Resolve a configured package, applying the default tag prefix.
+    ///
+    /// The default is `{name}-v`, except for Go modules: the Go toolchain
+    /// finds a nested module's versions by tags prefixed with its
+    /// directory, so they get `{path}/v` (`sub/mod/v1.2.3`). A package is a
+    /// Go module if its `type` says so or, without a `type`, if `go.mod`
+    /// exists in its directory under `project_root`.
+    pub fn from_config(project_root: &Utf8Path, config: &PackageConfig) -> Self {
+        let path = config.path.as_str().trim_end_matches('/');
+        let path = Utf8PathBuf::from(path.strip_prefix("./").unwrap_or(path));
+        let is_go = config.package_type.map_or_else(
+            || project_root.join(&path).join("go.mod").is_file(),
+            |ecosystem| ecosystem == Ecosystem::Go,
+        );
+        let tag_prefix = config.tag_prefix.clone().unwrap_or_else(|| {
+            if is_go {
+                format!("{path}/v")
+            } else {
+                format!("{}-v", config.name)
+            }
+        });
+        Self {
+            name: config.name.clone(),
+            path,
+            ecosystem: config.package_type,
+            tag_prefix,
+        }
+    }
+
+    /// The template for this package's tags (`{tag_prefix}{version}`).
+    pub fn tags(&self) -> TagTemplate {
+        TagTemplate::with_prefix(&self.tag_prefix)
+    }
+
+    /// The tag for a version of this package (e.g. `scrat-core-v0.2.0`).
+    pub fn tag(&self, version: &Version) -> String {
+        self.tags().format(version)
+    }
+
+    /// Absolute package directory.
+    pub fn root(&self, project_root: &Utf8Path) -> Utf8PathBuf {
+        project_root.join(&self.path)
+    }
+
+    /// Glob matching every file in the package, for `git-cliff --include-path`.
+    pub fn include_glob(&self) -> String {
+        format!("{}/**", self.path)
+    }
+}
+
+/// All configured packages, in config order.
+pub fn configured_packages(project_root: &Utf8Path, config: &Config) -> Vec<PackageScope> {
+    config
+        .packages
+        .iter()
+        .flatten()
+        .map(|package| PackageScope::from_config(project_root, package))
+        .collect()
+}
+
+/// Look up a configured package by name.
+pub fn find_package(
+    project_root: &Utf8Path,
+    config: &Config,
+    name: &str,
+) -> PackageResult<PackageScope> {
+    let packages = configured_packages(project_root, config);
+    if packages.is_empty() {
+        return Err(PackageError::NotConfigured);
+    }
+    packages
+        .iter()
+        .find(|p| p.name == name)
+        .cloned()
+        .ok_or_else(|| PackageError::Unknown {
+            name: name.to_string(),
+            available: packages
+                .iter()
+                .map(|p| p.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        })
+}
+
+/// Configured packages with commits touching their path since their
+/// latest tag (or with no tag yet and any commits at all).
+#[instrument(skip(config))]
+pub fn changed_packages(
+    project_root: &Utf8Path,
+    config: &Config,
+) -> PackageResult<Vec<PackageScope>> {
+    let mut changed = Vec::new();
+    for package in configured_packages(project_root, config) {
+        let tags = TagTemplate::resolve(config, Some(&package))?;
+        let latest = tags.latest()?.map(|(tag, _)| tag);
+        let commits = git::recent_commits(latest.as_deref(), 1, Some(package.path.as_str()))?;
+        debug!(package = %package.name, ?latest, changed = !commits.is_empty(), "package changes");
+        if !commits.is_empty() {
+            changed.push(package);
+        }
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package_config(name: &str, path: &str) -> PackageConfig {
+        PackageConfig {
+            name: name.into(),
+            path: path.into(),
+            ..PackageConfig::default()
+        }
+    }
+
+    #[test]
+    fn default_tag_prefix() {
+        let scope = PackageScope::from_config(
+            Utf8Path::new("."),
+            &package_config("scrat-core", "./crates/scrat-core/"),
+        );
+        assert_eq!(scope.path, "crates/scrat-core");
+        assert_eq!(scope.tag(&Version::new(0, 2, 0)), "scrat-core-v0.2.0");
+        assert_eq!(scope.include_glob(), "crates/scrat-core/**");
+        assert_eq!(
+            scope.tags().parse("scrat-core-v0.2.0"),
+            Some(Version::new(0, 2, 0))
+        );
+        assert_eq!(scope.tags().parse("v0.2.0"), None);
+    }
+
+    #[test]
+    fn go_module_tags_use_directory_prefix() {
+        let mut config = package_config("mod", "sub/mod/");
+        config.package_type = Some(Ecosystem::Go);
+        let scope = PackageScope::from_config(Utf8Path::new("."), &config);
+        assert_eq!(scope.tag(&Version::new(1, 2, 3)), "sub/mod/v1.2.3");
+        assert_eq!(scope.tags().pattern(), "^sub/mod/v[0-9]");
+    }
+
+    #[test]
+    fn go_module_detected_under_project_root() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = Utf8Path::from_path(tmp.path()).unwrap();
+        std::fs::create_dir_all(root.join("sub/mod")).unwrap();
+        std::fs::write(root.join("sub/mod/go.mod"), "module example.com/sub/mod\n").unwrap();
+
+        let config = package_config("mod", "./sub/mod/");
+        let scope = PackageScope::from_config(root, &config);
+        assert_eq!(scope.tag(&Version::new(1, 2, 3)), "sub/mod/v1.2.3");
+
+        let elsewhere = PackageScope::from_config(Utf8Path::new("."), &config);
+        assert_eq!(elsewhere.tag(&Version::new(1, 2, 3)), "mod-v1.2.3");
+    }
+
+    #[test]
+    fn tag_pattern_escapes_prefix() {
+        let mut config = package_config("web", "packages/web");
+        config.tag_prefix = Some("@acme/web.v".into());
+        let scope = PackageScope::from_config(Utf8Path::new("."), &config);
+        assert_eq!(scope.tags().pattern(), "^@acme/web\\.v[0-9]");
+    }
+
+    #[test]
+    fn find_package_by_name() {
+        let config = Config {
+            packages: Some(vec![
+                package_config("core", "crates/core"),
+                package_config("cli", "crates/cli"),
+            ]),
+            ..Config::default()
+        };
+        assert_eq!(
+            find_package(Utf8Path::new("."), &config, "cli")
+                .unwrap()
+                .path,
+            "crates/cli"
+        );
+
+        let err = find_package(Utf8Path::new("."), &config, "web").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown package `web` (configured: core, cli)"
+        );
+    }
+
+    #[test]
+    fn find_package_without_config() {
+        let err = find_package(Utf8Path::new("."), &Config::default(), "core").unwrap_err();
+        assert!(matches!(err, PackageError::NotConfigured));
+    }
+}
@@ -232,34 +232,9 @@ impl PipelineContext {
 // Helpers
 // ──────────────────────────────────────────────
 
-/// Return today's date as `YYYY-MM-DD` without external date crates.
-///
-/// Uses the civil-days-from-epoch algorithm (Howard Hinnant) to convert
-/// `SystemTime::now()` into a calendar date in local-ish UTC.
+/// Return today's date (UTC) as `YYYY-MM-DD` without external date crates.
 pub fn iso_date_today() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    let secs = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-
-    // Days since epoch (UTC)
-    let days = (secs / 86400) as i64;
-
-    // Hinnant civil_from_days algorithm
-    let z = days + 719_468;
-    let era = z.div_euclid(146_097);
-    let doe = z.rem_euclid(146_097); // day of era [0, 146096]
-    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // year of era [0, 399]
-    let y = yoe + era * 400;
-    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // day of year [0, 365]
-    let mp = (5 * doy + 2) / 153; // month index [0, 11]
-    let d = doy - (153 * mp + 2) / 5 + 1; // day [1, 31]
-    let m = if mp < 10 { mp + 3 } else { mp - 9 }; // month [1, 12]
-    let y = if m <= 2 { y + 1 } else { y };
-
-    format!("{y:04}-{m:02}-{d:02}")
+    crate::version::calver::Date::today().to_string()
 }
 
 #[cfg(test)]
@@ -1,15 +1,27 @@
 //! Preflight checks for release readiness.
 //!
 //! Validates the git state, branch, remote sync, and tool availability
-//! before a release. Returns structured results that the CLI formats.
+//! before a release, plus version-specific constraints once the next
+//! version is known. Returns structured results that the CLI formats.
+//!
+//! The [version drift](detect_drift) check compares the manifest version
+//! with the latest tag reachable from `HEAD`; `scrat bump --sync` repairs
+//! what it finds.
 
+use semver::Version;
 use serde::Serialize;
 use tracing::{debug, instrument};
 
 use crate::config::Config;
-use crate::detect;
-use crate::ecosystem::ProjectDetection;
+use crate::detect::{self, EcosystemMatch};
+use crate::ecosystem::{Ecosystem, ProjectDetection};
 use crate::git;
+use crate::glob;
+use crate::manifest;
+use crate::package;
+use crate::version::line::{self, VersionLine};
+use crate::version::tag::TagTemplate;
+use crate::version::{self, VersionResult};
 
 /// A single preflight check result.
 #[derive(Debug, Clone, Serialize)]
@@ -18,6 +30,8 @@ pub struct CheckResult {
     pub name: String,
     /// Whether the check passed.
     pub passed: bool,
+    /// Whether the check passed with a caveat worth showing.
+    pub warning: bool,
     /// Description of the result (reason for failure, or confirmation).
     pub message: String,
 }
@@ -31,6 +45,142 @@ pub struct PreflightReport {
     pub all_passed: bool,
     /// Detected project info (if detection succeeded).
     pub detection: Option<ProjectDetection>,
+    /// Every ecosystem whose marker files were found, with the evidence.
+    pub ecosystems: Vec<EcosystemMatch>,
+}
+
+/// How the manifest version relates to the latest reachable tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriftState {
+    /// The manifest carries the tagged version (or, for a snapshot project,
+    /// a development version after it).
+    InSync,
+    /// The manifest is ahead of the tag: the release was never tagged, or
+    /// the version was bumped by hand ahead of shipping. Only a warning.
+    Ahead,
+    /// The manifest is behind the tag: the bump never reached it.
+    Behind,
+    /// The manifest version cannot be read, or it is ahead of the tag while
+    /// its own tag exists off this branch.
+    Mismatch,
+}
+
+impl std::fmt::Display for DriftState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InSync => write!(f, "in sync"),
+            Self::Ahead => write!(f, "ahead"),
+            Self::Behind => write!(f, "behind"),
+            Self::Mismatch => write!(f, "mismatch"),
+        }
+    }
+}
+
+/// The manifest version compared with the latest reachable tag.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionDrift {
+    /// How the two relate.
+    pub state: DriftState,
+    /// The version in the project manifest, as written there.
+    pub manifest_version: String,
+    /// The latest tag reachable from `HEAD`.
+    pub tag: String,
+    /// The version that tag names.
+    pub tag_version: Version,
+}
+
+impl VersionDrift {
+    /// The parsed manifest version, if it is valid semver.
+    pub fn manifest(&self) -> Option<Version> {
+        version::parse_version(&self.manifest_version).ok()
+    }
+
+    /// One-line description, with the repair `scrat bump --sync` would make.
+    pub fn message(&self) -> String {
+        let (manifest, tag) = (&self.manifest_version, &self.tag);
+        match self.state {
+            DriftState::InSync => format!("Manifest version {manifest} matches {tag}"),
+            DriftState::Ahead => format!(
+                "Manifest version {manifest} is ahead of {tag} — fine if it is about to ship, else run `scrat bump --sync` to tag it"
+            ),
+            DriftState::Behind => format!(
+                "Manifest version {manifest} is behind {tag} — run `scrat bump --sync` to update the manifest"
+            ),
+            DriftState::Mismatch => format!(
+                "Manifest version {manifest} does not match {tag} and cannot be synced automatically"
+            ),
+        }
+    }
+}
+
+/// Compare a manifest version with the latest reachable tag's version.
+///
+/// `snapshots` accepts a `-SNAPSHOT` manifest version past the tag as in
+/// sync; `manifest_tagged` tells whether a tag for the manifest version
+/// exists anywhere in the repository.
+pub fn classify_drift(
+    manifest: Option<&Version>,
+    tag: &Version,
+    snapshots: bool,
+    manifest_tagged: bool,
+) -> DriftState {
+    let Some(manifest) = manifest else {
+        return DriftState::Mismatch;
+    };
+    if snapshots && manifest.pre.as_str() == "SNAPSHOT" {
+        return if version::release_of(manifest) > *tag {
+            DriftState::InSync
+        } else {
+            DriftState::Behind
+        };
+    }
+    match manifest.cmp(tag) {
+        std::cmp::Ordering::Equal => DriftState::InSync,
+        std::cmp::Ordering::Less => DriftState::Behind,
+        std::cmp::Ordering::Greater if manifest_tagged => DriftState::Mismatch,
+        std::cmp::Ordering::Greater => DriftState::Ahead,
+    }
+}
+
+/// Compare the repository's manifest version with the latest release tag
+/// reachable from `HEAD`.
+///
+/// Returns `None` when there is nothing to compare: no reachable tag (first
+/// release) or no version in the manifest.
+#[instrument(skip(config, detection), fields(root = %project_root))]
+pub fn detect_drift(
+    project_root: &camino::Utf8Path,
+    config: &Config,
+    detection: &ProjectDetection,
+) -> VersionResult<Option<VersionDrift>> {
+    let tags = TagTemplate::resolve(config, None)?;
+    let Some((tag, tag_version)) = tags.latest()? else {
+        return Ok(None);
+    };
+    let Some(manifest_version) =
+        manifest::current_version(project_root, detection.ecosystem, config)
+    else {
+        return Ok(None);
+    };
+
+    let parsed = tags
+        .scheme()
+        .parse(&manifest_version)
+        .or_else(|| version::parse_version(&manifest_version).ok());
+    let manifest_tagged = match parsed {
+        Some(ref v) => !git::list_tags(&tags.format(v))?.is_empty(),
+        None => false,
+    };
+    let snapshots = detection.ecosystem.provider().uses_snapshots(project_root);
+    let state = classify_drift(parsed.as_ref(), &tag_version, snapshots, manifest_tagged);
+    debug!(%manifest_version, %tag, %state, "version drift");
+    Ok(Some(VersionDrift {
+        state,
+        manifest_version,
+        tag,
+        tag_version,
+    }))
 }
 
 /// Run all preflight checks.
@@ -52,6 +202,7 @@ pub fn run_preflight(project_root: &camino::Utf8Path, config: &Config) -> Prefli
         return PreflightReport {
             all_passed: false,
             detection: None,
+            ecosystems: Vec::new(),
             checks,
         };
     }
@@ -64,20 +215,32 @@ pub fn run_preflight(project_root: &camino::Utf8Path, config: &Config) -> Prefli
         .project
         .as_ref()
         .and_then(|p| p.release_branch.as_deref());
-    checks.push(check_release_branch(release_branch_override));
+    checks.push(check_release_branch(
+        release_branch_override,
+        line::maintenance_patterns(config),
+    ));
 
     // Check 4: Remote in sync
     checks.push(check_remote_sync());
 
     // Check 5: Ecosystem detection (config override > auto-detect)
     let detection = detect::resolve_detection(project_root, config);
-    checks.push(check_ecosystem(&detection));
+    let ecosystems = detect::detect_ecosystems(project_root);
+    checks.push(check_ecosystem(&detection, &ecosystems));
 
     // Check 6: Required tools
     if let Some(ref det) = detection {
         checks.push(check_required_tools(det));
     }
 
+    // Check 7: Manifest and tag agree (monorepo packages carry their own tags)
+    if let Some(ref det) = detection
+        && package::configured_packages(project_root, config).is_empty()
+        && let Some(check) = check_version_drift(project_root, config, det)
+    {
+        checks.push(check);
+    }
+
     let all_passed = checks.iter().all(|c| c.passed);
     debug!(all_passed, check_count = checks.len(), "preflight complete");
 
@@ -85,6 +248,65 @@ pub fn run_preflight(project_root: &camino::Utf8Path, config: &Config) -> Prefli
         checks,
         all_passed,
         detection,
+        ecosystems,
+    }
+}
+
+/// Check constraints that depend on the version being released.
+///
+/// Runs once the next version is known (after the plan phase). Returns
+/// `None` when the ecosystem has no such constraints.
+///
+/// - Go: a v2+ release needs a matching `/vN` suffix on the module path
+pub fn check_release_version(
+    project_root: &camino::Utf8Path,
+    detection: &ProjectDetection,
+    version: &semver::Version,
+) -> Option<CheckResult> {
+    match detection.ecosystem {
+        Ecosystem::Go => Some(
+            match manifest::go::check_module_path(project_root, version) {
+                Ok(()) => CheckResult {
+                    name: "Go module path".into(),
+                    passed: true,
+                    warning: false,
+                    message: format!("Module path matches v{}", version.major),
+                },
+                Err(e) => CheckResult {
+                    name: "Go module path".into(),
+                    passed: false,
+                    warning: false,
+                    message: e.to_string(),
+                },
+            },
+        ),
+        _ => None,
+    }
+}
+
+fn check_version_drift(
+    project_root: &camino::Utf8Path,
+    config: &Config,
+    detection: &ProjectDetection,
+) -> Option<CheckResult> {
+    match detect_drift(project_root, config, detection) {
+        Ok(drift) => drift.as_ref().map(drift_check),
+        Err(e) => Some(CheckResult {
+            name: "Version drift".into(),
+            passed: false,
+            warning: false,
+            message: format!("Failed to check: {e}"),
+        }),
+    }
+}
+
+fn drift_check(drift: &VersionDrift) -> CheckResult {
+    CheckResult {
+        name: "Version drift".into(),
+        // A manifest bumped by hand before shipping is ahead of the tag
+        passed: matches!(drift.state, DriftState::InSync | DriftState::Ahead),
+        warning: drift.state == DriftState::Ahead,
+        message: drift.message(),
     }
 }
 
@@ -93,16 +315,19 @@ fn check_git_repo() -> CheckResult {
         Ok(true) => CheckResult {
             name: "Git repository".into(),
             passed: true,
+            warning: false,
             message: "Inside a git repository".into(),
         },
         Ok(false) => CheckResult {
             name: "Git repository".into(),
             passed: false,
+            warning: false,
             message: "Not inside a git repository".into(),
         },
         Err(e) => CheckResult {
             name: "Git repository".into(),
             passed: false,
+            warning: false,
             message: format!("Failed to check: {e}"),
         },
     }
@@ -113,28 +338,32 @@ fn check_clean_tree() -> CheckResult {
         Ok(true) => CheckResult {
             name: "Working tree".into(),
             passed: true,
+            warning: false,
             message: "Clean working tree".into(),
         },
         Ok(false) => CheckResult {
             name: "Working tree".into(),
             passed: false,
+            warning: false,
             message: "Uncommitted changes in working tree".into(),
         },
         Err(e) => CheckResult {
             name: "Working tree".into(),
             passed: false,
+            warning: false,
             message: format!("Failed to check: {e}"),
         },
     }
 }
 
-fn check_release_branch(override_branch: Option<&str>) -> CheckResult {
+fn check_release_branch(override_branch: Option<&str>, maintenance: &[String]) -> CheckResult {
     let current = match git::current_branch() {
         Ok(Some(b)) => b,
         Ok(None) => {
             return CheckResult {
                 name: "Release branch".into(),
                 passed: false,
+                warning: false,
                 message: "Detached HEAD — not on any branch".into(),
             };
         }
@@ -142,17 +371,36 @@ fn check_release_branch(override_branch: Option<&str>) -> CheckResult {
             return CheckResult {
                 name: "Release branch".into(),
                 passed: false,
+                warning: false,
                 message: format!("Failed to check: {e}"),
             };
         }
     };
 
+    // Maintenance branches release their own line
+    if maintenance
+        .iter()
+        .any(|pattern| glob::wildcard_match(pattern, &current))
+    {
+        let message = VersionLine::from_branch(&current).map_or_else(
+            || format!("On maintenance branch '{current}'"),
+            |line| format!("On maintenance branch '{current}' ({line} releases)"),
+        );
+        return CheckResult {
+            name: "Release branch".into(),
+            passed: true,
+            warning: false,
+            message,
+        };
+    }
+
     // If the user specified a release branch, check against that
     if let Some(expected) = override_branch {
         let passed = current == expected;
         return CheckResult {
             name: "Release branch".into(),
             passed,
+            warning: false,
             message: if passed {
                 format!("On configured release branch '{current}'")
             } else {
@@ -168,6 +416,7 @@ fn check_release_branch(override_branch: Option<&str>) -> CheckResult {
             CheckResult {
                 name: "Release branch".into(),
                 passed,
+                warning: false,
                 message: if passed {
                     format!("On release branch '{current}'")
                 } else {
@@ -178,11 +427,13 @@ fn check_release_branch(override_branch: Option<&str>) -> CheckResult {
         Ok(None) => CheckResult {
             name: "Release branch".into(),
             passed: false,
+            warning: false,
             message: format!("On '{current}' — no main/master branch found"),
         },
         Err(e) => CheckResult {
             name: "Release branch".into(),
             passed: false,
+            warning: false,
             message: format!("Failed to detect: {e}"),
         },
     }
@@ -193,36 +444,70 @@ fn check_remote_sync() -> CheckResult {
         Ok(true) => CheckResult {
             name: "Remote sync".into(),
             passed: true,
+            warning: false,
             message: "Local branch is in sync with remote".into(),
         },
         Ok(false) => CheckResult {
             name: "Remote sync".into(),
             passed: false,
+            warning: false,
             message: "Local branch is out of sync with remote (pull or push needed)".into(),
         },
         Err(e) => CheckResult {
             name: "Remote sync".into(),
             passed: false,
+            warning: false,
             message: format!("Failed to check: {e}"),
         },
     }
 }
 
-fn check_ecosystem(detection: &Option<ProjectDetection>) -> CheckResult {
-    detection.as_ref().map_or_else(
-        || CheckResult {
-            name: "Project detection".into(),
-            // Not a hard failure — CLI will prompt for ecosystem selection
-            passed: true,
-            message: "No ecosystem detected — select interactively or set project.type in config"
-                .into(),
-        },
-        |det| CheckResult {
+fn check_ecosystem(
+    detection: &Option<ProjectDetection>,
+    ecosystems: &[EcosystemMatch],
+) -> CheckResult {
+    let Some(det) = detection else {
+        // Not a hard failure — CLI will prompt for ecosystem selection
+        let message = if ecosystems.len() > 1 {
+            format!(
+                "Several ecosystems detected: {} — select one interactively or set project.type in config",
+                ecosystems
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        } else {
+            "No ecosystem detected — select interactively or set project.type in config".into()
+        };
+        return CheckResult {
             name: "Project detection".into(),
             passed: true,
-            message: format!("Detected {} project", det.ecosystem),
-        },
-    )
+            warning: false,
+            message,
+        };
+    };
+
+    let others: Vec<String> = ecosystems
+        .iter()
+        .filter(|m| m.ecosystem != det.ecosystem)
+        .map(ToString::to_string)
+        .collect();
+    let message = if others.is_empty() {
+        format!("Detected {} project", det.ecosystem)
+    } else {
+        format!(
+            "Using {} project (also found: {})",
+            det.ecosystem,
+            others.join(", ")
+        )
+    };
+    CheckResult {
+        name: "Project detection".into(),
+        passed: true,
+        warning: false,
+        message,
+    }
 }
 
 fn check_required_tools(detection: &ProjectDetection) -> CheckResult {
@@ -255,6 +540,7 @@ fn check_required_tools(detection: &ProjectDetection) -> CheckResult {
         return CheckResult {
             name: "Required tools".into(),
             passed: false,
+            warning: false,
             message: format!("Missing tools: {}", missing.join(", ")),
         };
     }
@@ -269,6 +555,7 @@ fn check_required_tools(detection: &ProjectDetection) -> CheckResult {
                 return CheckResult {
                     name: "Required tools".into(),
                     passed: false,
+                    warning: false,
                     message: format!(
                         "git-cliff {found} is too old (need {minimum}+) — run `cargo install git-cliff`"
                     ),
@@ -283,6 +570,7 @@ fn check_required_tools(detection: &ProjectDetection) -> CheckResult {
     CheckResult {
         name: "Required tools".into(),
         passed: true,
+        warning: false,
         message: "All required tools are installed".into(),
     }
 }
@@ -297,10 +585,12 @@ mod tests {
             checks: vec![CheckResult {
                 name: "test".into(),
                 passed: true,
+                warning: false,
                 message: "ok".into(),
             }],
             all_passed: true,
             detection: None,
+            ecosystems: Vec::new(),
         };
         let json = serde_json::to_string(&report).unwrap();
         assert!(json.contains("\"all_passed\":true"));
@@ -308,9 +598,139 @@ mod tests {
 
     #[test]
     fn check_ecosystem_none_passes_with_prompt_hint() {
-        let result = check_ecosystem(&None);
+        let result = check_ecosystem(&None, &[]);
         // No detection is not a hard failure — CLI will prompt for selection
         assert!(result.passed);
         assert!(result.message.contains("select interactively"));
     }
+
+    #[test]
+    fn check_ecosystem_lists_every_match() {
+        let matches = [
+            EcosystemMatch {
+                ecosystem: Ecosystem::Rust,
+                evidence: vec!["Cargo.toml".into()],
+            },
+            EcosystemMatch {
+                ecosystem: Ecosystem::Node,
+                evidence: vec!["package.json".into()],
+            },
+        ];
+        let ambiguous = check_ecosystem(&None, &matches);
+        assert!(ambiguous.passed);
+        assert!(
+            ambiguous
+                .message
+                .contains("rust (Cargo.toml), node (package.json)"),
+            "{}",
+            ambiguous.message
+        );
+
+        let chosen = ProjectDetection {
+            ecosystem: Ecosystem::Node,
+            ..ProjectDetection::generic(crate::ecosystem::VersionStrategy::Interactive)
+        };
+        let overridden = check_ecosystem(&Some(chosen), &matches);
+        assert_eq!(
+            overridden.message,
+            "Using node project (also found: rust (Cargo.toml))"
+        );
+    }
+
+    #[test]
+    fn drift_states() {
+        let v = |s: &str| Version::parse(s).unwrap();
+        let tag = v("1.3.0");
+        assert_eq!(
+            classify_drift(Some(&v("1.3.0")), &tag, false, true),
+            DriftState::InSync
+        );
+        assert_eq!(
+            classify_drift(Some(&v("1.4.0")), &tag, false, false),
+            DriftState::Ahead
+        );
+        assert_eq!(
+            classify_drift(Some(&v("1.2.9")), &tag, false, false),
+            DriftState::Behind
+        );
+        // The manifest's tag exists, but not on this branch
+        assert_eq!(
+            classify_drift(Some(&v("1.4.0")), &tag, false, true),
+            DriftState::Mismatch
+        );
+        assert_eq!(
+            classify_drift(None, &tag, false, false),
+            DriftState::Mismatch
+        );
+
+        // Snapshot projects sit on the next development version
+        assert_eq!(
+            classify_drift(Some(&v("1.3.1-SNAPSHOT")), &tag, true, false),
+            DriftState::InSync
+        );
+        assert_eq!(
+            classify_drift(Some(&v("2.0.0-SNAPSHOT")), &v("2.0.0-rc.1"), true, false),
+            DriftState::InSync
+        );
+        assert_eq!(
+            classify_drift(Some(&v("1.3.0-SNAPSHOT")), &tag, true, false),
+            DriftState::Behind
+        );
+    }
+
+    #[test]
+    fn drift_messages_name_the_repair() {
+        let drift = VersionDrift {
+            state: DriftState::Behind,
+            manifest_version: "1.2.0".into(),
+            tag: "v1.3.0".into(),
+            tag_version: Version::new(1, 3, 0),
+        };
+        assert_eq!(
+            drift.message(),
+            "Manifest version 1.2.0 is behind v1.3.0 — run `scrat bump --sync` to update the manifest"
+        );
+        let json = serde_json::to_value(&drift).unwrap();
+        assert_eq!(json["state"], "behind");
+    }
+
+    #[test]
+    fn drift_ahead_only_warns() {
+        let drift = |state| VersionDrift {
+            state,
+            manifest_version: "1.4.0".into(),
+            tag: "v1.3.0".into(),
+            tag_version: Version::new(1, 3, 0),
+        };
+        let ahead = drift_check(&drift(DriftState::Ahead));
+        assert!(ahead.passed && ahead.warning);
+        assert!(ahead.message.contains("fine if it is about to ship"));
+
+        let in_sync = drift_check(&drift(DriftState::InSync));
+        assert!(in_sync.passed && !in_sync.warning);
+        for state in [DriftState::Behind, DriftState::Mismatch] {
+            let check = drift_check(&drift(state));
+            assert!(!check.passed && !check.warning);
+        }
+    }
+
+    #[test]
+    fn release_version_checks_go_module_path() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("go.mod"), "module example.com/foo\n").unwrap();
+        let root = camino::Utf8Path::from_path(tmp.path()).unwrap();
+        let detection = ProjectDetection {
+            ecosystem: Ecosystem::Go,
+            ..ProjectDetection::generic(crate::ecosystem::VersionStrategy::Interactive)
+        };
+
+        let ok = check_release_version(root, &detection, &semver::Version::new(1, 4, 0));
+        assert!(ok.is_some_and(|c| c.passed));
+        let bad = check_release_version(root, &detection, &semver::Version::new(2, 0, 0)).unwrap();
+        assert!(!bad.passed);
+        assert!(bad.message.contains("`/v2`"));
+
+        let generic = ProjectDetection::generic(crate::ecosystem::VersionStrategy::Interactive);
+        assert!(check_release_version(root, &generic, &semver::Version::new(2, 0, 0)).is_none());
+    }
 }
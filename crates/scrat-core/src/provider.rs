@@ -0,0 +1,765 @@
+//! Pluggable ecosystem providers.
+//!
+//! Everything scrat knows about an ecosystem — how to recognize a project,
+//! which commands to run, where the version lives, and which lockfiles to
+//! diff — sits behind the [`EcosystemProvider`] trait. The built-in
+//! ecosystems are providers like any other; embedders can [`register`]
+//! their own (or replace a built-in one) without forking.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use camino::Utf8Path;
+//! use scrat_core::ecosystem::DetectedTools;
+//! use scrat_core::manifest::ManifestResult;
+//! use scrat_core::provider::{self, EcosystemProvider, VersionUpdate};
+//!
+//! struct Terraform;
+//!
+//! impl EcosystemProvider for Terraform {
+//!     fn name(&self) -> &'static str {
+//!         "terraform"
+//!     }
+//!
+//!     fn marker_file(&self) -> Option<&'static str> {
+//!         Some("module.json")
+//!     }
+//!
+//!     fn default_tools(&self, _project_root: &Utf8Path) -> DetectedTools {
+//!         DetectedTools {
+//!             test_cmd: "terraform test".into(),
+//!             build_cmd: "terraform validate".into(),
+//!             publish_cmd: None,
+//!             bump_cmd: None,
+//!             changelog_tool: None,
+//!         }
+//!     }
+//!
+//!     fn set_version(&self, _update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+//!         Ok(Vec::new())
+//!     }
+//! }
+//!
+//! provider::register(Box::new(Terraform));
+//! ```
+
+use std::sync::RwLock;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use semver::Version;
+use tracing::debug;
+
+use crate::deps;
+use crate::detect;
+use crate::ecosystem::{DetectedTools, Ecosystem};
+use crate::manifest::{self, ManifestResult};
+use crate::pipeline::DepChange;
+
+// ──────────────────────────────────────────────
+// Trait
+// ──────────────────────────────────────────────
+
+/// Ecosystem-specific behavior: detection, tools, versions, and lockfiles.
+pub trait EcosystemProvider: Send + Sync {
+    /// Unique name, used for `project.type` in config and in output
+    /// (e.g. `"rust"`).
+    fn name(&self) -> &'static str;
+
+    /// File whose presence marks a project of this ecosystem.
+    fn marker_file(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The manifest in `dir` that records the version, reported as
+    /// modified when a configured bump command rewrites it.
+    ///
+    /// Defaults to the [`marker_file`](Self::marker_file).
+    fn manifest_file(&self, _dir: &Utf8Path) -> Option<String> {
+        self.marker_file().map(str::to_string)
+    }
+
+    /// Files in `dir` showing it holds a project of this ecosystem; empty
+    /// when it does not.
+    ///
+    /// Defaults to the [`marker_file`](Self::marker_file), followed by any
+    /// of the [`lockfiles`](Self::lockfiles) present next to it.
+    fn evidence(&self, dir: &Utf8Path) -> Vec<String> {
+        let Some(marker) = self.marker_file().filter(|m| dir.join(m).is_file()) else {
+            return Vec::new();
+        };
+        std::iter::once(marker)
+            .chain(
+                self.lockfiles()
+                    .iter()
+                    .copied()
+                    .filter(|lockfile| dir.join(lockfile).is_file()),
+            )
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Default test, build, and publish commands for the project.
+    ///
+    /// [`detect::detect_changelog_tool`] fills in `changelog_tool`.
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools;
+
+    /// Read the version recorded in the project's manifest, if any.
+    ///
+    /// `version_module` is the configured `project.version_module`, for
+    /// ecosystems that can keep the version in source.
+    fn current_version(
+        &self,
+        _project_root: &Utf8Path,
+        _version_module: Option<&Utf8Path>,
+    ) -> Option<String> {
+        None
+    }
+
+    /// Write the new version into the project's files.
+    ///
+    /// Returns the paths (relative to the repository root) of the files
+    /// modified.
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>>;
+
+    /// Whether the project carries a `-SNAPSHOT` development version
+    /// between releases (a Maven convention, common with Gradle too).
+    ///
+    /// When it does, the release commit is followed by one moving the
+    /// version on to the next patch snapshot (`1.4.0` → `1.4.1-SNAPSHOT`).
+    fn uses_snapshots(&self, _project_root: &Utf8Path) -> bool {
+        false
+    }
+
+    /// Lockfiles with parseable dependency diffs, in probe order.
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Parse a `git diff` of one of [`lockfiles`](Self::lockfiles).
+    fn parse_lockfile_diff(&self, _lockfile: &str, _diff: &str) -> Vec<DepChange> {
+        Vec::new()
+    }
+}
+
+/// A version rewrite request passed to [`EcosystemProvider::set_version`].
+#[derive(Debug, Clone, Copy)]
+pub struct VersionUpdate<'a> {
+    /// Repository root.
+    pub project_root: &'a Utf8Path,
+    /// Directory of the monorepo package being released, relative to
+    /// `project_root` (`None` = the whole repository).
+    pub package_dir: Option<&'a Utf8Path>,
+    /// The new version.
+    pub version: &'a Version,
+    /// Python module whose `__version__` is bumped (`project.version_module`).
+    pub version_module: Option<&'a Utf8Path>,
+}
+
+impl VersionUpdate<'_> {
+    /// Directory holding the manifest: the package directory, or the root.
+    pub fn work_dir(&self) -> Utf8PathBuf {
+        self.package_dir.map_or_else(
+            || self.project_root.to_owned(),
+            |dir| self.project_root.join(dir),
+        )
+    }
+
+    /// Turn paths relative to [`work_dir`](Self::work_dir) into paths
+    /// relative to the repository root.
+    pub fn from_work_dir(&self, files: Vec<String>) -> Vec<String> {
+        match self.package_dir {
+            Some(dir) => files
+                .into_iter()
+                .map(|file| dir.join(file).into_string())
+                .collect(),
+            None => files,
+        }
+    }
+}
+
+// ──────────────────────────────────────────────
+// Registry
+// ──────────────────────────────────────────────
+
+/// Providers registered by embedders, most recent first.
+static REGISTERED: RwLock<Vec<&'static dyn EcosystemProvider>> = RwLock::new(Vec::new());
+
+/// Register a provider for the rest of the process.
+///
+/// Registered providers are tried before the built-in ones during
+/// detection, and one named like a built-in ecosystem replaces it.
+/// Register before loading config so `project.type` can name it.
+pub fn register(provider: Box<dyn EcosystemProvider>) {
+    let provider: &'static dyn EcosystemProvider = Box::leak(provider);
+    debug!(name = provider.name(), "registered ecosystem provider");
+    let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+    registered.retain(|p| p.name() != provider.name());
+    registered.insert(0, provider);
+}
+
+/// All providers, in detection order: registered ones, then the built-ins.
+pub fn providers() -> Vec<&'static dyn EcosystemProvider> {
+    let mut all = registered();
+    let builtins = Ecosystem::ALL.iter().map(|e| builtin(*e));
+    for provider in builtins {
+        if !all.iter().any(|p| p.name() == provider.name()) {
+            all.push(provider);
+        }
+    }
+    all
+}
+
+/// The provider for `ecosystem`.
+///
+/// A custom ecosystem that was never registered falls back to the generic
+/// provider.
+pub fn get(ecosystem: Ecosystem) -> &'static dyn EcosystemProvider {
+    let name = ecosystem.name();
+    registered()
+        .into_iter()
+        .find(|p| p.name() == name)
+        .unwrap_or_else(|| builtin(ecosystem))
+}
+
+/// Providers registered by embedders.
+pub(crate) fn registered() -> Vec<&'static dyn EcosystemProvider> {
+    REGISTERED.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// The built-in provider for `ecosystem`.
+const fn builtin(ecosystem: Ecosystem) -> &'static dyn EcosystemProvider {
+    match ecosystem {
+        Ecosystem::Rust => &RustProvider,
+        Ecosystem::Node => &NodeProvider,
+        Ecosystem::Python => &PythonProvider,
+        Ecosystem::Go => &GoProvider,
+        Ecosystem::Maven => &MavenProvider,
+        Ecosystem::Gradle => &GradleProvider,
+        Ecosystem::Ruby => &RubyProvider,
+        Ecosystem::Php => &PhpProvider,
+        Ecosystem::Generic | Ecosystem::Custom(_) => &GenericProvider,
+    }
+}
+
+// ──────────────────────────────────────────────
+// Built-in providers
+// ──────────────────────────────────────────────
+
+/// Rust (`Cargo.toml`), including Cargo workspaces.
+struct RustProvider;
+
+impl EcosystemProvider for RustProvider {
+    fn name(&self) -> &'static str {
+        "rust"
+    }
+
+    fn marker_file(&self) -> Option<&'static str> {
+        Some("Cargo.toml")
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::rust::detect_rust(project_root)
+    }
+
+    fn current_version(&self, project_root: &Utf8Path, _: Option<&Utf8Path>) -> Option<String> {
+        manifest::cargo::current_version(project_root)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        match update.package_dir {
+            // Members of a Cargo workspace rooted at the repository root
+            Some(dir) if update.project_root.join("Cargo.toml").is_file() => {
+                manifest::cargo::set_member_version(update.project_root, dir, update.version)
+            }
+            _ => manifest::cargo::set_version(&update.work_dir(), update.version)
+                .map(|files| update.from_work_dir(files)),
+        }
+    }
+
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["Cargo.lock"]
+    }
+
+    fn parse_lockfile_diff(&self, lockfile: &str, diff: &str) -> Vec<DepChange> {
+        deps::parse_lockfile_diff(lockfile, diff)
+    }
+}
+
+/// Node.js (`package.json`), including npm workspaces.
+struct NodeProvider;
+
+impl EcosystemProvider for NodeProvider {
+    fn name(&self) -> &'static str {
+        "node"
+    }
+
+    fn marker_file(&self) -> Option<&'static str> {
+        Some("package.json")
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::node::detect_node(project_root)
+    }
+
+    fn current_version(&self, project_root: &Utf8Path, _: Option<&Utf8Path>) -> Option<String> {
+        manifest::node::current_version(project_root)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        update.package_dir.map_or_else(
+            || manifest::node::set_version(update.project_root, update.version),
+            |dir| manifest::node::set_workspace_version(update.project_root, dir, update.version),
+        )
+    }
+
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &[
+            "package-lock.json",
+            "npm-shrinkwrap.json",
+            "pnpm-lock.yaml",
+            "yarn.lock",
+        ]
+    }
+
+    fn parse_lockfile_diff(&self, lockfile: &str, diff: &str) -> Vec<DepChange> {
+        deps::parse_lockfile_diff(lockfile, diff)
+    }
+}
+
+/// Python (`pyproject.toml`).
+struct PythonProvider;
+
+impl EcosystemProvider for PythonProvider {
+    fn name(&self) -> &'static str {
+        "python"
+    }
+
+    fn marker_file(&self) -> Option<&'static str> {
+        Some("pyproject.toml")
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::python::detect_python(project_root)
+    }
+
+    fn current_version(
+        &self,
+        project_root: &Utf8Path,
+        version_module: Option<&Utf8Path>,
+    ) -> Option<String> {
+        manifest::python::current_version(project_root, version_module)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        manifest::python::set_version(&update.work_dir(), update.version, update.version_module)
+            .map(|files| update.from_work_dir(files))
+    }
+
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["uv.lock", "poetry.lock", "requirements.txt"]
+    }
+
+    fn parse_lockfile_diff(&self, lockfile: &str, diff: &str) -> Vec<DepChange> {
+        deps::parse_lockfile_diff(lockfile, diff)
+    }
+}
+
+/// Go modules (`go.mod`), versioned by tags alone.
+struct GoProvider;
+
+impl EcosystemProvider for GoProvider {
+    fn name(&self) -> &'static str {
+        "go"
+    }
+
+    fn marker_file(&self) -> Option<&'static str> {
+        Some("go.mod")
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::go::detect_go(project_root)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        manifest::go::check_module_path(&update.work_dir(), update.version)?;
+        debug!("Go modules are versioned by tags — no project files to bump");
+        Ok(Vec::new())
+    }
+
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["go.sum"]
+    }
+
+    fn parse_lockfile_diff(&self, lockfile: &str, diff: &str) -> Vec<DepChange> {
+        deps::parse_lockfile_diff(lockfile, diff)
+    }
+}
+
+/// Maven (`pom.xml`), including multi-module builds.
+struct MavenProvider;
+
+impl EcosystemProvider for MavenProvider {
+    fn name(&self) -> &'static str {
+        "maven"
+    }
+
+    fn marker_file(&self) -> Option<&'static str> {
+        Some("pom.xml")
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::maven::detect_maven(project_root)
+    }
+
+    fn current_version(&self, project_root: &Utf8Path, _: Option<&Utf8Path>) -> Option<String> {
+        manifest::maven::current_version(project_root)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        manifest::maven::set_version(&update.work_dir(), update.version)
+            .map(|files| update.from_work_dir(files))
+    }
+
+    fn uses_snapshots(&self, project_root: &Utf8Path) -> bool {
+        is_snapshot(self.current_version(project_root, None))
+    }
+}
+
+/// Gradle (`settings.gradle(.kts)` / `build.gradle(.kts)`).
+struct GradleProvider;
+
+impl EcosystemProvider for GradleProvider {
+    fn name(&self) -> &'static str {
+        "gradle"
+    }
+
+    fn manifest_file(&self, dir: &Utf8Path) -> Option<String> {
+        manifest::gradle::version_file(dir)
+            .or_else(|| {
+                detect::gradle::MARKERS
+                    .iter()
+                    .copied()
+                    .find(|marker| dir.join(marker).is_file())
+            })
+            .map(str::to_string)
+    }
+
+    fn evidence(&self, dir: &Utf8Path) -> Vec<String> {
+        let present = |files: &[&str]| -> Vec<String> {
+            files
+                .iter()
+                .filter(|file| dir.join(file).is_file())
+                .map(|file| (*file).to_string())
+                .collect()
+        };
+        let mut evidence = present(detect::gradle::MARKERS);
+        if !evidence.is_empty() {
+            evidence.extend(present(self.lockfiles()));
+        }
+        evidence
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::gradle::detect_gradle(project_root)
+    }
+
+    fn current_version(&self, project_root: &Utf8Path, _: Option<&Utf8Path>) -> Option<String> {
+        manifest::gradle::current_version(project_root)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        manifest::gradle::set_version(&update.work_dir(), update.version)
+            .map(|files| update.from_work_dir(files))
+    }
+
+    fn uses_snapshots(&self, project_root: &Utf8Path) -> bool {
+        is_snapshot(self.current_version(project_root, None))
+    }
+
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["gradle.lockfile"]
+    }
+
+    fn parse_lockfile_diff(&self, lockfile: &str, diff: &str) -> Vec<DepChange> {
+        deps::parse_lockfile_diff(lockfile, diff)
+    }
+}
+
+/// Ruby gems (`*.gemspec`).
+struct RubyProvider;
+
+impl EcosystemProvider for RubyProvider {
+    fn name(&self) -> &'static str {
+        "ruby"
+    }
+
+    fn evidence(&self, dir: &Utf8Path) -> Vec<String> {
+        let Some(gemspec) = manifest::ruby::gemspec(dir) else {
+            return Vec::new();
+        };
+        std::iter::once(gemspec.into_string())
+            .chain(
+                self.lockfiles()
+                    .iter()
+                    .filter(|lockfile| dir.join(lockfile).is_file())
+                    .map(|lockfile| (*lockfile).to_string()),
+            )
+            .collect()
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::ruby::detect_ruby(project_root)
+    }
+
+    fn current_version(&self, project_root: &Utf8Path, _: Option<&Utf8Path>) -> Option<String> {
+        manifest::ruby::current_version(project_root)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        manifest::ruby::set_version(&update.work_dir(), update.version)
+            .map(|files| update.from_work_dir(files))
+    }
+
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["Gemfile.lock"]
+    }
+
+    fn parse_lockfile_diff(&self, lockfile: &str, diff: &str) -> Vec<DepChange> {
+        deps::parse_lockfile_diff(lockfile, diff)
+    }
+}
+
+/// PHP Composer packages (`composer.json`), published by tag.
+struct PhpProvider;
+
+impl EcosystemProvider for PhpProvider {
+    fn name(&self) -> &'static str {
+        "php"
+    }
+
+    fn marker_file(&self) -> Option<&'static str> {
+        Some("composer.json")
+    }
+
+    fn default_tools(&self, project_root: &Utf8Path) -> DetectedTools {
+        detect::php::detect_php(project_root)
+    }
+
+    fn current_version(&self, project_root: &Utf8Path, _: Option<&Utf8Path>) -> Option<String> {
+        manifest::php::current_version(project_root)
+    }
+
+    fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        manifest::php::set_version(&update.work_dir(), update.version)
+            .map(|files| update.from_work_dir(files))
+    }
+
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["composer.lock"]
+    }
+
+    fn parse_lockfile_diff(&self, lockfile: &str, diff: &str) -> Vec<DepChange> {
+        deps::parse_lockfile_diff(lockfile, diff)
+    }
+}
+
+/// Whether a manifest version is a Maven-style snapshot.
+fn is_snapshot(version: Option<String>) -> bool {
+    version.is_some_and(|v| v.ends_with("-SNAPSHOT"))
+}
+
+/// No ecosystem-specific behavior; never auto-detected.
+struct GenericProvider;
+
+impl EcosystemProvider for GenericProvider {
+    fn name(&self) -> &'static str {
+        "generic"
+    }
+
+    fn default_tools(&self, _project_root: &Utf8Path) -> DetectedTools {
+        DetectedTools {
+            test_cmd: String::new(),
+            build_cmd: String::new(),
+            publish_cmd: None,
+            bump_cmd: None,
+            changelog_tool: None,
+        }
+    }
+
+    fn set_version(&self, _update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+        debug!("generic ecosystem — no project files to bump");
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    /// Registered under a name no other test uses: the registry is global.
+    struct Terraform;
+
+    impl EcosystemProvider for Terraform {
+        fn name(&self) -> &'static str {
+            "terraform"
+        }
+
+        fn marker_file(&self) -> Option<&'static str> {
+            Some("module.json")
+        }
+
+        fn default_tools(&self, _project_root: &Utf8Path) -> DetectedTools {
+            DetectedTools {
+                test_cmd: "terraform test".into(),
+                build_cmd: String::new(),
+                publish_cmd: None,
+                bump_cmd: None,
+                changelog_tool: None,
+            }
+        }
+
+        fn current_version(&self, project_root: &Utf8Path, _: Option<&Utf8Path>) -> Option<String> {
+            let content = fs::read_to_string(project_root.join("module.json")).ok()?;
+            manifest::json::get_string(&content, &["version"])
+        }
+
+        fn set_version(&self, update: &VersionUpdate<'_>) -> ManifestResult<Vec<String>> {
+            let path = update.work_dir().join("module.json");
+            let content = fs::read_to_string(&path).unwrap();
+            let updated =
+                manifest::json::replace_string(&content, &["version"], &update.version.to_string())
+                    .unwrap();
+            fs::write(&path, updated).unwrap();
+            Ok(update.from_work_dir(vec!["module.json".into()]))
+        }
+    }
+
+    #[test]
+    fn builtins_are_providers() {
+        assert_eq!(get(Ecosystem::Rust).marker_file(), Some("Cargo.toml"));
+        assert_eq!(get(Ecosystem::Go).lockfiles(), &["go.sum"]);
+        assert_eq!(get(Ecosystem::Generic).marker_file(), None);
+
+        let names: Vec<&str> = providers().iter().map(|p| p.name()).collect();
+        for ecosystem in Ecosystem::ALL {
+            assert!(names.contains(&ecosystem.name()), "{ecosystem}");
+        }
+    }
+
+    #[test]
+    fn jvm_snapshots_and_gradle_evidence() {
+        let tmp = TempDir::new().unwrap();
+        let root = utf8_tmp(&tmp);
+        fs::write(tmp.path().join("settings.gradle.kts"), "").unwrap();
+        fs::write(tmp.path().join("gradle.lockfile"), "").unwrap();
+        fs::write(tmp.path().join("gradle.properties"), "version=1.0.0\n").unwrap();
+
+        let gradle = get(Ecosystem::Gradle);
+        assert_eq!(
+            gradle.evidence(root),
+            vec!["settings.gradle.kts", "gradle.lockfile"]
+        );
+        assert!(!gradle.uses_snapshots(root));
+        fs::write(
+            tmp.path().join("gradle.properties"),
+            "version=1.1.0-SNAPSHOT\n",
+        )
+        .unwrap();
+        assert!(gradle.uses_snapshots(root));
+        assert!(!get(Ecosystem::Maven).uses_snapshots(root));
+    }
+
+    #[test]
+    fn gradle_manifest_is_the_file_in_use() {
+        let tmp = TempDir::new().unwrap();
+        let root = utf8_tmp(&tmp);
+        let gradle = get(Ecosystem::Gradle);
+        assert_eq!(gradle.manifest_file(root), None);
+
+        fs::write(tmp.path().join("build.gradle"), "plugins {}\n").unwrap();
+        assert_eq!(gradle.manifest_file(root).as_deref(), Some("build.gradle"));
+
+        fs::write(tmp.path().join("build.gradle"), "version '1.0.0'\n").unwrap();
+        fs::write(tmp.path().join("gradle.properties"), "version=1.0.0\n").unwrap();
+        assert_eq!(
+            gradle.manifest_file(root).as_deref(),
+            Some("gradle.properties")
+        );
+    }
+
+    #[test]
+    fn python_version_module_is_read_from_config() {
+        let tmp = TempDir::new().unwrap();
+        let root = utf8_tmp(&tmp);
+        fs::write(
+            tmp.path().join("pyproject.toml"),
+            "[project]\nname = \"app\"\ndynamic = [\"version\"]\n",
+        )
+        .unwrap();
+        fs::create_dir_all(tmp.path().join("src/app")).unwrap();
+        fs::write(
+            tmp.path().join("src/app/_version.py"),
+            "__version__ = \"0.7.1\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            manifest::current_version(root, Ecosystem::Python, &Config::default()),
+            None
+        );
+
+        let config = Config {
+            project: Some(crate::config::ProjectConfig {
+                version_module: Some("src/app/_version.py".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            manifest::current_version(root, Ecosystem::Python, &config).as_deref(),
+            Some("0.7.1")
+        );
+    }
+
+    #[test]
+    fn registered_provider_detects_and_bumps() {
+        register(Box::new(Terraform));
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("modules/vpc")).unwrap();
+        fs::write(
+            tmp.path().join("modules/vpc/module.json"),
+            "{ \"version\": \"1.0.0\" }\n",
+        )
+        .unwrap();
+        let root = utf8_tmp(&tmp);
+
+        let ecosystem = Ecosystem::from_name("terraform").unwrap();
+        assert_eq!(ecosystem, Ecosystem::Custom("terraform"));
+        assert_eq!(ecosystem.marker_file(), Some("module.json"));
+
+        let detection = detect::detect_project(&root.join("modules/vpc")).unwrap();
+        assert_eq!(detection.ecosystem, ecosystem);
+        assert_eq!(detection.tools.test_cmd, "terraform test");
+
+        let modified = get(ecosystem)
+            .set_version(&VersionUpdate {
+                project_root: root,
+                package_dir: Some(Utf8Path::new("modules/vpc")),
+                version: &Version::new(1, 1, 0),
+                version_module: None,
+            })
+            .unwrap();
+        assert_eq!(modified, vec!["modules/vpc/module.json"]);
+        assert_eq!(
+            manifest::current_version(&root.join("modules/vpc"), ecosystem, &Config::default())
+                .as_deref(),
+            Some("1.1.0")
+        );
+    }
+}
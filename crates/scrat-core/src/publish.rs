@@ -0,0 +1,790 @@
+//! Ordered registry publishing for Cargo workspaces.
+//!
+//! A workspace can't go out with a single `cargo publish`: each crate must be
+//! published after the crates it depends on, and the registry index must
+//! list a dependency before a dependent can be verified against it.
+//!
+//! # Two-phase workflow
+//!
+//! 1. **Plan** ([`plan_cargo_publish`]) — read the workspace, drop
+//!    `publish = false` members, and order the rest topologically.
+//! 2. **Execute** ([`publish_cargo_crates`]) — for each crate, skip it if
+//!    the index already has this version (so a failed release can simply be
+//!    re-run), otherwise `cargo publish -p` it and poll the index until it
+//!    shows up before moving on to its dependents.
+//!
+//! Linked npm manifests (`version.linked`) follow the same plan/execute
+//! split: [`plan_linked_publish`] orders them by the pins between them and
+//! [`publish_linked_packages`] runs the publish command in each directory.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{debug, info, instrument, warn};
+
+use crate::config::PublishConfig;
+use crate::manifest::linked::LinkedManifest;
+use crate::manifest::{self, ManifestError};
+
+/// Default registry index (crates.io sparse index).
+pub const DEFAULT_INDEX: &str = "https://index.crates.io/";
+
+/// Default command run in each linked npm package directory.
+pub const DEFAULT_LINKED_COMMAND: &str = "npm publish";
+
+/// Default seconds between index polls.
+const DEFAULT_POLL_INTERVAL: u64 = 5;
+
+/// Default seconds to wait for a crate to appear in the index.
+const DEFAULT_TIMEOUT: u64 = 300;
+
+// ──────────────────────────────────────────────
+// Errors
+// ──────────────────────────────────────────────
+
+/// Errors from publishing.
+#[derive(Error, Debug)]
+pub enum PublishError {
+    /// Reading the workspace manifests failed.
+    #[error(transparent)]
+    Manifest(#[from] ManifestError),
+
+    /// A publishable crate or package has no resolvable version.
+    #[error("package {0} has no version")]
+    MissingVersion(String),
+
+    /// The dependencies between publishable packages form a cycle.
+    #[error("dependency cycle between packages: {}", .0.join(", "))]
+    Cycle(Vec<String>),
+
+    /// `cargo publish` (or the linked publish command) failed for a package.
+    #[error("failed to publish {krate}: {message}{}", published_note(.published))]
+    Failed {
+        /// The crate that failed.
+        krate: String,
+        /// Error details.
+        message: String,
+        /// Crates published (or already present) before the failure.
+        published: Vec<String>,
+    },
+
+    /// An alternate registry is set without the index to poll for it.
+    #[error(
+        "publish.registry = \"{0}\" needs publish.index: scrat polls the index to skip \
+         published crates and to wait for uploads, and crates.io's index says nothing about {0}"
+    )]
+    MissingIndex(String),
+
+    /// A published crate never showed up in the registry index.
+    #[error(
+        "timed out after {timeout}s waiting for {krate} {version} to appear in {index}{}",
+        published_note(.published)
+    )]
+    IndexTimeout {
+        /// The crate being waited on.
+        krate: String,
+        /// Its version.
+        version: String,
+        /// The index that was polled.
+        index: String,
+        /// Seconds waited.
+        timeout: u64,
+        /// Crates published (or already present), including `krate`.
+        published: Vec<String>,
+    },
+}
+
+/// Result alias for publish operations.
+pub type PublishResult<T> = Result<T, PublishError>;
+
+/// Suffix listing the crates that made it out before a failure.
+fn published_note(published: &[String]) -> String {
+    if published.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " (already published: {}; re-run to continue with the rest)",
+            published.join(", ")
+        )
+    }
+}
+
+// ──────────────────────────────────────────────
+// Types
+// ──────────────────────────────────────────────
+
+/// A crate scheduled for publishing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PublishCrate {
+    /// Crate name.
+    pub name: String,
+    /// Version being published.
+    pub version: String,
+    /// Publishable crates this one depends on (must go out first).
+    pub dependencies: Vec<String>,
+}
+
+/// Where to look for published crate versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryIndex {
+    /// A sparse HTTP index, fetched with `curl` (base URL with trailing `/`).
+    Sparse(String),
+    /// A local index directory (e.g. a mirror checkout).
+    Local(Utf8PathBuf),
+}
+
+impl RegistryIndex {
+    /// Parse an index location from config.
+    ///
+    /// `sparse+https://…`, `https://…` and `http://…` are sparse indexes;
+    /// `file://…` and plain paths are local directories.
+    pub fn parse(spec: &str) -> Self {
+        let spec = spec.trim();
+        let url = spec.strip_prefix("sparse+").unwrap_or(spec);
+        if url.starts_with("https://") || url.starts_with("http://") {
+            let base = if url.ends_with('/') {
+                url.to_string()
+            } else {
+                format!("{url}/")
+            };
+            Self::Sparse(base)
+        } else {
+            Self::Local(Utf8PathBuf::from(
+                url.strip_prefix("file://").unwrap_or(url),
+            ))
+        }
+    }
+
+    /// Check whether `name` at `version` is listed in the index.
+    ///
+    /// A missing index entry is `Ok(false)`; an unreachable index is an error.
+    pub fn has_version(&self, name: &str, version: &str) -> Result<bool, String> {
+        let entry_path = index_entry_path(name);
+        let content = match self {
+            Self::Local(dir) => match std::fs::read_to_string(dir.join(&entry_path)) {
+                Ok(c) => c,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+                Err(e) => return Err(format!("failed to read index entry: {e}")),
+            },
+            Self::Sparse(base) => {
+                let url = format!("{base}{entry_path}");
+                let output = Command::new("curl")
+                    .args(["-sSfL", &url])
+                    .output()
+                    .map_err(|e| format!("failed to run curl: {e}"))?;
+                // curl exits 22 on HTTP errors (-f), i.e. 404 for unknown crates
+                if output.status.code() == Some(22) {
+                    return Ok(false);
+                }
+                if !output.status.success() {
+                    return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+                }
+                String::from_utf8_lossy(&output.stdout).into_owned()
+            }
+        };
+        Ok(index_lists_version(&content, version))
+    }
+}
+
+impl fmt::Display for RegistryIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sparse(url) => write!(f, "{url}"),
+            Self::Local(dir) => write!(f, "{dir}"),
+        }
+    }
+}
+
+/// Resolved publish settings (config values with defaults applied).
+#[derive(Debug, Clone)]
+pub struct PublishSettings {
+    /// Registry name for `cargo publish --registry`.
+    pub registry: Option<String>,
+    /// Index to poll after each upload.
+    pub index: RegistryIndex,
+    /// Time between index polls.
+    pub poll_interval: Duration,
+    /// How long to wait for a crate to appear in the index.
+    pub timeout: Duration,
+}
+
+impl PublishSettings {
+    /// Build settings from the optional `[publish]` config section.
+    ///
+    /// The index defaults to crates.io only when no registry is set; an
+    /// alternate registry must name its index, or scrat would poll the
+    /// wrong one.
+    pub fn from_config(config: Option<&PublishConfig>) -> PublishResult<Self> {
+        let registry = config.and_then(|c| c.registry.clone());
+        let index = match (config.and_then(|c| c.index.as_deref()), &registry) {
+            (Some(index), _) => index,
+            (None, Some(registry)) => return Err(PublishError::MissingIndex(registry.clone())),
+            (None, None) => DEFAULT_INDEX,
+        };
+        Ok(Self {
+            registry,
+            index: RegistryIndex::parse(index),
+            poll_interval: Duration::from_secs(
+                config
+                    .and_then(|c| c.poll_interval)
+                    .unwrap_or(DEFAULT_POLL_INTERVAL),
+            ),
+            timeout: Duration::from_secs(config.and_then(|c| c.timeout).unwrap_or(DEFAULT_TIMEOUT)),
+        })
+    }
+}
+
+/// Result of publishing a set of crates.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PublishReport {
+    /// Crates uploaded by this run, in order.
+    pub published: Vec<String>,
+    /// Crates skipped because the index already had this version.
+    pub already_published: Vec<String>,
+}
+
+// ──────────────────────────────────────────────
+// Plan
+// ──────────────────────────────────────────────
+
+/// Plan the publish order for the Cargo workspace at `project_root`.
+///
+/// Members with `publish = false` are dropped. The remaining crates are
+/// ordered so that every crate comes after its path dependencies; ties are
+/// broken alphabetically so the order is stable.
+#[instrument(fields(%project_root))]
+pub fn plan_cargo_publish(project_root: &Utf8Path) -> PublishResult<Vec<PublishCrate>> {
+    let packages = manifest::cargo::workspace_packages(project_root)?;
+    let publishable: BTreeSet<&str> = packages
+        .iter()
+        .filter(|p| p.publishable)
+        .map(|p| p.name.as_str())
+        .collect();
+
+    let crates = packages
+        .iter()
+        .filter(|p| p.publishable)
+        .map(|p| {
+            Ok(PublishCrate {
+                name: p.name.clone(),
+                version: p
+                    .version
+                    .clone()
+                    .ok_or_else(|| PublishError::MissingVersion(p.name.clone()))?,
+                dependencies: p
+                    .path_dependencies
+                    .iter()
+                    .filter(|d| publishable.contains(d.as_str()))
+                    .cloned()
+                    .collect(),
+            })
+        })
+        .collect::<PublishResult<Vec<_>>>()?;
+
+    let ordered = topological_order(crates)?;
+    debug!(
+        order = ?ordered.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        "planned publish order"
+    );
+    Ok(ordered)
+}
+
+/// Something published after the packages it depends on.
+trait Dependent {
+    fn name(&self) -> &str;
+    fn dependencies(&self) -> &[String];
+}
+
+impl Dependent for PublishCrate {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dependencies(&self) -> &[String] {
+        &self.dependencies
+    }
+}
+
+impl Dependent for LinkedManifest {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dependencies(&self) -> &[String] {
+        &self.dependencies
+    }
+}
+
+/// Order packages so dependencies come first (Kahn's algorithm).
+fn topological_order<T: Dependent>(packages: Vec<T>) -> PublishResult<Vec<T>> {
+    let mut pending: BTreeMap<String, T> = packages
+        .into_iter()
+        .map(|p| (p.name().to_string(), p))
+        .collect();
+    let mut ordered = Vec::with_capacity(pending.len());
+
+    while !pending.is_empty() {
+        let ready: Vec<String> = pending
+            .values()
+            .filter(|p| p.dependencies().iter().all(|d| !pending.contains_key(d)))
+            .map(|p| p.name().to_string())
+            .collect();
+        if ready.is_empty() {
+            return Err(PublishError::Cycle(pending.into_keys().collect()));
+        }
+        for name in ready {
+            if let Some(package) = pending.remove(&name) {
+                ordered.push(package);
+            }
+        }
+    }
+
+    Ok(ordered)
+}
+
+/// Plan the publish order for the linked npm manifests (`version.linked`).
+///
+/// Private packages are dropped; the rest are ordered so that a package
+/// comes after the linked packages it depends on (platform packages before
+/// the wrapper that pins them).
+#[instrument(skip(patterns), fields(%project_root))]
+pub fn plan_linked_publish(
+    project_root: &Utf8Path,
+    patterns: &[Utf8PathBuf],
+) -> PublishResult<Vec<LinkedManifest>> {
+    let manifests = manifest::linked::read_linked(project_root, patterns)?;
+    let publishable: BTreeSet<String> = manifests
+        .iter()
+        .filter(|m| !m.private)
+        .map(|m| m.name.clone())
+        .collect();
+
+    let packages = manifests
+        .into_iter()
+        .filter(|m| !m.private)
+        .map(|mut m| {
+            if m.version.is_none() {
+                return Err(PublishError::MissingVersion(m.name));
+            }
+            m.dependencies.retain(|d| publishable.contains(d));
+            Ok(m)
+        })
+        .collect::<PublishResult<Vec<_>>>()?;
+
+    let ordered = topological_order(packages)?;
+    debug!(
+        order = ?ordered.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+        "planned linked publish order"
+    );
+    Ok(ordered)
+}
+
+// ──────────────────────────────────────────────
+// Execute
+// ──────────────────────────────────────────────
+
+/// Publish `crates` in order with `cargo publish -p`.
+///
+/// Crates whose version is already in the index are skipped. After each
+/// upload, if a later crate depends on it, polls the index until it shows
+/// up. On failure, the error lists every crate that is already out.
+#[instrument(skip(crates, settings), fields(%project_root, index = %settings.index))]
+pub fn publish_cargo_crates(
+    project_root: &Utf8Path,
+    crates: &[PublishCrate],
+    settings: &PublishSettings,
+) -> PublishResult<PublishReport> {
+    let mut report = PublishReport::default();
+    let done = |report: &PublishReport| -> Vec<String> {
+        report
+            .already_published
+            .iter()
+            .chain(&report.published)
+            .cloned()
+            .collect()
+    };
+
+    for (i, krate) in crates.iter().enumerate() {
+        match settings.index.has_version(&krate.name, &krate.version) {
+            Ok(true) => {
+                info!(name = %krate.name, version = %krate.version, "already in index, skipping");
+                report.already_published.push(krate.name.clone());
+                continue;
+            }
+            Ok(false) => {}
+            Err(e) => warn!(name = %krate.name, %e, "could not check index, publishing anyway"),
+        }
+
+        info!(name = %krate.name, version = %krate.version, "publishing crate");
+        let mut cmd = Command::new("cargo");
+        cmd.args(["publish", "-p", &krate.name]);
+        if let Some(ref registry) = settings.registry {
+            cmd.args(["--registry", registry]);
+        }
+        let output = cmd
+            .current_dir(project_root.as_std_path())
+            .output()
+            .map_err(|e| PublishError::Failed {
+                krate: krate.name.clone(),
+                message: format!("failed to execute cargo: {e}"),
+                published: done(&report),
+            })?;
+
+        if !output.status.success() {
+            return Err(PublishError::Failed {
+                krate: krate.name.clone(),
+                message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                published: done(&report),
+            });
+        }
+        report.published.push(krate.name.clone());
+
+        let has_dependents = crates[i + 1..]
+            .iter()
+            .any(|later| later.dependencies.contains(&krate.name));
+        if has_dependents {
+            wait_for_index(krate, settings, || done(&report))?;
+        }
+    }
+
+    Ok(report)
+}
+
+/// Publish linked npm packages in order, running `command` in each
+/// package directory.
+///
+/// Packages whose version `npm view` already reports are skipped, so a
+/// failed release can be re-run.
+#[instrument(skip(packages), fields(%project_root))]
+pub fn publish_linked_packages(
+    project_root: &Utf8Path,
+    packages: &[LinkedManifest],
+    command: &str,
+) -> PublishResult<PublishReport> {
+    let mut report = PublishReport::default();
+
+    for package in packages {
+        let version = package.version.as_deref().unwrap_or_default();
+        if npm_has_version(&package.name, version) {
+            info!(name = %package.name, %version, "already in registry, skipping");
+            report.already_published.push(package.name.clone());
+            continue;
+        }
+
+        info!(name = %package.name, %version, "publishing linked package");
+        let output = Command::new("sh")
+            .args(["-c", command])
+            .current_dir(project_root.join(package.dir()).as_std_path())
+            .output();
+        let failure = match output {
+            Ok(output) if output.status.success() => None,
+            Ok(output) => Some(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+            Err(e) => Some(format!("failed to execute `{command}`: {e}")),
+        };
+        if let Some(message) = failure {
+            return Err(PublishError::Failed {
+                krate: package.name.clone(),
+                message,
+                published: report
+                    .already_published
+                    .iter()
+                    .chain(&report.published)
+                    .cloned()
+                    .collect(),
+            });
+        }
+        report.published.push(package.name.clone());
+    }
+
+    Ok(report)
+}
+
+/// Whether the npm registry already has `name` at `version`.
+///
+/// Any failure to ask (no `npm`, unknown package, offline) counts as no.
+fn npm_has_version(name: &str, version: &str) -> bool {
+    Command::new("npm")
+        .args(["view", &format!("{name}@{version}"), "version"])
+        .output()
+        .is_ok_and(|output| {
+            output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == version
+        })
+}
+
+/// Poll the index until `krate` is listed or the timeout elapses.
+fn wait_for_index(
+    krate: &PublishCrate,
+    settings: &PublishSettings,
+    published: impl Fn() -> Vec<String>,
+) -> PublishResult<()> {
+    let start = Instant::now();
+    loop {
+        match settings.index.has_version(&krate.name, &krate.version) {
+            Ok(true) => {
+                debug!(name = %krate.name, elapsed = ?start.elapsed(), "crate visible in index");
+                return Ok(());
+            }
+            Ok(false) => {}
+            Err(e) => warn!(name = %krate.name, %e, "index poll failed"),
+        }
+
+        if start.elapsed() >= settings.timeout {
+            return Err(PublishError::IndexTimeout {
+                krate: krate.name.clone(),
+                version: krate.version.clone(),
+                index: settings.index.to_string(),
+                timeout: settings.timeout.as_secs(),
+                published: published(),
+            });
+        }
+        std::thread::sleep(settings.poll_interval);
+    }
+}
+
+// ──────────────────────────────────────────────
+// Index helpers
+// ──────────────────────────────────────────────
+
+/// Path of a crate's entry within a registry index.
+///
+/// Follows the Cargo index layout: `1/a`, `2/ab`, `3/a/abc`, `ab/cd/abcd…`.
+fn index_entry_path(name: &str) -> String {
+    let name = name.to_lowercase();
+    match name.len() {
+        1 => format!("1/{name}"),
+        2 => format!("2/{name}"),
+        3 => format!("3/{}/{name}", &name[..1]),
+        _ => format!("{}/{}/{name}", &name[..2], &name[2..4]),
+    }
+}
+
+/// Whether an index entry (one JSON object per line) lists `version`.
+fn index_lists_version(content: &str, version: &str) -> bool {
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .any(|entry| entry["vers"].as_str() == Some(version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn utf8_tmp(tmp: &TempDir) -> &Utf8Path {
+        Utf8Path::from_path(tmp.path()).expect("tempdir is UTF-8")
+    }
+
+    fn write(tmp: &TempDir, rel: &str, content: &str) {
+        let path = tmp.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn krate(name: &str, deps: &[&str]) -> PublishCrate {
+        PublishCrate {
+            name: name.into(),
+            version: "1.0.0".into(),
+            dependencies: deps.iter().map(|d| (*d).to_string()).collect(),
+        }
+    }
+
+    /// Workspace: `app` → `mid` → `base`, plus an unpublished `xtask`.
+    fn workspace_fixture(tmp: &TempDir) {
+        write(
+            tmp,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\", \"xtask\"]\n\n[workspace.package]\nversion = \"1.0.0\"\n",
+        );
+        write(
+            tmp,
+            "crates/app/Cargo.toml",
+            "[package]\nname = \"app\"\nversion.workspace = true\n\n[dependencies]\nmid = { path = \"../mid\", version = \"1.0\" }\n",
+        );
+        write(
+            tmp,
+            "crates/mid/Cargo.toml",
+            "[package]\nname = \"mid\"\nversion.workspace = true\n\n[build-dependencies]\nbase = { path = \"../base\", version = \"1.0\" }\n",
+        );
+        write(
+            tmp,
+            "crates/base/Cargo.toml",
+            "[package]\nname = \"base\"\nversion.workspace = true\n",
+        );
+        write(
+            tmp,
+            "xtask/Cargo.toml",
+            "[package]\nname = \"xtask\"\nversion = \"0.0.0\"\npublish = false\n\n[dependencies]\napp = { path = \"../crates/app\" }\n",
+        );
+    }
+
+    fn local_settings(index: &Utf8Path) -> PublishSettings {
+        PublishSettings {
+            registry: None,
+            index: RegistryIndex::Local(index.to_owned()),
+            poll_interval: Duration::from_millis(1),
+            timeout: Duration::from_millis(10),
+        }
+    }
+
+    #[test]
+    fn plan_orders_dependencies_first_and_skips_unpublishable() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+
+        let plan = plan_cargo_publish(utf8_tmp(&tmp)).unwrap();
+        let names: Vec<&str> = plan.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["base", "mid", "app"]);
+        assert!(plan.iter().all(|c| c.version == "1.0.0"));
+    }
+
+    #[test]
+    fn topological_order_is_stable() {
+        let ordered = topological_order(vec![
+            krate("zeta", &[]),
+            krate("alpha", &[]),
+            krate("beta", &["zeta"]),
+        ])
+        .unwrap();
+        let names: Vec<&str> = ordered.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "zeta", "beta"]);
+    }
+
+    #[test]
+    fn topological_order_detects_cycles() {
+        let err = topological_order(vec![krate("a", &["b"]), krate("b", &["a"])]).unwrap_err();
+        assert!(matches!(err, PublishError::Cycle(ref names) if names == &["a", "b"]));
+    }
+
+    #[test]
+    fn linked_plan_puts_platforms_before_wrapper() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            &tmp,
+            "npm/tool/package.json",
+            r#"{ "name": "tool", "version": "1.0.0", "optionalDependencies": { "tool-linux": "1.0.0", "tool-dev": "1.0.0" } }"#,
+        );
+        write(
+            &tmp,
+            "npm/platforms/tool-linux/package.json",
+            r#"{ "name": "tool-linux", "version": "1.0.0" }"#,
+        );
+        write(
+            &tmp,
+            "npm/platforms/tool-dev/package.json",
+            r#"{ "name": "tool-dev", "version": "1.0.0", "private": true }"#,
+        );
+
+        let plan = plan_linked_publish(
+            utf8_tmp(&tmp),
+            &["npm/tool".into(), "npm/platforms/*".into()],
+        )
+        .unwrap();
+        let names: Vec<&str> = plan.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["tool-linux", "tool"]);
+        assert_eq!(plan[1].dependencies, vec!["tool-linux"]);
+        assert_eq!(plan[1].dir(), "npm/tool");
+    }
+
+    #[test]
+    fn index_entry_paths() {
+        assert_eq!(index_entry_path("a"), "1/a");
+        assert_eq!(index_entry_path("ab"), "2/ab");
+        assert_eq!(index_entry_path("abc"), "3/a/abc");
+        assert_eq!(index_entry_path("Serde"), "se/rd/serde");
+    }
+
+    #[test]
+    fn registry_index_parse() {
+        assert_eq!(
+            RegistryIndex::parse("sparse+https://index.crates.io"),
+            RegistryIndex::Sparse("https://index.crates.io/".into())
+        );
+        assert_eq!(
+            RegistryIndex::parse("file:///srv/mirror"),
+            RegistryIndex::Local("/srv/mirror".into())
+        );
+        assert_eq!(
+            RegistryIndex::parse("./mirror"),
+            RegistryIndex::Local("./mirror".into())
+        );
+    }
+
+    #[test]
+    fn alternate_registry_needs_an_index() {
+        let defaults = PublishSettings::from_config(None).unwrap();
+        assert_eq!(defaults.index, RegistryIndex::parse(DEFAULT_INDEX));
+
+        let mut config = PublishConfig {
+            registry: Some("internal".into()),
+            ..PublishConfig::default()
+        };
+        let err = PublishSettings::from_config(Some(&config)).unwrap_err();
+        assert!(matches!(err, PublishError::MissingIndex(ref r) if r == "internal"));
+
+        config.index = Some("sparse+https://crates.internal.example/index/".into());
+        let settings = PublishSettings::from_config(Some(&config)).unwrap();
+        assert_eq!(settings.registry.as_deref(), Some("internal"));
+        assert_eq!(
+            settings.index,
+            RegistryIndex::Sparse("https://crates.internal.example/index/".into())
+        );
+    }
+
+    #[test]
+    fn local_index_lookup() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            &tmp,
+            "ba/se/base",
+            "{\"name\":\"base\",\"vers\":\"0.9.0\"}\n{\"name\":\"base\",\"vers\":\"1.0.0\"}\n",
+        );
+        let index = RegistryIndex::Local(utf8_tmp(&tmp).to_owned());
+        assert_eq!(index.has_version("base", "1.0.0"), Ok(true));
+        assert_eq!(index.has_version("base", "1.1.0"), Ok(false));
+        assert_eq!(index.has_version("unknown", "1.0.0"), Ok(false));
+    }
+
+    #[test]
+    fn rerun_skips_crates_already_in_index() {
+        let tmp = TempDir::new().unwrap();
+        workspace_fixture(&tmp);
+        let index = TempDir::new().unwrap();
+        for (path, name) in [
+            ("3/a/app", "app"),
+            ("3/m/mid", "mid"),
+            ("ba/se/base", "base"),
+        ] {
+            write(
+                &index,
+                path,
+                &format!("{{\"name\":\"{name}\",\"vers\":\"1.0.0\"}}\n"),
+            );
+        }
+
+        let plan = plan_cargo_publish(utf8_tmp(&tmp)).unwrap();
+        let report =
+            publish_cargo_crates(utf8_tmp(&tmp), &plan, &local_settings(utf8_tmp(&index))).unwrap();
+        assert!(report.published.is_empty());
+        assert_eq!(report.already_published, vec!["base", "mid", "app"]);
+    }
+
+    #[test]
+    fn wait_for_index_times_out_with_published_list() {
+        let index = TempDir::new().unwrap();
+        let err = wait_for_index(
+            &krate("base", &[]),
+            &local_settings(utf8_tmp(&index)),
+            || vec!["base".into()],
+        )
+        .unwrap_err();
+        assert!(matches!(err, PublishError::IndexTimeout { .. }));
+        assert!(err.to_string().contains("already published: base"));
+    }
+}
@@ -0,0 +1,119 @@
+//! The git remote a release goes to.
+//!
+//! Pushing the release commit and tag, checking for an existing tag, naming
+//! the GitHub repository for `gh`, and building compare links all use the
+//! same remote. It is `project.remote` when set; otherwise the remote the
+//! current branch tracks, then the remote the release branch tracks, and
+//! finally `origin`. In a fork-based workflow, where `origin` is a personal
+//! fork and `upstream` the canonical repository, a release branch tracking
+//! `upstream/main` is enough to pick `upstream`.
+
+use serde::Serialize;
+use tracing::{debug, instrument};
+
+use crate::config::Config;
+use crate::git;
+
+/// The remote used when nothing else names one.
+pub const DEFAULT_REMOTE: &str = "origin";
+
+/// A resolved git remote.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Remote {
+    /// Remote name (e.g. `"origin"`, `"upstream"`).
+    pub name: String,
+    /// Fetch URL (`None` when the remote doesn't exist).
+    pub url: Option<String>,
+}
+
+impl Remote {
+    /// Resolve the release remote for the current repository.
+    ///
+    /// Never fails: git errors (for example outside a repository) fall
+    /// back to [`DEFAULT_REMOTE`] with no URL.
+    #[instrument(skip(config))]
+    pub fn resolve(config: &Config) -> Self {
+        let configured = config.project.as_ref().and_then(|p| p.remote.clone());
+        let name = configured
+            .or_else(|| tracked_remote(config))
+            .unwrap_or_else(|| DEFAULT_REMOTE.into());
+        let url = git::remote_url(&name).ok().flatten();
+        debug!(%name, ?url, "release remote");
+        Self { name, url }
+    }
+
+    /// Owner and repository name, parsed from the URL.
+    pub fn owner_repo(&self) -> Option<(String, String)> {
+        self.url.as_deref().and_then(git::parse_owner_repo)
+    }
+
+    /// `owner/repo`, as `gh --repo` takes it.
+    pub fn slug(&self) -> Option<String> {
+        self.owner_repo()
+            .map(|(owner, repo)| format!("{owner}/{repo}"))
+    }
+
+    /// The repository's web address (`https://github.com/owner/repo`).
+    pub fn web_url(&self) -> Option<String> {
+        self.url.as_deref().and_then(git::web_url)
+    }
+}
+
+/// The remote tracked by the current branch, or else by the release branch.
+fn tracked_remote(config: &Config) -> Option<String> {
+    let release_branch = config
+        .project
+        .as_ref()
+        .and_then(|p| p.release_branch.clone())
+        .or_else(|| git::detect_release_branch().ok().flatten());
+    let current = git::current_branch().ok().flatten();
+    [current, release_branch]
+        .into_iter()
+        .flatten()
+        .find_map(|branch| git::branch_remote(&branch).ok().flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remote(url: &str) -> Remote {
+        Remote {
+            name: "upstream".into(),
+            url: Some(url.into()),
+        }
+    }
+
+    #[test]
+    fn repository_from_url() {
+        let upstream = remote("git@github.com:claylo/scrat.git");
+        assert_eq!(upstream.slug().as_deref(), Some("claylo/scrat"));
+        assert_eq!(
+            upstream.web_url().as_deref(),
+            Some("https://github.com/claylo/scrat")
+        );
+    }
+
+    #[test]
+    fn missing_remote_has_no_repository() {
+        let missing = Remote {
+            name: "upstream".into(),
+            url: None,
+        };
+        assert_eq!(missing.owner_repo(), None);
+        assert_eq!(missing.slug(), None);
+        assert_eq!(missing.web_url(), None);
+    }
+
+    #[test]
+    fn configured_remote_wins() {
+        let config = Config {
+            project: Some(crate::config::ProjectConfig {
+                remote: Some("upstream".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(Remote::resolve(&config).name, "upstream");
+    }
+}
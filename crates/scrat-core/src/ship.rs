@@ -16,7 +16,7 @@
 
 use std::process::{Command, Stdio};
 
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use semver::Version;
 use serde::Serialize;
 use thiserror::Error;
@@ -25,13 +25,18 @@ use tracing::{debug, info, instrument, warn};
 use crate::bump::{self, InteractiveBump, ReadyBump};
 use crate::config::Config;
 use crate::deps;
-use crate::ecosystem::ProjectDetection;
+use crate::detect::{self, EcosystemMatch};
+use crate::ecosystem::{Ecosystem, ProjectDetection};
 use crate::git;
 use crate::hooks::{self, HookContext};
 use crate::notes;
+use crate::package::PackageScope;
 use crate::pipeline::{PipelineContext, PipelineContextInit};
 use crate::preflight;
+use crate::publish;
+use crate::remote::Remote;
 use crate::stats;
+use crate::version::BumpLevel;
 
 // ──────────────────────────────────────────────
 // Errors
@@ -65,9 +70,59 @@ pub enum ShipError {
     #[error(transparent)]
     Hook(#[from] crate::hooks::HookError),
 
+    /// Registry publish error.
+    #[error(transparent)]
+    Publish(#[from] crate::publish::PublishError),
+
     /// Version error.
     #[error(transparent)]
     Version(#[from] crate::version::VersionError),
+
+    /// The version is not newer than the previous release.
+    #[error(
+        "{version} is not newer than the previous version {previous} — pass --allow-downgrade to release it anyway"
+    )]
+    VersionNotNewer {
+        /// The version being released.
+        version: String,
+        /// The previous release.
+        previous: String,
+    },
+
+    /// A tag for the version already exists.
+    #[error("tag {tag} already exists {location}")]
+    TagExists {
+        /// The tag name.
+        tag: String,
+        /// Where the tag was found.
+        location: TagLocation,
+    },
+
+    /// A GitHub release for the version's tag already exists.
+    #[error("a GitHub release for {tag} already exists")]
+    ReleaseExists {
+        /// The tag name.
+        tag: String,
+    },
+}
+
+/// Where a conflicting tag was found.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagLocation {
+    /// In the local repository.
+    Local,
+    /// On the named remote.
+    Remote(String),
+}
+
+impl std::fmt::Display for TagLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Local => write!(f, "locally"),
+            Self::Remote(remote) => write!(f, "on {remote}"),
+        }
+    }
 }
 
 /// Result alias for ship operations.
@@ -82,6 +137,13 @@ pub type ShipResult<T> = Result<T, ShipError>;
 pub struct ShipOptions {
     /// Set the version explicitly (e.g., `"1.2.3"`).
     pub explicit_version: Option<String>,
+    /// Ship a pre-release on this channel (e.g., `"rc"`).
+    pub pre: Option<String>,
+    /// Force this bump level instead of the computed one (`--bump`).
+    pub bump: Option<BumpLevel>,
+    /// Allow a version lower than or equal to the previous one
+    /// (`--allow-downgrade`).
+    pub allow_downgrade: bool,
     /// Skip changelog generation during the bump phase.
     pub no_changelog: bool,
     /// Skip the publish phase entirely.
@@ -106,6 +168,12 @@ pub struct ShipOptions {
     pub no_git: bool,
     /// Override draft mode from CLI (`Some(true)` = `--draft`, `Some(false)` = `--no-draft`).
     pub draft_override: Option<bool>,
+    /// Release a single monorepo package instead of the whole repository.
+    pub package: Option<PackageScope>,
+    /// Skip the preflight checks, already passed by an earlier package of
+    /// the same run (whose release commit leaves the branch ahead of its
+    /// remote under `--no-push`).
+    pub no_preflight: bool,
 }
 
 // ──────────────────────────────────────────────
@@ -196,6 +264,8 @@ pub struct ShipOutcome {
     pub version: Version,
     /// The previous version.
     pub previous_version: Version,
+    /// The bump level forced with `--bump` (`None` = computed).
+    pub forced_level: Option<BumpLevel>,
     /// The git tag that was created.
     pub tag: String,
     /// Results of each phase.
@@ -249,8 +319,13 @@ pub struct InteractiveShip {
 }
 
 /// Ecosystem auto-detection failed — the CLI must prompt the user.
+///
+/// Either no marker file was found or markers for several ecosystems were;
+/// `candidates` lists the latter so the user can pick one.
 #[derive(Debug)]
 pub struct NeedsEcosystemSelection {
+    /// Every ecosystem found, with its evidence (empty if none was).
+    pub candidates: Vec<EcosystemMatch>,
     /// Ship workflow options (preserved for re-planning after selection).
     pub options: ShipOptions,
     /// Loaded configuration (preserved for re-planning after selection).
@@ -274,26 +349,45 @@ pub fn plan_ship(
     options: ShipOptions,
 ) -> ShipResult<ShipPlan> {
     // Phase 1: Preflight
-    let report = preflight::run_preflight(project_root, config);
+    if !options.no_preflight {
+        let report = preflight::run_preflight(project_root, config);
+        if !report.all_passed {
+            let failures: Vec<&str> = report
+                .checks
+                .iter()
+                .filter(|c| !c.passed)
+                .map(|c| c.message.as_str())
+                .collect();
+            return Err(ShipError::PreflightFailed(failures.join("; ")));
+        }
+        for check in report.checks.iter().filter(|c| c.warning) {
+            warn!(check = %check.name, "{}", check.message);
+        }
+    }
 
-    if !report.all_passed {
-        let failures: Vec<&str> = report
-            .checks
-            .iter()
-            .filter(|c| !c.passed)
-            .map(|c| c.message.as_str())
-            .collect();
-        return Err(ShipError::PreflightFailed(failures.join("; ")));
+    // Catch publish settings that can't work before anything is changed
+    if !options.no_publish {
+        publish::PublishSettings::from_config(config.publish.as_ref())?;
     }
 
     // Phase 2: Version resolution (delegates to bump::plan_bump)
-    let bump_plan = match bump::plan_bump(project_root, config, options.explicit_version.as_deref())
-    {
+    let bump_options = bump::BumpOptions {
+        explicit_version: options.explicit_version.clone(),
+        package: options.package.clone(),
+        pre: options.pre.clone(),
+        bump: options.bump,
+    };
+    let bump_plan = match bump::plan_bump(project_root, config, &bump_options) {
         Ok(plan) => plan,
         Err(bump::BumpError::Detection(_)) => {
-            // Ecosystem not detected — signal the CLI to prompt for selection
+            // Ecosystem not detected (or ambiguous) — signal the CLI to prompt
             debug!("ecosystem detection failed, requesting user selection");
+            let dir = options
+                .package
+                .as_ref()
+                .map_or_else(|| project_root.to_owned(), |p| p.root(project_root));
             return Ok(ShipPlan::NeedsEcosystemSelection(NeedsEcosystemSelection {
+                candidates: detect::detect_ecosystems(&dir),
                 options,
                 config: config.clone(),
                 project_root: project_root.to_owned(),
@@ -304,6 +398,8 @@ pub fn plan_ship(
 
     match bump_plan {
         bump::BumpPlan::Ready(ready_bump) => {
+            check_release_version(project_root, &ready_bump)?;
+            check_version_conflicts(config, &ready_bump, &options, &LiveLookup { project_root })?;
             let detection = ready_bump.detection.clone();
             Ok(ShipPlan::Ready(ReadyShip {
                 bump: ready_bump,
@@ -322,10 +418,109 @@ pub fn plan_ship(
     }
 }
 
+/// Fail preflight if the version about to be released breaks an
+/// ecosystem constraint (e.g. a Go v2 release without a `/v2` module path)
+/// or leaves the maintenance branch's line.
+fn check_release_version(project_root: &Utf8Path, bump: &bump::ReadyBump) -> ShipResult<()> {
+    bump.check_line()?;
+    match preflight::check_release_version(
+        &bump.work_dir(project_root),
+        &bump.detection,
+        &bump.next,
+    ) {
+        Some(check) if !check.passed => Err(ShipError::PreflightFailed(check.message)),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a version that goes backwards or collides with an existing
+/// release: one not newer than the previous version (unless
+/// `allow_downgrade`), or whose tag or GitHub release already exists.
+///
+/// Tags are only checked when the git phase creates one; an unreachable
+/// remote is skipped with a warning. The GitHub release is only checked
+/// alongside a new tag, so re-running with `--no-git` still updates an
+/// existing release.
+fn check_version_conflicts(
+    config: &Config,
+    bump: &ReadyBump,
+    options: &ShipOptions,
+    lookup: &impl ReleaseLookup,
+) -> ShipResult<()> {
+    if !options.allow_downgrade && bump.next <= bump.previous {
+        return Err(ShipError::VersionNotNewer {
+            version: bump.version(),
+            previous: bump.previous_version(),
+        });
+    }
+    if options.no_git || options.no_tag {
+        return Ok(());
+    }
+
+    let tag = bump.tag();
+    if lookup.local_tag(&tag)? {
+        return Err(ShipError::TagExists {
+            tag,
+            location: TagLocation::Local,
+        });
+    }
+    let remote = Remote::resolve(config);
+    match lookup.remote_tag(&remote.name, &tag) {
+        Ok(true) => {
+            return Err(ShipError::TagExists {
+                tag,
+                location: TagLocation::Remote(remote.name),
+            });
+        }
+        Ok(false) => {}
+        Err(e) => warn!(remote = %remote.name, error = %e, "could not check remote tags"),
+    }
+
+    let github_release = config
+        .release
+        .as_ref()
+        .and_then(|r| r.github_release)
+        .unwrap_or(true);
+    if github_release && !options.no_release && lookup.release(&tag, remote.slug().as_deref()) {
+        return Err(ShipError::ReleaseExists { tag });
+    }
+    Ok(())
+}
+
+/// Where [`check_version_conflicts`] looks for an existing tag or release.
+trait ReleaseLookup {
+    /// Whether the tag exists in the local repository.
+    fn local_tag(&self, tag: &str) -> git::GitResult<bool>;
+    /// Whether the tag exists on `remote`.
+    fn remote_tag(&self, remote: &str, tag: &str) -> git::GitResult<bool>;
+    /// Whether a GitHub release exists for the tag.
+    fn release(&self, tag: &str, repo: Option<&str>) -> bool;
+}
+
+/// The repository, its remote, and GitHub (through `gh`).
+struct LiveLookup<'a> {
+    project_root: &'a Utf8Path,
+}
+
+impl ReleaseLookup for LiveLookup<'_> {
+    fn local_tag(&self, tag: &str) -> git::GitResult<bool> {
+        git::tag_exists(tag)
+    }
+
+    fn remote_tag(&self, remote: &str, tag: &str) -> git::GitResult<bool> {
+        git::remote_tag_exists(remote, tag)
+    }
+
+    fn release(&self, tag: &str, repo: Option<&str>) -> bool {
+        release_exists(tag, repo, self.project_root)
+    }
+}
+
 /// Resolve an ecosystem selection by re-planning with the chosen ecosystem.
 ///
 /// Called after the CLI prompts the user to select an ecosystem (e.g., Generic).
-/// Injects the chosen ecosystem into the config and re-runs [`plan_ship`].
+/// Injects the chosen ecosystem into the config (or the package being
+/// released) and re-runs [`plan_ship`].
 pub fn resolve_ecosystem_selection(
     selection: NeedsEcosystemSelection,
     ecosystem: crate::ecosystem::Ecosystem,
@@ -334,23 +529,42 @@ pub fn resolve_ecosystem_selection(
 
     // Inject the user's ecosystem choice into config
     let mut config = selection.config;
-    let project = config.project.get_or_insert_with(ProjectConfig::default);
-    project.project_type = Some(ecosystem);
+    let mut options = selection.options;
+    if let Some(ref mut package) = options.package {
+        package.ecosystem = Some(ecosystem);
+    } else {
+        let project = config.project.get_or_insert_with(ProjectConfig::default);
+        project.project_type = Some(ecosystem);
+    }
 
     // Re-plan with the overridden config
-    plan_ship(&selection.project_root, &config, selection.options)
+    plan_ship(&selection.project_root, &config, options)
 }
 
 /// Resolve an interactive ship plan with the user's chosen version.
-pub fn resolve_ship_interaction(plan: InteractiveShip, chosen_version: Version) -> ReadyShip {
+///
+/// The chosen version goes through the same checks as one resolved in
+/// [`plan_ship`].
+pub fn resolve_ship_interaction(
+    project_root: &Utf8Path,
+    plan: InteractiveShip,
+    chosen_version: Version,
+) -> ShipResult<ReadyShip> {
     let ready_bump = bump::resolve_interactive(plan.bump, chosen_version);
+    check_release_version(project_root, &ready_bump)?;
+    check_version_conflicts(
+        &plan.config,
+        &ready_bump,
+        &plan.options,
+        &LiveLookup { project_root },
+    )?;
     let detection = ready_bump.detection.clone();
-    ReadyShip {
+    Ok(ReadyShip {
         bump: ready_bump,
         options: plan.options,
         config: plan.config,
         detection,
-    }
+    })
 }
 
 // ──────────────────────────────────────────────
@@ -377,29 +591,36 @@ impl ReadyShip {
 
         let version = &self.bump.next;
         let previous = &self.bump.previous;
-        let tag = format!("v{version}");
+        // How the versions read in messages (a CalVer format may zero-pad)
+        let version_text = self.bump.version();
+        let previous_text = self.bump.previous_version();
+        let tag = self.bump.tag();
+        // Decided before anything is pushed, so a failed lookup can't
+        // stop a release halfway through
+        let latest = is_latest_release(&self.bump);
+        let package = self.options.package.as_ref();
+        let package_dir = package.map(|p| p.path.as_str());
+        // Tests and publishing run in the package directory for monorepo packages
+        let work_dir = self.bump.work_dir(project_root);
 
         // Build the pipeline context — accumulates structured data across phases
-        let (owner, repo, repo_url) = {
-            let remote = git::remote_url("origin").ok().flatten();
-            let (o, r) = remote
-                .as_deref()
-                .and_then(git::parse_owner_repo)
-                .unwrap_or_else(|| ("unknown".into(), "unknown".into()));
-            (o, r, remote)
-        };
+        let remote = Remote::resolve(&self.config);
+        let repo_slug = remote.slug();
+        let (owner, repo) = remote
+            .owner_repo()
+            .unwrap_or_else(|| ("unknown".into(), "unknown".into()));
 
         let mut ctx = PipelineContext::new(PipelineContextInit {
-            version: version.to_string(),
-            previous_version: previous.to_string(),
+            version: self.bump.version(),
+            previous_version: self.bump.previous_version(),
             tag: tag.clone(),
-            previous_tag: format!("v{previous}"),
+            previous_tag: self.bump.previous_tag(),
             owner,
             repo,
-            repo_url,
+            repo_url: remote.url.clone(),
             branch: git::current_branch().ok().flatten(),
             ecosystem: self.detection.ecosystem.to_string(),
-            changelog_path: project_root.join("CHANGELOG.md").to_string(),
+            changelog_path: project_root.join(self.bump.changelog_path()).to_string(),
             dry_run: is_dry,
         });
 
@@ -410,12 +631,13 @@ impl ReadyShip {
 
         // Deps diff (silent data-gathering, populates context)
         if !self.options.no_deps {
-            ctx.dependencies = deps::compute_deps(self.detection.ecosystem, &ctx.previous_tag);
+            ctx.dependencies =
+                deps::compute_deps(self.detection.ecosystem, &ctx.previous_tag, package_dir);
         }
 
         // Stats collection (silent data-gathering, populates context)
         if !self.options.no_stats {
-            ctx.stats = stats::compute_stats(&ctx.previous_tag);
+            ctx.stats = stats::compute_stats(&ctx.previous_tag, package_dir);
         }
 
         // Derive hook interpolation context
@@ -438,8 +660,13 @@ impl ReadyShip {
         on_event(ShipEvent::PhaseStarted(ShipPhase::Version));
         let outcome = PhaseOutcome::Success {
             message: format!(
-                "{previous} → {version} ({strategy})",
-                strategy = self.bump.strategy
+                "{previous_text} → {version_text} ({strategy}{forced})",
+                strategy = self.bump.strategy,
+                forced = self
+                    .bump
+                    .forced
+                    .map(|level| format!(", forced {level}"))
+                    .unwrap_or_default()
             ),
         };
         on_event(ShipEvent::PhaseCompleted(
@@ -476,17 +703,16 @@ impl ReadyShip {
                 reason: "--no-test flag".into(),
             }
         } else if is_dry {
-            let test_cmd = self
-                .config
-                .commands
-                .as_ref()
-                .and_then(|c| c.test.as_deref())
-                .unwrap_or(&self.detection.tools.test_cmd);
-            PhaseOutcome::Success {
-                message: format!("Would run: {test_cmd}"),
-            }
+            resolve_test_cmd(&self.config, &self.detection).map_or_else(
+                || PhaseOutcome::Skipped {
+                    reason: "no test command configured or detected".into(),
+                },
+                |test_cmd| PhaseOutcome::Success {
+                    message: format!("Would run: {test_cmd}"),
+                },
+            )
         } else {
-            run_test_phase(project_root, &self.config, &self.detection)?
+            run_test_phase(&work_dir, &self.config, &self.detection)?
         };
         on_event(ShipEvent::PhaseCompleted(
             ShipPhase::Test,
@@ -518,7 +744,7 @@ impl ReadyShip {
         on_event(ShipEvent::PhaseStarted(ShipPhase::Bump));
         let bump_outcome = if is_dry {
             PhaseOutcome::Success {
-                message: format!("Would bump {previous} → {version}"),
+                message: format!("Would bump {previous_text} → {version_text}"),
             }
         } else {
             let result = self
@@ -528,7 +754,7 @@ impl ReadyShip {
             ctx.record_bump(result.changelog_updated, result.modified_files);
             PhaseOutcome::Success {
                 message: format!(
-                    "Bumped to {version}{changelog} (modified: {files})",
+                    "Bumped to {version_text}{changelog} (modified: {files})",
                     changelog = if result.changelog_updated {
                         " + changelog"
                     } else {
@@ -569,19 +795,44 @@ impl ReadyShip {
             PhaseOutcome::Skipped {
                 reason: "--no-publish flag".into(),
             }
-        } else if is_dry {
-            let publish_cmd = self
-                .config
-                .commands
-                .as_ref()
-                .and_then(|c| c.publish.as_deref())
-                .or(self.detection.tools.publish_cmd.as_deref())
-                .unwrap_or("(no publish command)");
-            PhaseOutcome::Success {
-                message: format!("Would run: {publish_cmd}"),
-            }
         } else {
-            run_publish_phase(project_root, &self.config, &self.detection)?
+            let primary = if is_dry {
+                if package.is_none() && uses_workspace_publish(&self.config, &self.detection) {
+                    let order: Vec<String> = publish::plan_cargo_publish(project_root)?
+                        .into_iter()
+                        .map(|c| c.name)
+                        .collect();
+                    PhaseOutcome::Success {
+                        message: format!("Would publish in order: {}", order.join(" → ")),
+                    }
+                } else {
+                    let publish_cmd = self
+                        .config
+                        .commands
+                        .as_ref()
+                        .and_then(|c| c.publish.as_deref())
+                        .or(self.detection.tools.publish_cmd.as_deref())
+                        .unwrap_or("(no publish command)");
+                    PhaseOutcome::Success {
+                        message: format!("Would run: {publish_cmd}"),
+                    }
+                }
+            } else {
+                run_publish_phase(
+                    &work_dir,
+                    &self.config,
+                    &self.detection,
+                    &self.bump.next,
+                    package.is_some(),
+                )?
+            };
+            if self.bump.linked.is_empty() {
+                primary
+            } else {
+                let linked =
+                    run_linked_publish(project_root, &self.config, &self.bump.linked, is_dry)?;
+                combine_outcomes(primary, linked)
+            }
         };
         on_event(ShipEvent::PhaseCompleted(
             ShipPhase::Publish,
@@ -624,18 +875,25 @@ impl ReadyShip {
                 format!(", tag {tag}")
             };
             let push_msg = if self.options.no_push {
-                " (no push)"
+                " (no push)".to_string()
             } else {
-                " + push"
+                format!(" + push to {}", remote.name)
             };
+            let development_msg = self
+                .bump
+                .development_version()
+                .map(|v| format!(", then {v}"))
+                .unwrap_or_default();
             PhaseOutcome::Success {
-                message: format!("Would commit{tag_msg}{push_msg}"),
+                message: format!("Would commit{tag_msg}{development_msg}{push_msg}"),
             }
         } else {
             let git_result = run_git_phase(
                 project_root,
+                &self.bump,
                 &tag,
-                version,
+                &release_name(package, &version_text),
+                &remote.name,
                 self.options.no_push,
                 self.options.no_tag,
             )?;
@@ -646,12 +904,20 @@ impl ReadyShip {
                 format!(", tagged {tag}")
             };
             let push_part = if git_result.pushed {
-                ", pushed"
+                format!(", pushed to {}", remote.name)
             } else {
-                " (push skipped)"
+                " (push skipped)".to_string()
             };
+            let development_part = git_result
+                .development
+                .as_ref()
+                .map(|v| format!(", moved to {v}"))
+                .unwrap_or_default();
             PhaseOutcome::Success {
-                message: format!("Committed {}{tag_part}{push_part}", git_result.hash),
+                message: format!(
+                    "Committed {}{tag_part}{development_part}{push_part}",
+                    git_result.hash
+                ),
             }
         };
         on_event(ShipEvent::PhaseCompleted(
@@ -697,7 +963,13 @@ impl ReadyShip {
                     .release
                     .as_ref()
                     .and_then(|r| r.notes_template.as_deref());
-                match notes::render_notes(project_root, &ctx, custom_template) {
+                match notes::render_notes(
+                    project_root,
+                    &ctx,
+                    custom_template,
+                    &self.bump.tags,
+                    package,
+                ) {
                     Ok(rendered) => {
                         debug!(len = rendered.len(), "release notes rendered");
                         ctx.release_notes = Some(rendered.clone());
@@ -739,6 +1011,8 @@ impl ReadyShip {
             .map(|t| hooks::interpolate_command(t, &hook_ctx));
         let discussion_category = release_cfg.and_then(|r| r.discussion_category.as_deref());
         let assets = release_cfg.and_then(|r| r.assets.as_deref()).unwrap_or(&[]);
+        let prerelease = !version.pre.is_empty();
+        let latest = !prerelease && latest;
 
         on_event(ShipEvent::PhaseStarted(ShipPhase::Release));
         let release_outcome = if self.options.no_release {
@@ -750,7 +1024,12 @@ impl ReadyShip {
                 reason: "github_release = false in config".into(),
             }
         } else if is_dry {
-            let draft_label = if draft { " as draft" } else { "" };
+            let draft_label = match (draft, prerelease) {
+                (true, true) => " as draft pre-release",
+                (true, false) => " as draft",
+                (false, true) => " as pre-release",
+                (false, false) => "",
+            };
             let title_label = title
                 .as_ref()
                 .map_or(String::new(), |t| format!(" titled \"{t}\""));
@@ -780,9 +1059,12 @@ impl ReadyShip {
                 tag: &tag,
                 title,
                 draft,
+                prerelease,
+                latest,
                 notes_file: notes_path,
                 assets,
                 discussion_category,
+                repo: repo_slug.as_deref(),
                 project_root,
             };
             let release_result = run_release_phase(&release_opts)?;
@@ -792,7 +1074,12 @@ impl ReadyShip {
             } else {
                 "Created"
             };
-            let draft_label = if draft { " (draft)" } else { "" };
+            let draft_label = match (draft, prerelease) {
+                (true, true) => " (draft pre-release)",
+                (true, false) => " (draft)",
+                (false, true) => " (pre-release)",
+                (false, false) => "",
+            };
             let msg = release_result.url.as_ref().map_or_else(
                 || format!("{action} GitHub release {tag}{draft_label}"),
                 |url| format!("{action} GitHub release{draft_label}: {url}"),
@@ -829,6 +1116,7 @@ impl ReadyShip {
         let outcome = ShipOutcome {
             version: version.clone(),
             previous_version: previous.clone(),
+            forced_level: self.bump.forced,
             tag,
             phases,
             hooks_run,
@@ -914,11 +1202,11 @@ fn run_test_phase(
     config: &Config,
     detection: &ProjectDetection,
 ) -> ShipResult<PhaseOutcome> {
-    let test_cmd = config
-        .commands
-        .as_ref()
-        .and_then(|c| c.test.as_deref())
-        .unwrap_or(&detection.tools.test_cmd);
+    let Some(test_cmd) = resolve_test_cmd(config, detection) else {
+        return Ok(PhaseOutcome::Skipped {
+            reason: "no test command configured or detected".into(),
+        });
+    };
 
     debug!(%test_cmd, "running tests");
 
@@ -944,12 +1232,31 @@ fn run_test_phase(
     })
 }
 
+/// Resolve the test command (config override > detected), `None` if empty.
+fn resolve_test_cmd<'a>(config: &'a Config, detection: &'a ProjectDetection) -> Option<&'a str> {
+    let test_cmd = config
+        .commands
+        .as_ref()
+        .and_then(|c| c.test.as_deref())
+        .unwrap_or(&detection.tools.test_cmd);
+    (!test_cmd.trim().is_empty()).then_some(test_cmd)
+}
+
 /// Run the publish phase by executing the configured or detected publish command.
+///
+/// A monorepo package (`single_package`) is published on its own from its
+/// directory rather than as a whole Cargo workspace.
 fn run_publish_phase(
     project_root: &Utf8Path,
     config: &Config,
     detection: &ProjectDetection,
+    version: &Version,
+    single_package: bool,
 ) -> ShipResult<PhaseOutcome> {
+    if !single_package && uses_workspace_publish(config, detection) {
+        return run_cargo_workspace_publish(project_root, config);
+    }
+
     let publish_cmd = config
         .commands
         .as_ref()
@@ -964,14 +1271,21 @@ fn run_publish_phase(
 
     debug!(%publish_cmd, "publishing");
 
-    let output = Command::new("sh")
-        .args(["-c", publish_cmd])
-        .current_dir(project_root.as_std_path())
-        .output()
-        .map_err(|e| ShipError::PhaseFailed {
-            phase: ShipPhase::Publish,
-            message: format!("failed to execute publish command: {e}"),
-        })?;
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", publish_cmd])
+        .current_dir(project_root.as_std_path());
+    if detection.ecosystem == Ecosystem::Python {
+        // The release tag is created after publishing, so versions derived
+        // from git (hatch-vcs, setuptools-scm, poetry-dynamic-versioning)
+        // are told which tag they are about to get.
+        for var in PYTHON_PRETEND_VERSION_VARS {
+            cmd.env(var, version.to_string());
+        }
+    }
+    let output = cmd.output().map_err(|e| ShipError::PhaseFailed {
+        phase: ShipPhase::Publish,
+        message: format!("failed to execute publish command: {e}"),
+    })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -986,6 +1300,110 @@ fn run_publish_phase(
     })
 }
 
+/// Environment variables that override a version derived from git tags.
+const PYTHON_PRETEND_VERSION_VARS: &[&str] = &[
+    "SETUPTOOLS_SCM_PRETEND_VERSION",
+    "POETRY_DYNAMIC_VERSIONING_BYPASS",
+];
+
+/// Whether the publish phase should publish a Cargo workspace crate by crate.
+///
+/// True for Rust projects with the detected `cargo publish` default; an
+/// explicit `commands.publish` override always runs as-is.
+fn uses_workspace_publish(config: &Config, detection: &ProjectDetection) -> bool {
+    detection.ecosystem == Ecosystem::Rust
+        && detection.tools.publish_cmd.is_some()
+        && config
+            .commands
+            .as_ref()
+            .and_then(|c| c.publish.as_ref())
+            .is_none()
+}
+
+/// Publish every publishable crate of the workspace in dependency order.
+fn run_cargo_workspace_publish(
+    project_root: &Utf8Path,
+    config: &Config,
+) -> ShipResult<PhaseOutcome> {
+    let crates = publish::plan_cargo_publish(project_root)?;
+    if crates.is_empty() {
+        return Ok(PhaseOutcome::Skipped {
+            reason: "no publishable crates (all have `publish = false`)".into(),
+        });
+    }
+
+    let settings = publish::PublishSettings::from_config(config.publish.as_ref())?;
+    let report = publish::publish_cargo_crates(project_root, &crates, &settings)?;
+
+    let mut message = if report.published.is_empty() {
+        "Nothing to publish".to_string()
+    } else {
+        format!("Published {}", report.published.join(", "))
+    };
+    if !report.already_published.is_empty() {
+        message.push_str(&format!(
+            " (already in index: {})",
+            report.already_published.join(", ")
+        ));
+    }
+    Ok(PhaseOutcome::Success { message })
+}
+
+/// Publish the linked npm packages (`version.linked`) in dependency order.
+fn run_linked_publish(
+    project_root: &Utf8Path,
+    config: &Config,
+    patterns: &[Utf8PathBuf],
+    is_dry: bool,
+) -> ShipResult<PhaseOutcome> {
+    let packages = publish::plan_linked_publish(project_root, patterns)?;
+    if packages.is_empty() {
+        return Ok(PhaseOutcome::Skipped {
+            reason: "no publishable linked packages (all are private)".into(),
+        });
+    }
+
+    let command = config
+        .publish
+        .as_ref()
+        .and_then(|p| p.linked_command.as_deref())
+        .unwrap_or(publish::DEFAULT_LINKED_COMMAND);
+    if is_dry {
+        let order: Vec<&str> = packages.iter().map(|p| p.name.as_str()).collect();
+        return Ok(PhaseOutcome::Success {
+            message: format!("Would {command} in order: {}", order.join(" → ")),
+        });
+    }
+
+    let report = publish::publish_linked_packages(project_root, &packages, command)?;
+    let mut message = if report.published.is_empty() {
+        "Nothing to publish".to_string()
+    } else {
+        format!("Published {}", report.published.join(", "))
+    };
+    if !report.already_published.is_empty() {
+        message.push_str(&format!(
+            " (already in registry: {})",
+            report.already_published.join(", ")
+        ));
+    }
+    Ok(PhaseOutcome::Success { message })
+}
+
+/// Merge the outcomes of the primary and linked publishes into one.
+fn combine_outcomes(primary: PhaseOutcome, linked: PhaseOutcome) -> PhaseOutcome {
+    match (primary, linked) {
+        (PhaseOutcome::Success { message: first }, PhaseOutcome::Success { message: second }) => {
+            PhaseOutcome::Success {
+                message: format!("{first}; {second}"),
+            }
+        }
+        (PhaseOutcome::Skipped { .. }, success @ PhaseOutcome::Success { .. })
+        | (success @ PhaseOutcome::Success { .. }, PhaseOutcome::Skipped { .. }) => success,
+        (skipped @ PhaseOutcome::Skipped { .. }, PhaseOutcome::Skipped { .. }) => skipped,
+    }
+}
+
 /// Structured result from the git phase.
 struct GitPhaseResult {
     /// The commit hash.
@@ -994,40 +1412,79 @@ struct GitPhaseResult {
     branch: Option<String>,
     /// Whether the push actually happened.
     pushed: bool,
+    /// The `-SNAPSHOT` version committed after the release, if any.
+    development: Option<Version>,
 }
 
-/// Run the git phase: commit, tag, and optionally push.
+/// Whether the GitHub release should be marked "latest".
+///
+/// Only a maintenance-line release can be older than the newest release;
+/// if the tags can't be listed, it is marked latest with a warning.
+fn is_latest_release(bump: &ReadyBump) -> bool {
+    if bump.tags.line().is_none() {
+        return true;
+    }
+    match bump.tags.newest_anywhere() {
+        Ok(newest) => newest.is_none_or(|(_, newest)| bump.next >= newest),
+        Err(e) => {
+            warn!(error = %e, "could not find the newest release, marking this one latest");
+            true
+        }
+    }
+}
+
+/// What is being released, for commit and tag messages: the version, or
+/// `"<package> <version>"` for a monorepo package.
+fn release_name(package: Option<&PackageScope>, version: &str) -> String {
+    package.map_or_else(|| version.to_string(), |p| format!("{} {version}", p.name))
+}
+
+/// Run the git phase: commit, tag, and optionally push to `remote`.
 fn run_git_phase(
-    _project_root: &Utf8Path,
+    project_root: &Utf8Path,
+    bump: &ReadyBump,
     tag: &str,
-    version: &Version,
+    release_name: &str,
+    remote: &str,
     no_push: bool,
     no_tag: bool,
 ) -> ShipResult<GitPhaseResult> {
     // Stage and commit all modified files
-    let commit_msg = format!("chore: release {version}");
+    let commit_msg = format!("chore: release {release_name}");
     let hash = git::commit(&["."], &commit_msg)?;
 
     // Create annotated tag (unless skipped)
     if !no_tag {
-        let tag_msg = format!("Release {version}");
+        let tag_msg = format!("Release {release_name}");
         git::create_tag(tag, &tag_msg)?;
     }
 
+    // Projects on -SNAPSHOT versions move on to the next one after the tag
+    let development = bump.development_version();
+    if let Some(ref version) = development {
+        bump.set_development_version(project_root)?;
+        git::commit(
+            &["."],
+            &format!("chore: prepare next development iteration ({version})"),
+        )?;
+    }
+
     // Push if requested (only push tags if we created one)
     if !no_push {
         let branch = git::current_branch()?.unwrap_or_else(|| "HEAD".into());
-        git::push("origin", &branch, !no_tag)?;
+        git::push(remote, &branch, !no_tag)?;
         Ok(GitPhaseResult {
             hash,
             branch: Some(branch),
             pushed: true,
+            development,
         })
     } else {
         Ok(GitPhaseResult {
             hash,
             branch: None,
             pushed: false,
+            development,
         })
     }
 }
@@ -1058,15 +1515,32 @@ struct ReleaseOptions<'a> {
     tag: &'a str,
     title: Option<String>,
     draft: bool,
+    /// Mark the release as a pre-release.
+    prerelease: bool,
+    /// Mark the release as "latest" (false for pre-releases and for
+    /// maintenance releases older than the newest release).
+    latest: bool,
     notes_file: Option<&'a std::path::Path>,
     assets: &'a [String],
     discussion_category: Option<&'a str>,
+    /// `owner/repo` of the release remote, passed to `gh --repo`.
+    repo: Option<&'a str>,
     project_root: &'a Utf8Path,
 }
 
+/// `gh release <subcommand> <tag>`, aimed at the release remote's repository.
+fn release_args(subcommand: &str, tag: &str, repo: Option<&str>) -> Vec<String> {
+    let mut args = vec!["release".into(), subcommand.into(), tag.into()];
+    if let Some(repo) = repo {
+        args.push("--repo".into());
+        args.push(repo.into());
+    }
+    args
+}
+
 /// Build args for `gh release create`.
 fn build_create_args(opts: &ReleaseOptions<'_>) -> Vec<String> {
-    let mut args = vec!["release".into(), "create".into(), opts.tag.into()];
+    let mut args = release_args("create", opts.tag, opts.repo);
 
     if let Some(ref title) = opts.title {
         args.push("--title".into());
@@ -1077,6 +1551,14 @@ fn build_create_args(opts: &ReleaseOptions<'_>) -> Vec<String> {
         args.push("--draft".into());
     }
 
+    if opts.prerelease {
+        args.push("--prerelease".into());
+    }
+
+    if !opts.latest {
+        args.push("--latest=false".into());
+    }
+
     if let Some(path) = opts.notes_file {
         args.push("--notes-file".into());
         args.push(path.to_string_lossy().to_string());
@@ -1098,7 +1580,7 @@ fn build_create_args(opts: &ReleaseOptions<'_>) -> Vec<String> {
 
 /// Build args for `gh release edit`.
 fn build_edit_args(opts: &ReleaseOptions<'_>) -> Vec<String> {
-    let mut args = vec!["release".into(), "edit".into(), opts.tag.into()];
+    let mut args = release_args("edit", opts.tag, opts.repo);
 
     if let Some(ref title) = opts.title {
         args.push("--title".into());
@@ -1111,6 +1593,17 @@ fn build_edit_args(opts: &ReleaseOptions<'_>) -> Vec<String> {
         args.push("--draft=false".into());
     }
 
+    // An existing release may have been created as a pre-release
+    if opts.prerelease {
+        args.push("--prerelease".into());
+    } else {
+        args.push("--prerelease=false".into());
+    }
+
+    if !opts.latest {
+        args.push("--latest=false".into());
+    }
+
     if let Some(path) = opts.notes_file {
         args.push("--notes-file".into());
         args.push(path.to_string_lossy().to_string());
@@ -1120,9 +1613,9 @@ fn build_edit_args(opts: &ReleaseOptions<'_>) -> Vec<String> {
 }
 
 /// Check if a GitHub release already exists for the given tag.
-fn release_exists(tag: &str, project_root: &Utf8Path) -> bool {
+fn release_exists(tag: &str, repo: Option<&str>, project_root: &Utf8Path) -> bool {
     Command::new("gh")
-        .args(["release", "view", tag])
+        .args(release_args("view", tag, repo))
         .current_dir(project_root.as_std_path())
         .stdout(Stdio::null())
         .stderr(Stdio::null())
@@ -1131,7 +1624,12 @@ fn release_exists(tag: &str, project_root: &Utf8Path) -> bool {
 }
 
 /// Upload assets to an existing release, replacing any with the same name.
-fn upload_release_assets(tag: &str, assets: &[String], project_root: &Utf8Path) -> ShipResult<()> {
+fn upload_release_assets(
+    tag: &str,
+    assets: &[String],
+    repo: Option<&str>,
+    project_root: &Utf8Path,
+) -> ShipResult<()> {
     for asset in assets {
         // Try to delete existing asset (ignore failure — may not exist)
         let basename = std::path::Path::new(asset)
@@ -1140,7 +1638,8 @@ fn upload_release_assets(tag: &str, assets: &[String], project_root: &Utf8Path)
             .unwrap_or_else(|| asset.clone());
 
         let _ = Command::new("gh")
-            .args(["release", "delete-asset", tag, &basename, "--yes"])
+            .args(release_args("delete-asset", tag, repo))
+            .args([basename.as_str(), "--yes"])
             .current_dir(project_root.as_std_path())
             .stdout(Stdio::null())
             .stderr(Stdio::null())
@@ -1148,7 +1647,8 @@ fn upload_release_assets(tag: &str, assets: &[String], project_root: &Utf8Path)
 
         // Upload
         let output = Command::new("gh")
-            .args(["release", "upload", tag, asset])
+            .args(release_args("upload", tag, repo))
+            .arg(asset)
             .current_dir(project_root.as_std_path())
             .output()
             .map_err(|e| ShipError::PhaseFailed {
@@ -1173,7 +1673,7 @@ fn upload_release_assets(tag: &str, assets: &[String], project_root: &Utf8Path)
 /// - **Exists:** edits the release, then re-uploads any assets
 /// - **New:** creates the release with all options
 fn run_release_phase(opts: &ReleaseOptions<'_>) -> ShipResult<ReleasePhaseResult> {
-    let exists = release_exists(opts.tag, opts.project_root);
+    let exists = release_exists(opts.tag, opts.repo, opts.project_root);
 
     if exists {
         debug!(tag = opts.tag, "release exists, editing");
@@ -1198,7 +1698,7 @@ fn run_release_phase(opts: &ReleaseOptions<'_>) -> ShipResult<ReleasePhaseResult
 
         // Upload assets separately for edits
         if !opts.assets.is_empty() {
-            upload_release_assets(opts.tag, opts.assets, opts.project_root)?;
+            upload_release_assets(opts.tag, opts.assets, opts.repo, opts.project_root)?;
         }
 
         let raw_url = String::from_utf8_lossy(&output.stdout).trim().to_string();
@@ -1298,6 +1798,7 @@ mod tests {
         let outcome = ShipOutcome {
             version: Version::new(1, 2, 3),
             previous_version: Version::new(1, 1, 0),
+            forced_level: Some(BumpLevel::Minor),
             tag: "v1.2.3".into(),
             phases: vec![(
                 ShipPhase::Preflight,
@@ -1312,6 +1813,7 @@ mod tests {
         let json = serde_json::to_string_pretty(&outcome).unwrap();
         assert!(json.contains("\"tag\": \"v1.2.3\""));
         assert!(json.contains("\"hooks_run\": 2"));
+        assert!(json.contains("\"forced_level\": \"minor\""));
         assert!(json.contains("\"dry_run\": false"));
         assert!(json.contains("\"context\""));
         assert!(json.contains("\"ecosystem\": \"rust\""));
@@ -1333,6 +1835,21 @@ mod tests {
         assert!(!opts.no_changelog);
         assert!(opts.explicit_version.is_none());
         assert!(opts.draft_override.is_none());
+        assert!(opts.package.is_none());
+    }
+
+    #[test]
+    fn release_name_includes_package() {
+        let version = "0.2.0";
+        assert_eq!(release_name(None, version), "0.2.0");
+
+        let package = PackageScope {
+            name: "scrat-core".into(),
+            path: "crates/scrat-core".into(),
+            ecosystem: None,
+            tag_prefix: "scrat-core-v".into(),
+        };
+        assert_eq!(release_name(Some(&package), version), "scrat-core 0.2.0");
     }
 
     #[test]
@@ -1366,15 +1883,19 @@ mod tests {
             tag: "v1.2.3",
             title: Some("myrepo v1.2.3".into()),
             draft: true,
+            prerelease: false,
+            latest: true,
             notes_file: Some(notes.path()),
             assets: &["dist/app.tar.gz".into(), "dist/checksums.txt".into()],
             discussion_category: Some("releases"),
+            repo: Some("claylo/scrat"),
             project_root: Utf8Path::new("/tmp"),
         };
         let args = build_create_args(&opts);
         assert_eq!(args[0], "release");
         assert_eq!(args[1], "create");
         assert_eq!(args[2], "v1.2.3");
+        assert_eq!(args[3..5], ["--repo", "claylo/scrat"]);
         assert!(args.contains(&"--title".into()));
         assert!(args.contains(&"myrepo v1.2.3".into()));
         assert!(args.contains(&"--draft".into()));
@@ -1391,9 +1912,12 @@ mod tests {
             tag: "v0.1.0",
             title: None,
             draft: false,
+            prerelease: false,
+            latest: true,
             notes_file: None,
             assets: &[],
             discussion_category: None,
+            repo: None,
             project_root: Utf8Path::new("/tmp"),
         };
         let args = build_create_args(&opts);
@@ -1409,9 +1933,12 @@ mod tests {
             tag: "v1.0.0",
             title: Some("Release v1.0.0".into()),
             draft: true,
+            prerelease: false,
+            latest: true,
             notes_file: None,
             assets: &[],
             discussion_category: None,
+            repo: None,
             project_root: Utf8Path::new("/tmp"),
         };
         let args = build_edit_args(&opts);
@@ -1429,16 +1956,285 @@ mod tests {
             tag: "v1.0.0",
             title: None,
             draft: false,
+            prerelease: false,
+            latest: true,
             notes_file: None,
             assets: &[],
             discussion_category: None,
+            repo: None,
             project_root: Utf8Path::new("/tmp"),
         };
         let args = build_edit_args(&opts);
         assert!(args.contains(&"--draft=false".into()));
+        assert!(args.contains(&"--prerelease=false".into()));
         assert!(!args.contains(&"--title".into()));
     }
 
+    #[test]
+    fn prerelease_is_never_latest() {
+        let opts = ReleaseOptions {
+            tag: "v1.3.0-rc.1",
+            title: None,
+            draft: false,
+            prerelease: true,
+            latest: false,
+            notes_file: None,
+            assets: &[],
+            discussion_category: None,
+            repo: None,
+            project_root: Utf8Path::new("/tmp"),
+        };
+        for args in [build_create_args(&opts), build_edit_args(&opts)] {
+            assert!(args.contains(&"--prerelease".into()));
+            assert!(args.contains(&"--latest=false".into()));
+        }
+    }
+
+    fn ready_bump(previous: &str, next: &str) -> ReadyBump {
+        ReadyBump {
+            previous: Version::parse(previous).unwrap(),
+            next: Version::parse(next).unwrap(),
+            strategy: crate::ecosystem::VersionStrategy::Explicit(next.into()),
+            detection: ProjectDetection::generic(crate::ecosystem::VersionStrategy::Interactive),
+            package: None,
+            version_module: None,
+            version_files: Vec::new(),
+            linked: Vec::new(),
+            snapshots: false,
+            tags: crate::version::tag::TagTemplate::default(),
+            forced: None,
+        }
+    }
+
+    /// Canned answers for the conflict checks (`remote: None` = unreachable).
+    #[derive(Default)]
+    struct FakeLookup {
+        local: bool,
+        remote: Option<bool>,
+        release: bool,
+    }
+
+    impl ReleaseLookup for FakeLookup {
+        fn local_tag(&self, _tag: &str) -> git::GitResult<bool> {
+            Ok(self.local)
+        }
+
+        fn remote_tag(&self, _remote: &str, _tag: &str) -> git::GitResult<bool> {
+            self.remote.ok_or_else(|| git::GitError::Command {
+                command: "ls-remote".into(),
+                stderr: "could not read from remote repository".into(),
+            })
+        }
+
+        fn release(&self, _tag: &str, _repo: Option<&str>) -> bool {
+            self.release
+        }
+    }
+
+    /// Config whose release remote is `upstream`.
+    fn upstream_config() -> Config {
+        Config {
+            project: Some(crate::config::ProjectConfig {
+                remote: Some("upstream".into()),
+                ..Default::default()
+            }),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn rejects_versions_that_are_not_newer() {
+        let lookup = FakeLookup::default();
+        let config = Config::default();
+        // Tag checks are skipped without a git phase
+        let options = ShipOptions {
+            no_git: true,
+            ..ShipOptions::default()
+        };
+        for (previous, next) in [
+            ("2.3.0", "1.0.0"),
+            ("2.3.0", "2.3.0"),
+            ("1.3.0", "1.3.0-rc.1"),
+        ] {
+            let err =
+                check_version_conflicts(&config, &ready_bump(previous, next), &options, &lookup)
+                    .unwrap_err();
+            assert!(
+                matches!(err, ShipError::VersionNotNewer { .. }),
+                "{previous} → {next}: {err}"
+            );
+        }
+        assert!(
+            check_version_conflicts(
+                &config,
+                &ready_bump("1.3.0-rc.1", "1.3.0"),
+                &options,
+                &lookup
+            )
+            .is_ok()
+        );
+
+        let allowed = ShipOptions {
+            allow_downgrade: true,
+            ..options
+        };
+        assert!(
+            check_version_conflicts(&config, &ready_bump("2.3.0", "1.0.0"), &allowed, &lookup)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn duplicate_version_needs_the_override() {
+        let config = Config::default();
+        let bump = ready_bump("2.3.0", "2.3.0");
+        let options = ShipOptions::default();
+        let err =
+            check_version_conflicts(&config, &bump, &options, &FakeLookup::default()).unwrap_err();
+        assert!(matches!(err, ShipError::VersionNotNewer { .. }), "{err}");
+
+        // The override lets the version through, but not over its own tag
+        let allowed = ShipOptions {
+            allow_downgrade: true,
+            ..options
+        };
+        let unreleased = FakeLookup {
+            remote: Some(false),
+            ..FakeLookup::default()
+        };
+        assert!(check_version_conflicts(&config, &bump, &allowed, &unreleased).is_ok());
+        let tagged = FakeLookup {
+            local: true,
+            ..FakeLookup::default()
+        };
+        let err = check_version_conflicts(&config, &bump, &allowed, &tagged).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ShipError::TagExists {
+                    location: TagLocation::Local,
+                    ..
+                }
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn rejects_a_tag_on_the_release_remote() {
+        let lookup = FakeLookup {
+            remote: Some(true),
+            ..FakeLookup::default()
+        };
+        let err = check_version_conflicts(
+            &upstream_config(),
+            &ready_bump("1.2.0", "1.3.0"),
+            &ShipOptions::default(),
+            &lookup,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "tag v1.3.0 already exists on upstream");
+
+        // Without a tag to create there is nothing to collide with
+        let no_tag = ShipOptions {
+            no_tag: true,
+            ..ShipOptions::default()
+        };
+        assert!(
+            check_version_conflicts(
+                &upstream_config(),
+                &ready_bump("1.2.0", "1.3.0"),
+                &no_tag,
+                &lookup
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn unreachable_remote_is_not_a_conflict() {
+        let lookup = FakeLookup {
+            remote: None,
+            ..FakeLookup::default()
+        };
+        assert!(
+            check_version_conflicts(
+                &upstream_config(),
+                &ready_bump("1.2.0", "1.3.0"),
+                &ShipOptions::default(),
+                &lookup
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_an_existing_github_release() {
+        let lookup = FakeLookup {
+            remote: Some(false),
+            release: true,
+            ..FakeLookup::default()
+        };
+        let bump = ready_bump("1.2.0", "1.3.0");
+        let err =
+            check_version_conflicts(&upstream_config(), &bump, &ShipOptions::default(), &lookup)
+                .unwrap_err();
+        assert!(matches!(err, ShipError::ReleaseExists { ref tag } if tag == "v1.3.0"));
+
+        // Skipping the release, or turning GitHub releases off, skips the check
+        let no_release = ShipOptions {
+            no_release: true,
+            ..ShipOptions::default()
+        };
+        assert!(check_version_conflicts(&upstream_config(), &bump, &no_release, &lookup).is_ok());
+        let mut config = upstream_config();
+        config.release = Some(crate::config::ReleaseConfig {
+            github_release: Some(false),
+            ..Default::default()
+        });
+        assert!(check_version_conflicts(&config, &bump, &ShipOptions::default(), &lookup).is_ok());
+    }
+
+    #[test]
+    fn conflict_messages() {
+        let err = ShipError::TagExists {
+            tag: "v1.2.3".into(),
+            location: TagLocation::Remote("origin".into()),
+        };
+        assert_eq!(err.to_string(), "tag v1.2.3 already exists on origin");
+        let err = ShipError::VersionNotNewer {
+            version: "1.0.0".into(),
+            previous: "2.3.0".into(),
+        };
+        assert!(err.to_string().contains("--allow-downgrade"), "{err}");
+    }
+
+    #[test]
+    fn latest_is_only_questioned_on_a_maintenance_line() {
+        // Off a maintenance line the tags aren't consulted at all
+        assert!(is_latest_release(&ready_bump("2.3.0", "1.0.0")));
+    }
+
+    #[test]
+    fn older_maintenance_release_is_not_latest() {
+        let opts = ReleaseOptions {
+            tag: "v1.4.3",
+            title: None,
+            draft: false,
+            prerelease: false,
+            latest: false,
+            notes_file: None,
+            assets: &[],
+            discussion_category: None,
+            repo: None,
+            project_root: Utf8Path::new("/tmp"),
+        };
+        for args in [build_create_args(&opts), build_edit_args(&opts)] {
+            assert!(!args.contains(&"--prerelease".into()));
+            assert!(args.contains(&"--latest=false".into()));
+        }
+    }
+
     #[test]
     fn build_edit_args_with_notes_file() {
         let notes = tempfile::NamedTempFile::new().unwrap();
@@ -1446,9 +2242,12 @@ mod tests {
             tag: "v2.0.0",
             title: None,
             draft: true,
+            prerelease: false,
+            latest: true,
             notes_file: Some(notes.path()),
             assets: &[],
             discussion_category: None,
+            repo: None,
             project_root: Utf8Path::new("/tmp"),
         };
         let args = build_edit_args(&opts);
@@ -18,9 +18,12 @@ const CONTRIBUTOR_LIMIT: usize = 20;
 
 /// Compute release statistics between a ref and HEAD.
 ///
+/// When `path` is set (a monorepo package directory), only changes to files
+/// under it are counted.
+///
 /// Returns `None` if stats gathering fails (non-fatal — logs a warning).
-pub fn compute_stats(previous_tag: &str) -> Option<ReleaseStats> {
-    let stats = match git::stats_since(previous_tag) {
+pub fn compute_stats(previous_tag: &str, path: Option<&str>) -> Option<ReleaseStats> {
+    let stats = match git::stats_since(previous_tag, path) {
         Ok(s) => s,
         Err(e) => {
             warn!(%e, "failed to gather release stats, skipping");
@@ -28,7 +31,7 @@ pub fn compute_stats(previous_tag: &str) -> Option<ReleaseStats> {
         }
     };
 
-    let contributors = match git::contributors_since(previous_tag, CONTRIBUTOR_LIMIT) {
+    let contributors = match git::contributors_since(previous_tag, CONTRIBUTOR_LIMIT, path) {
         Ok(c) => c
             .into_iter()
             .map(|(name, count)| Contributor { name, count })
@@ -57,7 +60,7 @@ mod tests {
         // We're running in the scrat repo — HEAD~5 should be a valid ref.
         // This is a "smoke test" that the function runs without panicking
         // and produces plausible values.
-        let result = compute_stats("HEAD~5");
+        let result = compute_stats("HEAD~5", None);
         // Should succeed in a git repo with at least 5 commits
         if let Some(stats) = result {
             // Basic sanity — 5 commits back should have some commits
@@ -69,7 +72,7 @@ mod tests {
 
     #[test]
     fn release_stats_has_contributors() {
-        let result = compute_stats("HEAD~5");
+        let result = compute_stats("HEAD~5", None);
         if let Some(stats) = result {
             // At least one contributor should be present
             assert!(!stats.contributors.is_empty(), "expected contributors");
@@ -81,10 +84,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compute_stats_limited_to_path() {
+        let all = compute_stats("HEAD~5", None);
+        let scoped = compute_stats("HEAD~5", Some("definitely/not/a/path"));
+        if let (Some(all), Some(scoped)) = (all, scoped) {
+            assert_eq!(scoped.commit_count, 0);
+            assert_eq!(scoped.files_changed, 0);
+            assert!(scoped.contributors.is_empty());
+            assert!(all.commit_count >= scoped.commit_count);
+        }
+    }
+
     #[test]
     fn compute_stats_bad_ref_returns_none() {
         // A nonsense ref should fail gracefully, not panic
-        let result = compute_stats("definitely-not-a-real-ref-abc123xyz");
+        let result = compute_stats("definitely-not-a-real-ref-abc123xyz", None);
         assert!(result.is_none());
     }
 }
@@ -0,0 +1,407 @@
+//! Calendar versioning.
+//!
+//! A [`CalVerFormat`] such as `YYYY.MM.MICRO` or `YY.0W.MICRO` names the
+//! version after the release date: a year, then a month or ISO week, then
+//! a counter that restarts at 0 whenever the period changes.
+//!
+//! CalVer versions are carried as [`semver::Version`]s (year, period,
+//! micro), so ordering, pre-releases, and manifest rewrites work as for
+//! semver. Only the rendering differs: zero-padded tokens (`0M`, `0W`,
+//! `0Y`) are padded in tags, notes, hooks, and `[[version.files]]`, while
+//! manifests that require semver get the unpadded form (`26.5.1`).
+
+use std::fmt;
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::version::{VersionError, VersionResult, release_of};
+
+/// The format used when `version.calver_format` is not set.
+pub const DEFAULT_CALVER_FORMAT: &str = "YYYY.MM.MICRO";
+
+// ──────────────────────────────────────────────
+// Dates
+// ──────────────────────────────────────────────
+
+/// A calendar date (UTC), computed without external date crates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    /// Year (e.g. 2026).
+    pub year: i64,
+    /// Month, 1–12.
+    pub month: u32,
+    /// Day of the month, 1–31.
+    pub day: u32,
+}
+
+impl Date {
+    /// Today's date (UTC).
+    pub fn today() -> Self {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Self::from_days((secs / 86400) as i64)
+    }
+
+    /// The date `days` days after 1970-01-01.
+    ///
+    /// Uses the civil-from-days algorithm (Howard Hinnant).
+    pub const fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097); // day of era [0, 146096]
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // year of era [0, 399]
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // day of year [0, 365]
+        let mp = (5 * doy + 2) / 153; // month index [0, 11]
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32; // day [1, 31]
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32; // month [1, 12]
+        let year = if month <= 2 { y + 1 } else { y };
+        Self { year, month, day }
+    }
+
+    /// Days since 1970-01-01 (Hinnant's days-from-civil).
+    const fn days(self) -> i64 {
+        let y = if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400);
+        let m = self.month as i64;
+        let mp = if m > 2 { m - 3 } else { m + 9 };
+        let doy = (153 * mp + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// The ISO 8601 week-numbering year and week (1–53).
+    ///
+    /// Weeks start on Monday; week 1 is the one containing the year's first
+    /// Thursday, so early January can belong to the previous year's last
+    /// week.
+    pub const fn iso_week(self) -> (i64, u32) {
+        let days = self.days();
+        // 1970-01-01 was a Thursday; Monday = 0
+        let weekday = (days + 3).rem_euclid(7);
+        let thursday = days - weekday + 3;
+        let year = Self::from_days(thursday).year;
+        let first = Self {
+            year,
+            month: 1,
+            day: 1,
+        }
+        .days();
+        (year, ((thursday - first) / 7 + 1) as u32)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// ──────────────────────────────────────────────
+// Formats
+// ──────────────────────────────────────────────
+
+/// One dot-separated part of a CalVer format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    /// `YYYY` — full year (2026).
+    FullYear,
+    /// `YY` — year since 2000 (26).
+    ShortYear,
+    /// `0Y` — zero-padded year since 2000 (06).
+    PaddedYear,
+    /// `MM` — month (1–12).
+    Month,
+    /// `0M` — zero-padded month (01–12).
+    PaddedMonth,
+    /// `WW` — ISO week (1–53).
+    Week,
+    /// `0W` — zero-padded ISO week (01–53).
+    PaddedWeek,
+    /// `MICRO` (or `N`) — release counter within the period.
+    Micro,
+}
+
+impl Token {
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "YYYY" => Self::FullYear,
+            "YY" => Self::ShortYear,
+            "0Y" => Self::PaddedYear,
+            "MM" => Self::Month,
+            "0M" => Self::PaddedMonth,
+            "WW" => Self::Week,
+            "0W" => Self::PaddedWeek,
+            "MICRO" | "N" => Self::Micro,
+            _ => return None,
+        })
+    }
+
+    const fn is_year(self) -> bool {
+        matches!(self, Self::FullYear | Self::ShortYear | Self::PaddedYear)
+    }
+
+    const fn is_week(self) -> bool {
+        matches!(self, Self::Week | Self::PaddedWeek)
+    }
+
+    const fn is_padded(self) -> bool {
+        matches!(
+            self,
+            Self::PaddedYear | Self::PaddedMonth | Self::PaddedWeek
+        )
+    }
+
+    /// Render a component.
+    fn render(self, value: u64) -> String {
+        if self.is_padded() {
+            format!("{value:02}")
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Read a component, rejecting text this token would not produce.
+    fn read(self, text: &str) -> Option<u64> {
+        if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let unpadded = text.len() == 1 || !text.starts_with('0');
+        let well_formed = match self {
+            Self::FullYear => text.len() == 4 && unpadded,
+            Self::PaddedYear | Self::PaddedMonth | Self::PaddedWeek => text.len() >= 2,
+            Self::ShortYear | Self::Month | Self::Week | Self::Micro => unpadded,
+        };
+        let value: u64 = text.parse().ok().filter(|_| well_formed)?;
+        let in_range = match self {
+            Self::Month | Self::PaddedMonth => (1..=12).contains(&value),
+            Self::Week | Self::PaddedWeek => (1..=53).contains(&value),
+            _ => true,
+        };
+        in_range.then_some(value)
+    }
+}
+
+/// A calendar versioning format: a year, a month or ISO week, and a
+/// counter (`YYYY.MM.MICRO`, `YY.0W.MICRO`).
+///
+/// Tokens follow [calver.org](https://calver.org/): `YYYY`, `YY`, `0Y`,
+/// `MM`, `0M`, `WW`, `0W`, and `MICRO` (also written `N`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CalVerFormat {
+    text: String,
+    tokens: [Token; 3],
+}
+
+impl Default for CalVerFormat {
+    fn default() -> Self {
+        Self::new(DEFAULT_CALVER_FORMAT).expect("default CalVer format is valid")
+    }
+}
+
+impl CalVerFormat {
+    /// Parse a format such as `"YYYY.MM.MICRO"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersionError::InvalidCalVer`] unless the format is a year
+    /// token, a month or week token, and `MICRO`, separated by dots.
+    pub fn new(format: &str) -> VersionResult<Self> {
+        let invalid = || VersionError::InvalidCalVer(format.into());
+        let tokens: Vec<Token> = format
+            .split('.')
+            .map(Token::parse)
+            .collect::<Option<_>>()
+            .ok_or_else(invalid)?;
+        match tokens[..] {
+            [year, period, Token::Micro] if year.is_year() && !period.is_year() => Ok(Self {
+                text: format.into(),
+                tokens: [year, period, Token::Micro],
+            }),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// The year and period components for `date`.
+    ///
+    /// Week formats use the ISO week-numbering year, so the last days of
+    /// December can belong to week 1 of the next year.
+    pub fn period(&self, date: Date) -> (u64, u64) {
+        let [year_token, period_token, _] = self.tokens;
+        let (year, period) = if period_token.is_week() {
+            let (year, week) = date.iso_week();
+            (year, u64::from(week))
+        } else {
+            (date.year, u64::from(date.month))
+        };
+        let year = u64::try_from(year).unwrap_or_default();
+        let year = if year_token == Token::FullYear {
+            year
+        } else {
+            year.saturating_sub(2000)
+        };
+        (year, period)
+    }
+
+    /// The version to release on `today` after `current`.
+    ///
+    /// Within the current period the counter goes up by one (or an open
+    /// pre-release is promoted); in a new period it restarts at 0.
+    pub fn next(&self, current: &Version, today: Date) -> Version {
+        let (year, period) = self.period(today);
+        if current.major == year && current.minor == period {
+            if current.pre.is_empty() {
+                Version::new(year, period, current.patch + 1)
+            } else {
+                release_of(current)
+            }
+        } else {
+            Version::new(year, period, 0)
+        }
+    }
+
+    /// Render a version in this format (`2026.10.0`, `26.05.1-rc.1`).
+    pub fn format(&self, version: &Version) -> String {
+        let [year, period, micro] = self.tokens;
+        let mut out = format!(
+            "{}.{}.{}",
+            year.render(version.major),
+            period.render(version.minor),
+            micro.render(version.patch)
+        );
+        if !version.pre.is_empty() {
+            out.push('-');
+            out.push_str(version.pre.as_str());
+        }
+        if !version.build.is_empty() {
+            out.push('+');
+            out.push_str(version.build.as_str());
+        }
+        out
+    }
+
+    /// Parse a version written in this format. Returns `None` if `s` does
+    /// not follow it.
+    pub fn parse(&self, s: &str) -> Option<Version> {
+        let split = s.find(['-', '+']).unwrap_or(s.len());
+        let (core, rest) = s.split_at(split);
+        let mut parts = core.split('.');
+        let mut components = [0; 3];
+        for (component, token) in components.iter_mut().zip(self.tokens) {
+            *component = token.read(parts.next()?)?;
+        }
+        if parts.next().is_some() {
+            return None;
+        }
+        let [year, period, micro] = components;
+        Version::parse(&format!("{year}.{period}.{micro}{rest}")).ok()
+    }
+}
+
+impl TryFrom<String> for CalVerFormat {
+    type Error = VersionError;
+
+    fn try_from(value: String) -> VersionResult<Self> {
+        Self::new(&value)
+    }
+}
+
+impl From<CalVerFormat> for String {
+    fn from(format: CalVerFormat) -> Self {
+        format.text
+    }
+}
+
+impl fmt::Display for CalVerFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i64, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn dates_round_trip() {
+        assert_eq!(Date::from_days(0), date(1970, 1, 1));
+        assert_eq!(Date::from_days(20_742), date(2026, 10, 16));
+        assert_eq!(date(2026, 10, 16).days(), 20_742);
+        assert_eq!(date(2024, 2, 29).to_string(), "2024-02-29");
+        assert_eq!(Date::from_days(date(2024, 2, 29).days()), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn iso_weeks() {
+        assert_eq!(date(2026, 10, 16).iso_week(), (2026, 42));
+        assert_eq!(date(2026, 1, 1).iso_week(), (2026, 1));
+        // Friday 2027-01-01 belongs to the last week of 2026
+        assert_eq!(date(2027, 1, 1).iso_week(), (2026, 53));
+        // Monday 2024-12-30 starts week 1 of 2025
+        assert_eq!(date(2024, 12, 30).iso_week(), (2025, 1));
+    }
+
+    #[test]
+    fn formats() {
+        assert!(CalVerFormat::new("YYYY.MM.MICRO").is_ok());
+        assert!(CalVerFormat::new("YY.0W.N").is_ok());
+        assert!(CalVerFormat::new("YYYY.MM").is_err());
+        assert!(CalVerFormat::new("MM.YYYY.MICRO").is_err());
+        assert!(CalVerFormat::new("YYYY.YY.MICRO").is_err());
+        assert!(CalVerFormat::new("YYYY.DD.MICRO").is_err());
+        assert_eq!(CalVerFormat::default().to_string(), "YYYY.MM.MICRO");
+    }
+
+    #[test]
+    fn next_versions() {
+        let monthly = CalVerFormat::new("YYYY.MM.MICRO").unwrap();
+        let today = date(2026, 10, 16);
+        assert_eq!(monthly.next(&v("0.0.0"), today), v("2026.10.0"));
+        assert_eq!(monthly.next(&v("2026.10.0"), today), v("2026.10.1"));
+        assert_eq!(monthly.next(&v("2026.9.4"), today), v("2026.10.0"));
+        assert_eq!(monthly.next(&v("2026.10.2-rc.1"), today), v("2026.10.2"));
+
+        let weekly = CalVerFormat::new("YY.0W.MICRO").unwrap();
+        assert_eq!(weekly.next(&v("26.41.3"), today), v("26.42.0"));
+        assert_eq!(weekly.next(&v("26.42.0"), today), v("26.42.1"));
+        assert_eq!(weekly.next(&v("26.52.0"), date(2027, 1, 1)), v("26.53.0"));
+    }
+
+    #[test]
+    fn format_and_parse() {
+        let weekly = CalVerFormat::new("YY.0W.MICRO").unwrap();
+        assert_eq!(weekly.format(&v("26.5.1")), "26.05.1");
+        assert_eq!(weekly.format(&v("26.5.0-rc.2")), "26.05.0-rc.2");
+        assert_eq!(weekly.parse("26.05.1"), Some(v("26.5.1")));
+        assert_eq!(weekly.parse("26.05.0-rc.2"), Some(v("26.5.0-rc.2")));
+        assert_eq!(weekly.parse("26.5.1"), None);
+        assert_eq!(weekly.parse("26.60.1"), None);
+
+        let monthly = CalVerFormat::default();
+        assert_eq!(monthly.format(&v("2026.10.0")), "2026.10.0");
+        assert_eq!(monthly.parse("2026.10.0"), Some(v("2026.10.0")));
+        // Semver tags from before the switch don't match
+        assert_eq!(monthly.parse("1.4.0"), None);
+        assert_eq!(monthly.parse("2026.01.0"), None);
+        assert_eq!(monthly.parse("2026.10"), None);
+    }
+}
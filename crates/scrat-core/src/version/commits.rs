@@ -0,0 +1,323 @@
+//! Built-in conventional-commit analysis.
+//!
+//! Parses [Conventional Commits](https://www.conventionalcommits.org/) in
+//! the history since the last tag and works out the bump level, without
+//! git-cliff or cog. The per-commit classification is shared by version
+//! computation, the interactive picker, and release notes.
+//!
+//! The mapping from commit type to bump level follows `[version.bump]`
+//! ([`BumpRulesConfig`]); without it, breaking changes are major, `feat` is
+//! minor, and `fix` and `perf` are patch.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use semver::Version;
+use serde::Serialize;
+use tracing::{debug, instrument};
+
+use crate::config::BumpRulesConfig;
+use crate::package::PackageScope;
+use crate::version::tag::TagTemplate;
+use crate::version::{BumpLevel, VersionResult, next_version};
+
+/// `type(scope)!: description`
+static HEADER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?<kind>[A-Za-z][\w-]*)(?:\((?<scope>[^()\r\n]*)\))?(?<bang>!)?: (?<description>.+)$",
+    )
+    .expect("header pattern is valid")
+});
+
+/// A parsed conventional commit message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConventionalCommit {
+    /// Commit type, lowercased (`feat`, `fix`, ...).
+    pub kind: String,
+    /// Optional scope (`feat(parser): ...` → `parser`).
+    pub scope: Option<String>,
+    /// The description after the colon.
+    pub description: String,
+    /// Whether the commit is marked breaking (`!` or a `BREAKING CHANGE:` footer).
+    pub breaking: bool,
+    /// Text of the `BREAKING CHANGE:` footer, if any.
+    pub breaking_description: Option<String>,
+}
+
+impl ConventionalCommit {
+    /// Parse a full commit message. Returns `None` if the header does not
+    /// follow the conventional-commit format.
+    pub fn parse(message: &str) -> Option<Self> {
+        let header = message.lines().next()?.trim_end();
+        let caps = HEADER.captures(header)?;
+        let breaking_description = message.lines().skip(1).find_map(|line| {
+            line.strip_prefix("BREAKING CHANGE:")
+                .or_else(|| line.strip_prefix("BREAKING-CHANGE:"))
+                .map(|text| text.trim().to_string())
+        });
+        Some(Self {
+            kind: caps["kind"].to_ascii_lowercase(),
+            scope: caps
+                .name("scope")
+                .map(|m| m.as_str().trim().to_string())
+                .filter(|s| !s.is_empty()),
+            description: caps["description"].trim().to_string(),
+            breaking: caps.name("bang").is_some() || breaking_description.is_some(),
+            breaking_description,
+        })
+    }
+
+    /// The bump level this commit calls for under `rules`: none for ignored
+    /// types, major for breaking changes, then the configured level for the
+    /// type, then the defaults (minor for `feat`, patch for `fix` and
+    /// `perf`).
+    pub fn level(&self, rules: &BumpRulesConfig) -> Option<BumpLevel> {
+        if rules
+            .ignore
+            .as_ref()
+            .is_some_and(|ignored| ignored.iter().any(|t| t.eq_ignore_ascii_case(&self.kind)))
+        {
+            return None;
+        }
+        if self.breaking {
+            return Some(BumpLevel::Major);
+        }
+        if let Some(level) = rules.types.as_ref().and_then(|types| {
+            types
+                .iter()
+                .find(|(kind, _)| kind.eq_ignore_ascii_case(&self.kind))
+                .map(|(_, level)| *level)
+        }) {
+            return Some(level);
+        }
+        match self.kind.as_str() {
+            "feat" => Some(BumpLevel::Minor),
+            "fix" | "perf" => Some(BumpLevel::Patch),
+            _ => None,
+        }
+    }
+}
+
+/// A commit with its conventional-commit classification.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClassifiedCommit {
+    /// Full commit hash.
+    pub id: String,
+    /// First line of the message.
+    pub subject: String,
+    /// The parsed message (`None` for non-conventional commits).
+    pub conventional: Option<ConventionalCommit>,
+    /// The bump level the commit calls for (`None` = no release).
+    pub level: Option<BumpLevel>,
+}
+
+impl ClassifiedCommit {
+    /// Classify a commit from its hash and full message.
+    pub fn new(id: &str, message: &str, rules: &BumpRulesConfig) -> Self {
+        let conventional = ConventionalCommit::parse(message);
+        let level = conventional.as_ref().and_then(|c| c.level(rules));
+        Self {
+            id: id.to_string(),
+            subject: message.lines().next().unwrap_or_default().to_string(),
+            conventional,
+            level,
+        }
+    }
+}
+
+/// The commits since the last release, classified.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CommitAnalysis {
+    /// The tag the analysis starts from (`None` = whole history).
+    pub since: Option<String>,
+    /// Commits, newest first.
+    pub commits: Vec<ClassifiedCommit>,
+    /// The highest bump level among the commits (`None` = nothing to release).
+    pub level: Option<BumpLevel>,
+}
+
+impl CommitAnalysis {
+    /// Classify `(hash, message)` pairs.
+    pub fn from_messages(
+        since: Option<String>,
+        messages: &[(String, String)],
+        rules: &BumpRulesConfig,
+    ) -> Self {
+        let commits: Vec<ClassifiedCommit> = messages
+            .iter()
+            .map(|(id, message)| ClassifiedCommit::new(id, message, rules))
+            .collect();
+        let level = commits.iter().filter_map(|c| c.level).max();
+        Self {
+            since,
+            commits,
+            level,
+        }
+    }
+
+    /// The version after `current`, or `None` if nothing calls for a
+    /// release.
+    pub fn next_version(&self, current: &Version, rules: &BumpRulesConfig) -> Option<Version> {
+        let level = level_at(self.level?, current, rules);
+        Some(next_version(current, level))
+    }
+}
+
+/// The level to apply to `current`: a major bump of a `0.x` version becomes
+/// [`breaking_in_zero`](BumpRulesConfig::breaking_in_zero) when configured.
+pub fn level_at(level: BumpLevel, current: &Version, rules: &BumpRulesConfig) -> BumpLevel {
+    if level == BumpLevel::Major && current.major == 0 {
+        rules.breaking_in_zero.unwrap_or(level)
+    } else {
+        level
+    }
+}
+
+/// Analyze the commits since the latest tag matching `tags`.
+///
+/// For a monorepo package, only the commits touching its path are
+/// considered.
+#[instrument(skip(rules))]
+pub fn analyze_commits(
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
+    rules: &BumpRulesConfig,
+) -> VersionResult<CommitAnalysis> {
+    let since = tags.latest()?.map(|(tag, _)| tag);
+    analyze_commits_since(since.as_deref(), package, rules)
+}
+
+/// Analyze the commits since `since` (or the whole history if `None`).
+#[instrument(skip(rules))]
+pub fn analyze_commits_since(
+    since: Option<&str>,
+    package: Option<&PackageScope>,
+    rules: &BumpRulesConfig,
+) -> VersionResult<CommitAnalysis> {
+    let messages = crate::git::commit_messages(since, package.map(|p| p.path.as_str()))?;
+    let analysis = CommitAnalysis::from_messages(since.map(str::to_string), &messages, rules);
+    debug!(
+        commits = analysis.commits.len(),
+        level = ?analysis.level,
+        "analyzed conventional commits"
+    );
+    Ok(analysis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_headers() {
+        let c = ConventionalCommit::parse("feat(parser): accept tabs\n\nLonger body.").unwrap();
+        assert_eq!(c.kind, "feat");
+        assert_eq!(c.scope.as_deref(), Some("parser"));
+        assert_eq!(c.description, "accept tabs");
+        assert!(!c.breaking);
+        assert_eq!(c.level(&BumpRulesConfig::default()), Some(BumpLevel::Minor));
+
+        let c = ConventionalCommit::parse("Fix: handle empty input").unwrap();
+        assert_eq!(c.kind, "fix");
+        assert_eq!(c.level(&BumpRulesConfig::default()), Some(BumpLevel::Patch));
+
+        assert_eq!(
+            ConventionalCommit::parse("docs: typo")
+                .unwrap()
+                .level(&BumpRulesConfig::default()),
+            None
+        );
+        assert!(ConventionalCommit::parse("Merge branch 'main'").is_none());
+        assert!(ConventionalCommit::parse("feat:missing space").is_none());
+    }
+
+    #[test]
+    fn breaking_changes() {
+        let bang = ConventionalCommit::parse("refactor(api)!: drop v1 routes").unwrap();
+        assert!(bang.breaking);
+        assert_eq!(
+            bang.level(&BumpRulesConfig::default()),
+            Some(BumpLevel::Major)
+        );
+
+        let footer = ConventionalCommit::parse(
+            "fix: stricter config parsing\n\nBody.\n\nBREAKING CHANGE: unknown keys are rejected",
+        )
+        .unwrap();
+        assert!(footer.breaking);
+        assert_eq!(
+            footer.breaking_description.as_deref(),
+            Some("unknown keys are rejected")
+        );
+        assert!(
+            ConventionalCommit::parse("feat: x\n\nBREAKING-CHANGE: y")
+                .unwrap()
+                .breaking
+        );
+    }
+
+    #[test]
+    fn analysis_takes_highest_level() {
+        let messages = |list: &[&str]| -> Vec<(String, String)> {
+            list.iter()
+                .enumerate()
+                .map(|(i, m)| (format!("{i:040}"), (*m).to_string()))
+                .collect()
+        };
+        let analysis = CommitAnalysis::from_messages(
+            Some("v1.2.0".into()),
+            &messages(&["fix: a", "feat: b", "chore: c", "update readme"]),
+            &BumpRulesConfig::default(),
+        );
+        assert_eq!(analysis.level, Some(BumpLevel::Minor));
+        assert_eq!(analysis.commits.len(), 4);
+        assert_eq!(analysis.commits[1].subject, "feat: b");
+        assert!(analysis.commits[3].conventional.is_none());
+
+        let analysis = CommitAnalysis::from_messages(
+            None,
+            &messages(&["docs: a", "ci: b"]),
+            &BumpRulesConfig::default(),
+        );
+        assert_eq!(analysis.level, None);
+    }
+
+    #[test]
+    fn configured_rules() {
+        let rules = BumpRulesConfig {
+            types: Some(
+                [
+                    ("perf".into(), BumpLevel::Minor),
+                    ("deps".into(), BumpLevel::Patch),
+                ]
+                .into(),
+            ),
+            ignore: Some(vec!["chore".into()]),
+            breaking_in_zero: Some(BumpLevel::Minor),
+        };
+        let level = |message: &str| ConventionalCommit::parse(message).unwrap().level(&rules);
+        assert_eq!(level("perf: faster"), Some(BumpLevel::Minor));
+        assert_eq!(level("deps: bump serde"), Some(BumpLevel::Patch));
+        assert_eq!(level("fix: a"), Some(BumpLevel::Patch));
+        assert_eq!(level("chore!: drop old toolchain"), None);
+        assert_eq!(level("docs!: rewrite"), Some(BumpLevel::Major));
+
+        let analysis =
+            CommitAnalysis::from_messages(None, &[("0".into(), "feat!: new api".into())], &rules);
+        let next = |current: &str| {
+            analysis
+                .next_version(&Version::parse(current).unwrap(), &rules)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(next("0.4.2"), "0.5.0");
+        assert_eq!(next("1.4.2"), "2.0.0");
+        let default = BumpRulesConfig::default();
+        assert_eq!(
+            analysis
+                .next_version(&Version::new(0, 4, 2), &default)
+                .unwrap(),
+            Version::new(1, 0, 0)
+        );
+    }
+}
@@ -1,38 +1,118 @@
 //! Conventional-commit version computation.
 //!
 //! Delegates to `git-cliff` or `cog` to determine the next version
-//! from the commit history.
+//! from the commit history, or uses the built-in [`commits`](super::commits)
+//! analyzer when no tool is configured or installed.
 
 use std::process::Command;
 
 use semver::Version;
 use tracing::{debug, instrument};
 
+use crate::config::BumpRulesConfig;
 use crate::ecosystem::ChangelogTool;
-use crate::version::{VersionError, VersionResult, parse_version};
+use crate::package::PackageScope;
+use crate::version::commits::analyze_commits;
+use crate::version::tag::TagTemplate;
+use crate::version::{BumpLevel, VersionError, VersionResult, next_version, parse_version};
 
-/// Compute the next version using a conventional-commit tool.
+/// Compute the next version from conventional commits.
 ///
 /// - **git-cliff**: runs `git cliff --bumped-version`
 /// - **cog**: runs `cog bump --dry-run --auto`
-#[instrument]
-pub fn compute_next_version(tool: ChangelogTool) -> VersionResult<Version> {
-    match tool {
-        ChangelogTool::GitCliff => compute_via_cliff(),
-        ChangelogTool::Cog => compute_via_cog(),
+/// - **built-in** (no tool, or its binary is not installed): analyzes the
+///   commits since the last tag itself
+///
+/// Configured `rules` (`[version.bump]`) always go through the built-in
+/// analyzer, since the tools apply their own policy. A `forced` level
+/// (`--bump`) replaces the one the commits call for: git-cliff gets
+/// `--bump <level>`, cog `--<level>`, and the built-in analyzer bumps the
+/// latest tag's version.
+///
+/// Only tags matching `tags` count as releases. For a monorepo package,
+/// only commits touching its path are considered.
+#[instrument(skip(rules))]
+pub fn compute_next_version(
+    tool: Option<ChangelogTool>,
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
+    rules: Option<&BumpRulesConfig>,
+    forced: Option<BumpLevel>,
+) -> VersionResult<Version> {
+    if let Some(rules) = rules {
+        debug!("bump rules configured, using the built-in analyzer");
+        return compute_builtin(tags, package, rules, forced);
+    }
+    let installed = tool.filter(|t| crate::detect::has_binary(&t.to_string()));
+    if let Some(missing) = tool.filter(|_| installed.is_none()) {
+        debug!(%missing, "tool not installed, using the built-in analyzer");
     }
+    match installed {
+        Some(ChangelogTool::GitCliff) => compute_via_cliff(tags, package, forced),
+        Some(ChangelogTool::Cog) => compute_via_cog(package, forced),
+        None => compute_builtin(tags, package, &BumpRulesConfig::default(), forced),
+    }
+}
+
+/// Compute the next version with the built-in analyzer.
+///
+/// The first release is `0.1.0`; afterwards the highest bump level among
+/// the commits since the last tag applies. A `forced` level applies to the
+/// latest tag's version (or `0.0.0`) instead.
+fn compute_builtin(
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
+    rules: &BumpRulesConfig,
+    forced: Option<BumpLevel>,
+) -> VersionResult<Version> {
+    if let Some(level) = forced {
+        debug!(%level, "applying forced bump level");
+        let current = tags
+            .latest()?
+            .map_or_else(|| Version::new(0, 0, 0), |(_, version)| version);
+        return Ok(next_version(&current, level));
+    }
+    debug!("computing version from conventional commits");
+
+    let analysis = analyze_commits(tags, package, rules)?;
+    let Some(ref since) = analysis.since else {
+        return Ok(Version::new(0, 1, 0));
+    };
+    let current = tags.parse(since).map_or_else(|| parse_version(since), Ok)?;
+    analysis
+        .next_version(&current, rules)
+        .ok_or_else(|| VersionError::NothingToRelease {
+            since: since.clone(),
+        })
 }
 
-fn compute_via_cliff() -> VersionResult<Version> {
+/// `git-cliff` arguments limiting it to the tags matching `tags` and, for a
+/// monorepo package, to the commits touching its path.
+pub fn cliff_tag_args(tags: &TagTemplate, package: Option<&PackageScope>) -> Vec<String> {
+    let mut args = vec!["--tag-pattern".into(), tags.pattern()];
+    if let Some(package) = package {
+        args.extend(["--include-path".into(), package.include_glob()]);
+    }
+    args
+}
+
+fn compute_via_cliff(
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
+    forced: Option<BumpLevel>,
+) -> VersionResult<Version> {
     debug!("computing version via git-cliff");
 
-    let output = Command::new("git-cliff")
-        .arg("--bumped-version")
-        .output()
-        .map_err(|e| VersionError::ToolFailed {
-            tool: "git-cliff".into(),
-            message: format!("failed to execute: {e}"),
-        })?;
+    let mut cmd = Command::new("git-cliff");
+    cmd.arg("--bumped-version");
+    cmd.args(cliff_tag_args(tags, package));
+    if let Some(level) = forced {
+        cmd.args(["--bump", &level.to_string()]);
+    }
+    let output = cmd.output().map_err(|e| VersionError::ToolFailed {
+        tool: "git-cliff".into(),
+        message: format!("failed to execute: {e}"),
+    })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -42,21 +122,29 @@ fn compute_via_cliff() -> VersionResult<Version> {
         });
     }
 
+    // The bumped version is a tag name (e.g. `scrat-core-v0.2.0`)
     let version_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
     debug!(%version_str, "git-cliff suggested version");
-    parse_version(&version_str)
+    tags.parse(&version_str)
+        .map_or_else(|| parse_version(&version_str), Ok)
 }
 
-fn compute_via_cog() -> VersionResult<Version> {
+fn compute_via_cog(
+    package: Option<&PackageScope>,
+    forced: Option<BumpLevel>,
+) -> VersionResult<Version> {
     debug!("computing version via cog");
 
-    let output = Command::new("cog")
-        .args(["bump", "--dry-run", "--auto"])
-        .output()
-        .map_err(|e| VersionError::ToolFailed {
-            tool: "cog".into(),
-            message: format!("failed to execute: {e}"),
-        })?;
+    let mut cmd = Command::new("cog");
+    cmd.args(["bump", "--dry-run"]);
+    cmd.arg(forced.map_or_else(|| "--auto".to_string(), |level| format!("--{level}")));
+    if let Some(package) = package {
+        cmd.args(["--package", &package.name]);
+    }
+    let output = cmd.output().map_err(|e| VersionError::ToolFailed {
+        tool: "cog".into(),
+        message: format!("failed to execute: {e}"),
+    })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -0,0 +1,300 @@
+//! Version explanation.
+//!
+//! Shows why a version was chosen: where the current version comes from,
+//! how each commit since the last tag is classified, and which commit set
+//! the final bump level. The classification is the built-in
+//! [`commits`](super::commits) analyzer's, under the configured
+//! `[version.bump]` rules; the next version itself is planned the way
+//! [`bump`] plans it, so it can come from git-cliff or cog, an
+//! open pre-release cycle, or a forced level instead.
+
+use camino::Utf8Path;
+use semver::Version;
+use serde::Serialize;
+use tracing::instrument;
+
+use crate::bump::{self, BumpError, BumpOptions, BumpPlan, BumpResult};
+use crate::config::{BumpRulesConfig, Config};
+use crate::ecosystem::VersionStrategy;
+use crate::version::commits::{ClassifiedCommit, analyze_commits_since, level_at};
+use crate::version::tag::TagTemplate;
+use crate::version::{BumpLevel, VersionError, VersionScheme, next_version, parse_version};
+use crate::{detect, manifest};
+
+/// Where the current version was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionSource {
+    /// The latest tag matching the tag template.
+    Tag,
+    /// The project manifest (no matching tag yet).
+    Manifest,
+    /// Neither — this is the first release.
+    None,
+}
+
+impl std::fmt::Display for VersionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tag => write!(f, "tag"),
+            Self::Manifest => write!(f, "manifest"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+/// One commit since the last tag and what it contributes.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplainedCommit {
+    /// Full commit hash.
+    pub id: String,
+    /// First line of the message.
+    pub subject: String,
+    /// Commit type (`None` for non-conventional commits).
+    pub kind: Option<String>,
+    /// Commit scope, if any.
+    pub scope: Option<String>,
+    /// Whether the commit is marked breaking.
+    pub breaking: bool,
+    /// The bump level the commit contributes (`None` = no release), after
+    /// `breaking_in_zero` is applied.
+    pub level: Option<BumpLevel>,
+}
+
+/// Why the next version is what it is.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionExplanation {
+    /// The current version, written the way it is released.
+    pub current: Option<String>,
+    /// Where [`current`](Self::current) was read from.
+    pub source: VersionSource,
+    /// The latest matching tag (`None` = first release).
+    pub tag: Option<String>,
+    /// The version in the project manifest, if one could be read.
+    pub manifest_version: Option<String>,
+    /// Commits since [`tag`](Self::tag), newest first.
+    pub commits: Vec<ExplainedCommit>,
+    /// The final bump level (`None` = nothing to release).
+    pub level: Option<BumpLevel>,
+    /// Hash of the oldest commit that calls for the final level.
+    pub decided_by: Option<String>,
+    /// The version the built-in analyzer's level leads to (`None` when
+    /// nothing calls for a release, or the project uses calendar versioning,
+    /// which ignores bump levels).
+    pub builtin_next: Option<String>,
+    /// How [`next`](Self::next) was planned (`None` = it wasn't: the project
+    /// type is unknown and `next` is the built-in analyzer's, or nothing
+    /// calls for a release).
+    pub strategy: Option<VersionStrategy>,
+    /// The next version `scrat bump` and `scrat ship` would release, or the
+    /// one the interactive picker suggests (`None` = nothing to release).
+    pub next: Option<String>,
+}
+
+impl VersionExplanation {
+    /// Build an explanation from the commits classified since the last tag.
+    ///
+    /// `base` is the version the levels apply to (`None` = first release,
+    /// which is `0.1.0` whenever anything calls for a release).
+    pub fn from_commits(
+        base: Option<&Version>,
+        commits: &[ClassifiedCommit],
+        rules: &BumpRulesConfig,
+        scheme: &VersionScheme,
+    ) -> Self {
+        let zero = Version::new(0, 0, 0);
+        let at = base.unwrap_or(&zero);
+        let commits: Vec<ExplainedCommit> = commits
+            .iter()
+            .map(|c| ExplainedCommit {
+                id: c.id.clone(),
+                subject: c.subject.clone(),
+                kind: c.conventional.as_ref().map(|cc| cc.kind.clone()),
+                scope: c.conventional.as_ref().and_then(|cc| cc.scope.clone()),
+                breaking: c.conventional.as_ref().is_some_and(|cc| cc.breaking),
+                level: c.level.map(|level| level_at(level, at, rules)),
+            })
+            .collect();
+        let level = commits.iter().filter_map(|c| c.level).max();
+        let decided_by = level.and_then(|level| {
+            commits
+                .iter()
+                .rev()
+                .find(|c| c.level == Some(level))
+                .map(|c| c.id.clone())
+        });
+        let builtin_next = match (level, base, scheme) {
+            (_, _, VersionScheme::CalVer(_)) | (None, _, _) => None,
+            (Some(_), None, _) => Some(Version::new(0, 1, 0)),
+            (Some(level), Some(base), _) => Some(next_version(base, level)),
+        }
+        .map(|v| scheme.format(&v));
+        Self {
+            current: None,
+            source: VersionSource::None,
+            tag: None,
+            manifest_version: None,
+            commits,
+            level,
+            decided_by,
+            next: builtin_next.clone(),
+            builtin_next,
+            strategy: None,
+        }
+    }
+
+    /// Whether the planned version differs from the built-in analyzer's.
+    pub fn differs_from_builtin(&self) -> bool {
+        self.strategy.is_some() && self.builtin_next.is_some() && self.next != self.builtin_next
+    }
+}
+
+/// Explain the next version of the repository or of a monorepo package
+/// (`options.package`).
+///
+/// The current version comes from the latest tag matching the tag
+/// template, falling back to the project manifest before the first
+/// release. The next version is planned as [`plan_bump`](bump::plan_bump)
+/// plans it under the same `options` (`--pre`, `--bump`); when the project
+/// type can't be detected, the built-in analyzer's version stands in.
+#[instrument(skip(config, options), fields(%project_root))]
+pub fn explain_version(
+    project_root: &Utf8Path,
+    config: &Config,
+    options: &BumpOptions,
+) -> BumpResult<VersionExplanation> {
+    let package = options.package.as_ref();
+    let tags = TagTemplate::resolve(config, package)?;
+    let latest = tags.latest()?;
+
+    let detection = package.map_or_else(
+        || detect::resolve_detection(project_root, config),
+        |p| detect::resolve_package_detection(project_root, p),
+    );
+    let root = package.map_or_else(|| project_root.to_owned(), |p| p.root(project_root));
+    let manifest_version = detection
+        .as_ref()
+        .and_then(|d| manifest::current_version(&root, d.ecosystem, config));
+
+    let rules = config
+        .version
+        .as_ref()
+        .and_then(|v| v.bump.clone())
+        .unwrap_or_default();
+    let since = latest.as_ref().map(|(tag, _)| tag.as_str());
+    let analysis = analyze_commits_since(since, package, &rules)?;
+
+    let mut explanation = VersionExplanation::from_commits(
+        latest.as_ref().map(|(_, v)| v),
+        &analysis.commits,
+        &rules,
+        tags.scheme(),
+    );
+    match latest {
+        Some((tag, version)) => {
+            explanation.current = Some(tags.scheme().format(&version));
+            explanation.source = VersionSource::Tag;
+            explanation.tag = Some(tag);
+        }
+        None => {
+            let parsed = manifest_version.as_deref().and_then(|v| {
+                tags.scheme()
+                    .parse(v)
+                    .map_or_else(|| parse_version(v).ok(), Some)
+            });
+            if let Some(version) = parsed {
+                explanation.current = Some(tags.scheme().format(&version));
+                explanation.source = VersionSource::Manifest;
+            }
+        }
+    }
+    explanation.manifest_version = manifest_version;
+
+    match bump::plan(project_root, config, options) {
+        Ok(BumpPlan::Ready(ready)) => {
+            explanation.next = Some(ready.version());
+            explanation.strategy = Some(ready.strategy);
+        }
+        Ok(BumpPlan::NeedsInteraction(interactive)) => {
+            let context = interactive.context;
+            explanation.next = context
+                .suggested_index()
+                .map(|i| tags.scheme().format(&context.candidates[i].version));
+            explanation.strategy = Some(VersionStrategy::Interactive);
+        }
+        Err(BumpError::Version(VersionError::NothingToRelease { .. })) => explanation.next = None,
+        Err(BumpError::Detection(_)) => {}
+        Err(e) => return Err(e),
+    }
+    Ok(explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classified(list: &[&str], rules: &BumpRulesConfig) -> Vec<ClassifiedCommit> {
+        list.iter()
+            .enumerate()
+            .map(|(i, m)| ClassifiedCommit::new(&format!("{i:040}"), m, rules))
+            .collect()
+    }
+
+    #[test]
+    fn names_the_deciding_commit() {
+        let commits = classified(
+            &["feat: b", "fix(api)!: a", "feat(cli): c", "docs: d"],
+            &BumpRulesConfig::default(),
+        );
+        let explanation = VersionExplanation::from_commits(
+            Some(&Version::new(1, 2, 3)),
+            &commits,
+            &BumpRulesConfig::default(),
+            &VersionScheme::Semver,
+        );
+        assert_eq!(explanation.level, Some(BumpLevel::Major));
+        assert_eq!(explanation.decided_by, Some(format!("{:040}", 1)));
+        assert_eq!(explanation.builtin_next.as_deref(), Some("2.0.0"));
+
+        let c = &explanation.commits[1];
+        assert_eq!(c.kind.as_deref(), Some("fix"));
+        assert_eq!(c.scope.as_deref(), Some("api"));
+        assert!(c.breaking);
+        assert_eq!(explanation.commits[2].level, Some(BumpLevel::Minor));
+        assert_eq!(explanation.commits[3].level, None);
+    }
+
+    #[test]
+    fn applies_zero_major_rules() {
+        let rules = BumpRulesConfig {
+            breaking_in_zero: Some(BumpLevel::Minor),
+            ..BumpRulesConfig::default()
+        };
+        let commits = classified(&["feat!: new api", "fix: a"], &rules);
+        let explanation = VersionExplanation::from_commits(
+            Some(&Version::new(0, 4, 2)),
+            &commits,
+            &rules,
+            &VersionScheme::Semver,
+        );
+        assert_eq!(explanation.commits[0].level, Some(BumpLevel::Minor));
+        assert_eq!(explanation.level, Some(BumpLevel::Minor));
+        assert_eq!(explanation.decided_by, Some(format!("{:040}", 0)));
+        assert_eq!(explanation.builtin_next.as_deref(), Some("0.5.0"));
+    }
+
+    #[test]
+    fn nothing_to_release() {
+        let commits = classified(&["docs: a", "update readme"], &BumpRulesConfig::default());
+        let explanation = VersionExplanation::from_commits(
+            None,
+            &commits,
+            &BumpRulesConfig::default(),
+            &VersionScheme::Semver,
+        );
+        assert_eq!(explanation.level, None);
+        assert_eq!(explanation.decided_by, None);
+        assert_eq!(explanation.builtin_next, None);
+        assert_eq!(explanation.commits[1].kind, None);
+    }
+}
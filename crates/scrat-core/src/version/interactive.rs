@@ -1,14 +1,22 @@
 //! Interactive version mode — data gathering.
 //!
 //! Provides the data the CLI needs to present an interactive version picker:
-//! recent commits since the last tag, and candidate versions for each bump level.
+//! recent commits since the last tag, candidate versions for each bump level,
+//! final and pre-release, and the level the conventional commits suggest.
 //! The actual prompting (inquire) happens in the CLI crate.
 
 use semver::Version;
 use serde::Serialize;
 use tracing::instrument;
 
-use crate::version::{BumpLevel, VersionResult, current_version_from_tags, next_version};
+use crate::config::BumpRulesConfig;
+use crate::package::PackageScope;
+use crate::version::commits::{analyze_commits_since, level_at};
+use crate::version::tag::TagTemplate;
+use crate::version::{BumpLevel, VersionResult, next_prerelease, next_version};
+
+/// Channel of the pre-release candidates when none is requested.
+pub const DEFAULT_CHANNEL: &str = "rc";
 
 /// Data for the interactive version picker.
 #[derive(Debug, Clone, Serialize)]
@@ -19,6 +27,16 @@ pub struct InteractiveContext {
     pub recent_commits: Vec<(String, String)>,
     /// Candidate versions for each bump level.
     pub candidates: Vec<VersionCandidate>,
+    /// The bump level the conventional commits since the last tag call for.
+    pub suggested: Option<BumpLevel>,
+}
+
+impl InteractiveContext {
+    /// Index of the first candidate at the [suggested](Self::suggested) level.
+    pub fn suggested_index(&self) -> Option<usize> {
+        let level = self.suggested?;
+        self.candidates.iter().position(|c| c.level == level)
+    }
 }
 
 /// A candidate version with its bump level.
@@ -28,54 +46,162 @@ pub struct VersionCandidate {
     pub level: BumpLevel,
     /// The resulting version.
     pub version: Version,
+    /// The pre-release channel, for pre-release candidates.
+    pub channel: Option<String>,
+}
+
+impl std::fmt::Display for VersionCandidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.channel {
+            Some(ref channel) => write!(f, "{} ({} {channel})", self.version, self.level),
+            None => write!(f, "{} ({})", self.version, self.level),
+        }
+    }
 }
 
 /// Gather the data for an interactive version prompt.
 ///
 /// Returns recent commits and candidate versions. The CLI uses this
-/// to display options and prompt the user.
-#[instrument]
-pub fn gather_interactive_context(max_commits: usize) -> VersionResult<InteractiveContext> {
-    let current = current_version_from_tags()?;
-
-    let since_tag = current.as_ref().map(|v| format!("v{v}"));
-    let commits = crate::git::recent_commits(since_tag.as_deref(), max_commits)?;
+/// to display options and prompt the user. Only tags matching `tags` count
+/// as releases; for a monorepo package, only the commits touching its path
+/// are considered.
+///
+/// Pre-release candidates use `channel`, or [`DEFAULT_CHANNEL`]; with a
+/// requested channel only pre-release candidates are offered. The
+/// suggested level follows `rules` (`[version.bump]`), if configured.
+#[instrument(skip(rules))]
+pub fn gather_interactive_context(
+    max_commits: usize,
+    tags: &TagTemplate,
+    package: Option<&PackageScope>,
+    channel: Option<&str>,
+    rules: Option<&BumpRulesConfig>,
+) -> VersionResult<InteractiveContext> {
+    let rules = rules.cloned().unwrap_or_default();
+    let latest = tags.latest()?;
+    let since_tag = latest.as_ref().map(|(tag, _)| tag.clone());
+    let current = latest.map(|(_, version)| version);
+    let commits = crate::git::recent_commits(
+        since_tag.as_deref(),
+        max_commits,
+        package.map(|p| p.path.as_str()),
+    )?;
 
-    let candidates = current.as_ref().map_or_else(
-        || {
-            // First release — suggest 0.1.0 or 1.0.0
-            vec![
-                VersionCandidate {
-                    level: BumpLevel::Minor,
-                    version: Version::new(0, 1, 0),
-                },
-                VersionCandidate {
-                    level: BumpLevel::Major,
-                    version: Version::new(1, 0, 0),
-                },
-            ]
-        },
-        |v| {
-            vec![
-                VersionCandidate {
-                    level: BumpLevel::Patch,
-                    version: next_version(v, BumpLevel::Patch),
-                },
-                VersionCandidate {
-                    level: BumpLevel::Minor,
-                    version: next_version(v, BumpLevel::Minor),
-                },
-                VersionCandidate {
-                    level: BumpLevel::Major,
-                    version: next_version(v, BumpLevel::Major),
-                },
-            ]
-        },
-    );
+    let candidates = candidates(current.as_ref(), channel);
+    let suggested = analyze_commits_since(since_tag.as_deref(), package, &rules)?
+        .level
+        .map(|level| {
+            current
+                .as_ref()
+                .map_or(level, |v| level_at(level, v, &rules))
+        });
 
     Ok(InteractiveContext {
         current_version: current,
         recent_commits: commits,
         candidates,
+        suggested,
     })
 }
+
+/// Candidate versions: each bump level, then a pre-release of each.
+///
+/// Levels that lead to the same version (e.g. promoting `1.3.0-rc.1`) are
+/// listed once, under the highest level.
+fn candidates(current: Option<&Version>, channel: Option<&str>) -> Vec<VersionCandidate> {
+    let levels: &[BumpLevel] = if current.is_some() {
+        &[BumpLevel::Patch, BumpLevel::Minor, BumpLevel::Major]
+    } else {
+        // First release — suggest 0.1.0 or 1.0.0
+        &[BumpLevel::Minor, BumpLevel::Major]
+    };
+    let release = |level: BumpLevel| {
+        current.map_or_else(
+            || match level {
+                BumpLevel::Major => Version::new(1, 0, 0),
+                _ => Version::new(0, 1, 0),
+            },
+            |v| next_version(v, level),
+        )
+    };
+
+    let mut candidates: Vec<VersionCandidate> = Vec::new();
+    let mut push = |candidate: VersionCandidate| match candidates
+        .iter_mut()
+        .find(|c| c.version == candidate.version)
+    {
+        Some(existing) => existing.level = candidate.level,
+        None => candidates.push(candidate),
+    };
+    if channel.is_none() {
+        for &level in levels {
+            push(VersionCandidate {
+                level,
+                version: release(level),
+                channel: None,
+            });
+        }
+    }
+    let channel = channel.unwrap_or(DEFAULT_CHANNEL);
+    for &level in levels {
+        // A channel that would go backwards is not a candidate
+        if let Ok(version) = next_prerelease(current, &release(level), channel) {
+            push(VersionCandidate {
+                level,
+                version,
+                channel: Some(channel.into()),
+            });
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(current: Option<&str>, channel: Option<&str>) -> Vec<String> {
+        let current = current.map(|v| Version::parse(v).unwrap());
+        candidates(current.as_ref(), channel)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn final_and_prerelease_candidates() {
+        assert_eq!(
+            labels(Some("1.2.3"), None),
+            vec![
+                "1.2.4 (patch)",
+                "1.3.0 (minor)",
+                "2.0.0 (major)",
+                "1.2.4-rc.1 (patch rc)",
+                "1.3.0-rc.1 (minor rc)",
+                "2.0.0-rc.1 (major rc)",
+            ]
+        );
+        assert_eq!(
+            labels(None, Some("beta")),
+            vec!["0.1.0-beta.1 (minor beta)", "1.0.0-beta.1 (major beta)"]
+        );
+    }
+
+    #[test]
+    fn prerelease_candidates_promote_and_continue() {
+        assert_eq!(
+            labels(Some("1.3.0-rc.1"), None),
+            vec![
+                "1.3.0 (minor)",
+                "2.0.0 (major)",
+                "1.3.0-rc.2 (minor rc)",
+                "2.0.0-rc.1 (major rc)",
+            ]
+        );
+        // `beta` would go backwards from `rc` for 1.3.0
+        assert_eq!(
+            labels(Some("1.3.0-rc.1"), Some("beta")),
+            vec!["2.0.0-beta.1 (major beta)"]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Maintenance lines.
+//!
+//! A maintenance branch (`release/1.x`, `release/1.4.x`) ships patches for
+//! an older major or minor line while the main branch moves on. The
+//! branch patterns come from `project.release_branches`; the line is read
+//! from the branch name, and restricts which tags count as the previous
+//! release and which versions the branch may ship.
+
+use semver::Version;
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::git::{self, GitResult};
+use crate::glob;
+
+/// A major (`1.x`) or minor (`1.4.x`) release line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct VersionLine {
+    /// The major version every release on the line shares.
+    pub major: u64,
+    /// The minor version, for a minor line.
+    pub minor: Option<u64>,
+}
+
+impl VersionLine {
+    /// Read the line from a branch name: the last path segment, from its
+    /// first digit (`release/1.x` → `1.x`, `support/v2.3.x` → `2.3.x`).
+    /// Returns `None` if the name carries no version.
+    pub fn from_branch(branch: &str) -> Option<Self> {
+        let segment = branch.rsplit('/').next()?;
+        let start = segment.find(|c: char| c.is_ascii_digit())?;
+        let mut numbers = segment[start..]
+            .split('.')
+            .map_while(|part| part.parse::<u64>().ok());
+        Some(Self {
+            major: numbers.next()?,
+            minor: numbers.next(),
+        })
+    }
+
+    /// The line for the current branch, when it matches one of the
+    /// `project.release_branches` patterns. `None` on any other branch
+    /// (including the main release branch) and in a detached `HEAD`.
+    pub fn current(config: &Config) -> GitResult<Option<Self>> {
+        let patterns = maintenance_patterns(config);
+        if patterns.is_empty() {
+            return Ok(None);
+        }
+        let Some(branch) = git::current_branch()? else {
+            return Ok(None);
+        };
+        Ok(patterns
+            .iter()
+            .any(|pattern| glob::wildcard_match(pattern, &branch))
+            .then(|| Self::from_branch(&branch))
+            .flatten())
+    }
+
+    /// Whether `version` belongs to the line.
+    pub fn contains(&self, version: &Version) -> bool {
+        version.major == self.major && self.minor.is_none_or(|minor| version.minor == minor)
+    }
+
+    /// The version text every tag on the line starts with (`1.`, `1.4.`).
+    pub fn prefix(&self) -> String {
+        self.minor.map_or_else(
+            || format!("{}.", self.major),
+            |minor| format!("{}.{minor}.", self.major),
+        )
+    }
+}
+
+impl std::fmt::Display for VersionLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x", self.prefix())
+    }
+}
+
+/// The configured maintenance branch patterns (`project.release_branches`).
+pub fn maintenance_patterns(config: &Config) -> &[String] {
+    config
+        .project
+        .as_ref()
+        .and_then(|p| p.release_branches.as_deref())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_from_branch_names() {
+        let line = |branch: &str| VersionLine::from_branch(branch).map(|l| l.to_string());
+        assert_eq!(line("release/1.x").as_deref(), Some("1.x"));
+        assert_eq!(line("release/1.4.x").as_deref(), Some("1.4.x"));
+        assert_eq!(line("support/v2.3.x").as_deref(), Some("2.3.x"));
+        assert_eq!(line("release-3").as_deref(), Some("3.x"));
+        assert_eq!(line("main"), None);
+        assert_eq!(line("release/next"), None);
+    }
+
+    #[test]
+    fn line_membership() {
+        let major = VersionLine::from_branch("release/1.x").unwrap();
+        assert!(major.contains(&Version::new(1, 4, 3)));
+        assert!(!major.contains(&Version::new(2, 0, 0)));
+
+        let minor = VersionLine::from_branch("release/1.4.x").unwrap();
+        assert!(minor.contains(&Version::new(1, 4, 3)));
+        assert!(!minor.contains(&Version::new(1, 5, 0)));
+        assert_eq!(minor.prefix(), "1.4.");
+    }
+}
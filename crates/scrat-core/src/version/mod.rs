@@ -1,17 +1,28 @@
 //! Version determination and computation.
 //!
 //! This module handles figuring out what the next version should be via
-//! three strategies: conventional commits (auto), interactive (prompted),
-//! and explicit (user-supplied).
+//! four strategies: conventional commits (auto), interactive (prompted),
+//! explicit (user-supplied), and [calendar versioning](calver). Conventional
+//! commits are analyzed by git-cliff, cog, or the built-in [`commits`]
+//! parser; [`explain`] shows how they were classified.
 
+pub mod calver;
+pub mod commits;
 pub mod conventional;
+pub mod explain;
 pub mod explicit;
 pub mod interactive;
+pub mod line;
+pub mod tag;
 
-use semver::Version;
+use semver::{Prerelease, Version};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::config::Config;
+use crate::version::calver::CalVerFormat;
+use crate::version::tag::TagTemplate;
+
 /// Errors from version operations.
 #[derive(Error, Debug)]
 pub enum VersionError {
@@ -35,13 +46,47 @@ pub enum VersionError {
     /// A git operation failed.
     #[error("git error: {0}")]
     Git(#[from] crate::git::GitError),
+
+    /// No commit since the last tag calls for a release.
+    #[error(
+        "no releasable commits since {since} — only commits like `feat:` and `fix:` bump the version"
+    )]
+    NothingToRelease {
+        /// The tag the analysis started from.
+        since: String,
+    },
+
+    /// A pre-release channel name that cannot start a semver pre-release.
+    #[error("invalid pre-release channel `{0}` — use letters, digits, and hyphens (e.g. `rc`)")]
+    InvalidChannel(String),
+
+    /// The requested pre-release would sort before the current version.
+    #[error("cannot move from {current} to a `{channel}` pre-release of the same version")]
+    ChannelRegression {
+        /// The current pre-release version.
+        current: Version,
+        /// The requested channel.
+        channel: String,
+    },
+
+    /// A `version.calver_format` that is not year, period, and `MICRO`.
+    #[error(
+        "invalid CalVer format `{0}` — use a year, a month or week, and MICRO (e.g. `YYYY.MM.MICRO`)"
+    )]
+    InvalidCalVer(String),
+
+    /// A `release.tag_template` without exactly one `{version}`.
+    #[error("invalid tag template `{0}` — it must contain `{{version}}` exactly once")]
+    InvalidTagTemplate(String),
 }
 
 /// Result alias for version operations.
 pub type VersionResult<T> = Result<T, VersionError>;
 
-/// Semver bump level.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Semver bump level, ordered from patch to major.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, clap::ValueEnum,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum BumpLevel {
     /// Patch release (x.y.Z).
@@ -63,7 +108,20 @@ impl std::fmt::Display for BumpLevel {
 }
 
 /// Compute the next version by applying a bump level.
-pub const fn next_version(current: &Version, level: BumpLevel) -> Version {
+///
+/// A pre-release is promoted to its final version when that already
+/// satisfies the level: `1.3.0-rc.2` becomes `1.3.0` for a patch or minor
+/// bump, and `2.0.0` for a major one.
+pub fn next_version(current: &Version, level: BumpLevel) -> Version {
+    let promotes = !current.pre.is_empty()
+        && match level {
+            BumpLevel::Patch => true,
+            BumpLevel::Minor => current.patch == 0,
+            BumpLevel::Major => current.minor == 0 && current.patch == 0,
+        };
+    if promotes {
+        return release_of(current);
+    }
     match level {
         BumpLevel::Patch => Version::new(current.major, current.minor, current.patch + 1),
         BumpLevel::Minor => Version::new(current.major, current.minor + 1, 0),
@@ -71,23 +129,118 @@ pub const fn next_version(current: &Version, level: BumpLevel) -> Version {
     }
 }
 
+/// The final version of a pre-release (`1.3.0-rc.2` → `1.3.0`).
+pub const fn release_of(version: &Version) -> Version {
+    Version::new(version.major, version.minor, version.patch)
+}
+
+/// Compute the next pre-release on `channel` leading up to `release`.
+///
+/// Continuing the current channel increments its number
+/// (`1.3.0-rc.1` → `1.3.0-rc.2`); moving to a later channel or starting a
+/// new cycle begins at `.1` (`1.3.0-beta.3` → `1.3.0-rc.1`,
+/// `1.2.4` → `1.3.0-rc.1`).
+///
+/// # Errors
+///
+/// Returns [`VersionError::InvalidChannel`] for a channel that is not a
+/// single alphanumeric identifier, and [`VersionError::ChannelRegression`]
+/// when the result would sort before `current` (e.g. `rc` back to `beta`).
+pub fn next_prerelease(
+    current: Option<&Version>,
+    release: &Version,
+    channel: &str,
+) -> VersionResult<Version> {
+    let valid = !channel.is_empty()
+        && channel
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !channel.chars().all(|c| c.is_ascii_digit());
+    if !valid {
+        return Err(VersionError::InvalidChannel(channel.into()));
+    }
+
+    let cycle = current.filter(|v| !v.pre.is_empty() && release_of(v) == release_of(release));
+    let number = cycle
+        .and_then(|v| {
+            let mut identifiers = v.pre.split('.');
+            (identifiers.next() == Some(channel))
+                .then(|| identifiers.next().and_then(|n| n.parse::<u64>().ok()))
+        })
+        .map_or(1, |n| n.unwrap_or(0) + 1);
+
+    let mut next = release_of(release);
+    next.pre = Prerelease::new(&format!("{channel}.{number}"))?;
+    if let Some(current) = cycle
+        && next <= *current
+    {
+        return Err(VersionError::ChannelRegression {
+            current: current.clone(),
+            channel: channel.into(),
+        });
+    }
+    Ok(next)
+}
+
 /// Parse a version string, stripping an optional `v` prefix.
 pub fn parse_version(s: &str) -> VersionResult<Version> {
     let s = s.strip_prefix('v').unwrap_or(s);
     Ok(Version::parse(s)?)
 }
 
-/// Get the current version from git tags.
-///
-/// Returns `None` if no version tags exist (first release).
-pub fn current_version_from_tags() -> VersionResult<Option<Version>> {
-    let tag = crate::git::latest_version_tag()?;
-    match tag {
-        Some(t) => Ok(Some(parse_version(&t)?)),
-        None => Ok(None),
+/// How versions are numbered and written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum VersionScheme {
+    /// Semantic versioning (`1.2.3`).
+    #[default]
+    Semver,
+    /// Calendar versioning (`version.strategy = "calver"`).
+    CalVer(CalVerFormat),
+}
+
+impl VersionScheme {
+    /// The scheme configured for the project: CalVer when
+    /// `version.strategy` is `"calver"`, semver otherwise.
+    pub fn from_config(config: &Config) -> VersionResult<Self> {
+        let Some(version) = config
+            .version
+            .as_ref()
+            .filter(|v| v.strategy.as_deref() == Some("calver"))
+        else {
+            return Ok(Self::Semver);
+        };
+        let format = version
+            .calver_format
+            .as_deref()
+            .map_or_else(|| Ok(CalVerFormat::default()), CalVerFormat::new)?;
+        Ok(Self::CalVer(format))
+    }
+
+    /// Write a version the way it is released (tags, notes, hooks).
+    pub fn format(&self, version: &Version) -> String {
+        match self {
+            Self::Semver => version.to_string(),
+            Self::CalVer(format) => format.format(version),
+        }
+    }
+
+    /// Read a version written in this scheme. Returns `None` if `s` does not
+    /// follow it.
+    pub fn parse(&self, s: &str) -> Option<Version> {
+        match self {
+            Self::Semver => Version::parse(s).ok(),
+            Self::CalVer(format) => format.parse(s),
+        }
     }
 }
 
+/// Get the current version from the latest tag matching `tags`.
+///
+/// Returns `None` if no such tag exists (first release).
+pub fn current_version_from_tags(tags: &TagTemplate) -> VersionResult<Option<Version>> {
+    Ok(tags.latest()?.map(|(_, version)| version))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +278,52 @@ mod tests {
         assert!(parse_version("not-a-version").is_err());
     }
 
+    #[test]
+    fn bump_promotes_prerelease() {
+        let rc = parse_version("1.3.0-rc.2").unwrap();
+        assert_eq!(next_version(&rc, BumpLevel::Patch), Version::new(1, 3, 0));
+        assert_eq!(next_version(&rc, BumpLevel::Minor), Version::new(1, 3, 0));
+        assert_eq!(next_version(&rc, BumpLevel::Major), Version::new(2, 0, 0));
+
+        let beta = parse_version("1.3.1-beta.1").unwrap();
+        assert_eq!(next_version(&beta, BumpLevel::Minor), Version::new(1, 4, 0));
+    }
+
+    #[test]
+    fn prerelease_channels() {
+        let pre = |current: &str, release: &str, channel: &str| {
+            let current = parse_version(current).unwrap();
+            next_prerelease(Some(&current), &parse_version(release).unwrap(), channel)
+                .map(|v| v.to_string())
+        };
+        // Start a cycle, continue it, move to a later channel
+        assert_eq!(pre("1.2.4", "1.3.0", "rc").unwrap(), "1.3.0-rc.1");
+        assert_eq!(pre("1.3.0-rc.1", "1.3.0", "rc").unwrap(), "1.3.0-rc.2");
+        assert_eq!(pre("1.3.0-rc.9", "1.3.0", "rc").unwrap(), "1.3.0-rc.10");
+        assert_eq!(pre("1.3.0-beta.3", "1.3.0", "rc").unwrap(), "1.3.0-rc.1");
+        // A pre-release of a different version starts a new cycle
+        assert_eq!(
+            pre("1.3.0-rc.2", "2.0.0", "alpha").unwrap(),
+            "2.0.0-alpha.1"
+        );
+        assert_eq!(
+            next_prerelease(None, &Version::new(0, 1, 0), "beta")
+                .unwrap()
+                .to_string(),
+            "0.1.0-beta.1"
+        );
+
+        let err = pre("1.3.0-rc.1", "1.3.0", "beta").unwrap_err();
+        assert!(
+            matches!(err, VersionError::ChannelRegression { .. }),
+            "{err}"
+        );
+        assert!(matches!(
+            pre("1.2.4", "1.3.0", "rc.1").unwrap_err(),
+            VersionError::InvalidChannel(_)
+        ));
+    }
+
     #[test]
     fn bump_from_zero() {
         let v = Version::new(0, 1, 0);
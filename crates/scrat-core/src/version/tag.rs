@@ -0,0 +1,261 @@
+//! Tag naming.
+//!
+//! A [`TagTemplate`] turns a version into a tag name (`v{version}`,
+//! `release-{version}`, `mytool@{version}`) and back. Tag lookups only
+//! consider tags that match the template, so a repository can carry other
+//! tags alongside its release tags.
+//!
+//! The previous release is the latest matching tag reachable from `HEAD`;
+//! on a maintenance branch it must also belong to the branch's
+//! [line](VersionLine).
+
+use semver::Version;
+
+use crate::config::Config;
+use crate::package::PackageScope;
+use crate::version::line::VersionLine;
+use crate::version::{VersionError, VersionResult, VersionScheme};
+
+/// The placeholder a tag template must contain exactly once.
+const PLACEHOLDER: &str = "{version}";
+
+/// A tag naming scheme: the text around `{version}`, and how the version
+/// itself is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagTemplate {
+    prefix: String,
+    suffix: String,
+    scheme: VersionScheme,
+    line: Option<VersionLine>,
+}
+
+/// `v{version}`, used when `release.tag_template` is not set.
+impl Default for TagTemplate {
+    fn default() -> Self {
+        Self::with_prefix("v")
+    }
+}
+
+impl TagTemplate {
+    /// Parse a template such as `"release-{version}"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersionError::InvalidTagTemplate`] unless `{version}`
+    /// appears exactly once.
+    pub fn new(template: &str) -> VersionResult<Self> {
+        match template.split_once(PLACEHOLDER) {
+            Some((prefix, suffix)) if !suffix.contains(PLACEHOLDER) => Ok(Self {
+                prefix: prefix.into(),
+                suffix: suffix.into(),
+                scheme: VersionScheme::Semver,
+                line: None,
+            }),
+            _ => Err(VersionError::InvalidTagTemplate(template.into())),
+        }
+    }
+
+    /// A template with only a prefix (`"scrat-core-v"` → `scrat-core-v1.2.3`).
+    pub fn with_prefix(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.into(),
+            suffix: String::new(),
+            scheme: VersionScheme::Semver,
+            line: None,
+        }
+    }
+
+    /// Write the version in `scheme` instead of semver.
+    #[must_use]
+    pub fn with_scheme(mut self, scheme: VersionScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// How the version is written in the tags.
+    pub const fn scheme(&self) -> &VersionScheme {
+        &self.scheme
+    }
+
+    /// Only count tags on `line` as releases.
+    #[must_use]
+    pub const fn with_line(mut self, line: Option<VersionLine>) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// The maintenance line the releases are restricted to, if any.
+    pub const fn line(&self) -> Option<&VersionLine> {
+        self.line.as_ref()
+    }
+
+    /// The template for the repository (`release.tag_template`) or for a
+    /// monorepo package (its tag prefix), in the configured
+    /// [`VersionScheme`], restricted to the current branch's
+    /// [line](VersionLine::current) on a maintenance branch.
+    pub fn resolve(config: &Config, package: Option<&PackageScope>) -> VersionResult<Self> {
+        let tags = match package {
+            Some(package) => package.tags(),
+            None => config
+                .release
+                .as_ref()
+                .and_then(|r| r.tag_template.as_deref())
+                .map_or_else(|| Ok(Self::default()), Self::new)?,
+        };
+        Ok(tags
+            .with_scheme(VersionScheme::from_config(config)?)
+            .with_line(VersionLine::current(config)?))
+    }
+
+    /// The tag for `version`.
+    pub fn format(&self, version: &Version) -> String {
+        format!(
+            "{}{}{}",
+            self.prefix,
+            self.scheme.format(version),
+            self.suffix
+        )
+    }
+
+    /// The version a tag names, or `None` if the tag does not match.
+    pub fn parse(&self, tag: &str) -> Option<Version> {
+        let version = tag.strip_prefix(&self.prefix)?.strip_suffix(&self.suffix)?;
+        self.scheme.parse(version)
+    }
+
+    /// Glob for `git tag --list` that covers every matching tag.
+    pub fn glob(&self) -> String {
+        format!("{}*{}", self.prefix, self.suffix)
+    }
+
+    /// Regex matching the tags, for `git-cliff --tag-pattern`. On a
+    /// maintenance line, only the line's tags match.
+    pub fn pattern(&self) -> String {
+        let start = self
+            .line
+            .map_or_else(|| "[0-9]".to_string(), |line| escape(&line.prefix()));
+        let mut pattern = format!("^{}{start}", escape(&self.prefix));
+        if !self.suffix.is_empty() {
+            pattern.push_str(".*");
+            pattern.push_str(&escape(&self.suffix));
+            pattern.push('$');
+        }
+        pattern
+    }
+
+    /// The previous release: the latest tag matching the template that is
+    /// reachable from `HEAD` and on the [line](Self::line), with its
+    /// version, by semver precedence. Returns `None` if no tag matches
+    /// (first release).
+    pub fn latest(&self) -> VersionResult<Option<(String, Version)>> {
+        let tags = crate::git::list_reachable_tags(&self.glob())?;
+        Ok(self.newest(tags, true))
+    }
+
+    /// The newest release anywhere in the repository, on any branch or
+    /// line.
+    pub fn newest_anywhere(&self) -> VersionResult<Option<(String, Version)>> {
+        let tags = crate::git::list_tags(&self.glob())?;
+        Ok(self.newest(tags, false))
+    }
+
+    /// The matching tag with the highest version, on the line if
+    /// `in_line`.
+    fn newest(&self, tags: Vec<String>, in_line: bool) -> Option<(String, Version)> {
+        tags.into_iter()
+            .filter_map(|tag| self.parse(&tag).map(|version| (tag, version)))
+            .filter(|(_, version)| !in_line || self.line.is_none_or(|l| l.contains(version)))
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+    }
+}
+
+/// Escape regex metacharacters in literal tag text.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl std::fmt::Display for TagTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{PLACEHOLDER}{}", self.prefix, self.suffix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::version::calver::CalVerFormat;
+
+    #[test]
+    #[expect(clippy::literal_string_with_formatting_args)]
+    fn templates_round_trip() {
+        let version = Version::parse("1.2.3-rc.1").unwrap();
+        for (template, tag) in [
+            ("v{version}", "v1.2.3-rc.1"),
+            ("{version}", "1.2.3-rc.1"),
+            ("release-{version}", "release-1.2.3-rc.1"),
+            ("mytool@{version}", "mytool@1.2.3-rc.1"),
+            ("{version}-final", "1.2.3-rc.1-final"),
+        ] {
+            let tags = TagTemplate::new(template).unwrap();
+            assert_eq!(tags.to_string(), template);
+            assert_eq!(tags.format(&version), tag);
+            assert_eq!(tags.parse(tag), Some(version.clone()), "{template}");
+        }
+    }
+
+    #[test]
+    fn non_matching_tags_are_ignored() {
+        let tags = TagTemplate::new("release-{version}").unwrap();
+        assert_eq!(tags.parse("v1.2.3"), None);
+        assert_eq!(tags.parse("release-candidate"), None);
+        assert_eq!(TagTemplate::default().parse("1.2.3"), None);
+        assert_eq!(TagTemplate::new("{version}").unwrap().parse("v1.2.3"), None);
+    }
+
+    #[test]
+    fn invalid_templates() {
+        assert!(TagTemplate::new("v1").is_err());
+        assert!(TagTemplate::new("{version}-{version}").is_err());
+    }
+
+    #[test]
+    fn calver_tags() {
+        let format = CalVerFormat::new("YY.0W.MICRO").unwrap();
+        let tags = TagTemplate::default().with_scheme(VersionScheme::CalVer(format));
+        let version = Version::new(26, 5, 1);
+        assert_eq!(tags.format(&version), "v26.05.1");
+        assert_eq!(tags.parse("v26.05.1"), Some(version));
+        assert_eq!(tags.parse("v1.2.3"), None);
+    }
+
+    #[test]
+    fn globs_and_patterns() {
+        let tags = TagTemplate::new("mytool@{version}").unwrap();
+        assert_eq!(tags.glob(), "mytool@*");
+        assert_eq!(tags.pattern(), "^mytool@[0-9]");
+
+        let tags = TagTemplate::new("{version}.final").unwrap();
+        assert_eq!(tags.glob(), "*.final");
+        assert_eq!(tags.pattern(), r"^[0-9].*\.final$");
+
+        let tags = TagTemplate::with_prefix("@acme/web.v");
+        assert_eq!(tags.pattern(), r"^@acme/web\.v[0-9]");
+    }
+
+    #[test]
+    fn line_patterns() {
+        let line = VersionLine::from_branch("release/1.4.x");
+        let tags = TagTemplate::default().with_line(line);
+        assert_eq!(tags.pattern(), r"^v1\.4\.");
+        assert_eq!(tags.glob(), "v*");
+        // Parsing is not restricted: any tag still names its version
+        assert_eq!(tags.parse("v2.0.0"), Some(Version::new(2, 0, 0)));
+    }
+}
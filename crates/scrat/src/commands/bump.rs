@@ -6,8 +6,10 @@ use inquire::Select;
 use owo_colors::OwoColorize;
 use tracing::{debug, instrument};
 
-use scrat_core::bump::{self, BumpError, BumpPlan, InteractiveBump};
+use scrat_core::bump::{self, BumpError, BumpOptions, BumpPlan, InteractiveBump, SyncAction};
 use scrat_core::config::{Config, ProjectConfig};
+use scrat_core::detect;
+use scrat_core::version::BumpLevel;
 
 /// Arguments for the `bump` subcommand.
 #[derive(Args, Debug, Default)]
@@ -16,6 +18,14 @@ pub struct BumpArgs {
     #[arg(long, value_name = "VERSION")]
     pub version: Option<String>,
 
+    /// Release a pre-release on CHANNEL (e.g. "rc": 1.3.0-rc.1, then 1.3.0-rc.2)
+    #[arg(long, value_name = "CHANNEL", conflicts_with = "version")]
+    pub pre: Option<String>,
+
+    /// Force a bump level instead of the computed one (the base is still the latest tag)
+    #[arg(long, value_name = "LEVEL", conflicts_with = "version")]
+    pub bump: Option<BumpLevel>,
+
     /// Skip changelog generation
     #[arg(long)]
     pub no_changelog: bool,
@@ -23,6 +33,16 @@ pub struct BumpArgs {
     /// Run without making changes (show what would happen)
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Bump a single monorepo package (default: every changed package
+    /// when `[[packages]]` is configured)
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
+
+    /// Repair drift between the manifest and the latest tag instead of bumping
+    /// (rewrite a stale manifest, or create a missing tag)
+    #[arg(long, conflicts_with_all = ["version", "pre", "bump", "package"])]
+    pub sync: bool,
 }
 
 /// Execute the bump command.
@@ -35,18 +55,75 @@ pub fn cmd_bump(
 ) -> anyhow::Result<()> {
     debug!(json_output = global_json, "executing bump command");
 
+    if args.sync {
+        return sync(&args, global_json, config, cwd);
+    }
+
+    let options = BumpOptions {
+        explicit_version: args.version.clone(),
+        pre: args.pre.clone(),
+        bump: args.bump,
+        package: None,
+    };
+
+    let Some(packages) = super::resolve_target_packages(config, cwd, args.package.as_deref())?
+    else {
+        return bump_one(&args, options, global_json, config, cwd);
+    };
+
+    if packages.is_empty() {
+        println!(
+            "{}",
+            "No packages changed since their last release.".yellow()
+        );
+        return Ok(());
+    }
+    if packages.len() > 1 && args.version.is_some() {
+        bail!("--version applies to a single package — select one with --package");
+    }
+
+    for package in packages {
+        if !global_json {
+            println!("\n{}: {}", "Package".bold(), package.name.cyan().bold());
+        }
+        let options = BumpOptions {
+            package: Some(package),
+            ..options.clone()
+        };
+        bump_one(&args, options, global_json, config, cwd)?;
+    }
+
+    Ok(())
+}
+
+/// Plan and execute one bump (the repository or one package).
+fn bump_one(
+    args: &BumpArgs,
+    mut options: BumpOptions,
+    global_json: bool,
+    config: &Config,
+    cwd: &camino::Utf8Path,
+) -> anyhow::Result<()> {
     // Plan the bump (all logic in core)
     // If ecosystem detection fails, prompt the user to select one
     let mut config = config.clone();
-    let plan = match bump::plan_bump(cwd, &config, args.version.as_deref()) {
+    let plan = match bump::plan_bump(cwd, &config, &options) {
         Ok(plan) => plan,
         Err(BumpError::Detection(_)) => {
+            let dir = options
+                .package
+                .as_ref()
+                .map_or_else(|| cwd.to_owned(), |p| p.root(cwd));
             let ecosystem =
-                super::prompt_ecosystem_selection().context("ecosystem selection failed")?;
-            let project = config.project.get_or_insert_with(ProjectConfig::default);
-            project.project_type = Some(ecosystem);
-            bump::plan_bump(cwd, &config, args.version.as_deref())
-                .context("bump planning failed")?
+                super::prompt_ecosystem_selection(&detect::detect_ecosystems(&dir), global_json)
+                    .context("ecosystem selection failed")?;
+            if let Some(ref mut package) = options.package {
+                package.ecosystem = Some(ecosystem);
+            } else {
+                let project = config.project.get_or_insert_with(ProjectConfig::default);
+                project.project_type = Some(ecosystem);
+            }
+            bump::plan_bump(cwd, &config, &options).context("bump planning failed")?
         }
         Err(e) => return Err(e).context("bump planning failed"),
     };
@@ -57,17 +134,21 @@ pub fn cmd_bump(
         BumpPlan::NeedsInteraction(interactive) => {
             let chosen = prompt_interactive_version(&interactive)
                 .context("interactive version selection failed")?;
-            bump::resolve_interactive(interactive, chosen)
+            let ready = bump::resolve_interactive(interactive, chosen);
+            ready.check_line().context("bump planning failed")?;
+            ready
         }
     };
 
     // Display the plan
     if global_json {
         let plan_json = serde_json::json!({
-            "previous": ready.previous.to_string(),
-            "next": ready.next.to_string(),
+            "previous": ready.previous_version(),
+            "next": ready.version(),
             "strategy": ready.strategy.to_string(),
+            "forced_level": ready.forced,
             "ecosystem": ready.detection.ecosystem.to_string(),
+            "package": ready.package.as_ref().map(|p| p.name.as_str()),
             "dry_run": args.dry_run,
         });
         if args.dry_run {
@@ -78,10 +159,14 @@ pub fn cmd_bump(
         println!(
             "{}: {} → {}",
             "Version".bold(),
-            ready.previous.to_string().dimmed(),
-            ready.next.to_string().green().bold()
+            ready.previous_version().dimmed(),
+            ready.version().green().bold()
+        );
+        let strategy = ready.forced.map_or_else(
+            || ready.strategy.to_string(),
+            |level| format!("{} (forced {level})", ready.strategy),
         );
-        println!("{}: {}", "Strategy".dimmed(), ready.strategy);
+        println!("{}: {}", "Strategy".dimmed(), strategy);
         println!("{}: {}", "Ecosystem".dimmed(), ready.detection.ecosystem);
 
         if args.dry_run {
@@ -104,7 +189,7 @@ pub fn cmd_bump(
         println!(
             "  {} Version updated to {}",
             "✓".green(),
-            outcome.new.to_string().green().bold()
+            ready.version().green().bold()
         );
         if outcome.changelog_updated {
             println!("  {} Changelog updated", "✓".green());
@@ -117,6 +202,56 @@ pub fn cmd_bump(
     Ok(())
 }
 
+/// Repair manifest/tag drift (`--sync`).
+fn sync(
+    args: &BumpArgs,
+    global_json: bool,
+    config: &Config,
+    cwd: &camino::Utf8Path,
+) -> anyhow::Result<()> {
+    let outcome = bump::sync_version(cwd, config, args.dry_run).context("sync failed")?;
+
+    if global_json {
+        println!("{}", serde_json::to_string_pretty(&outcome)?);
+        return Ok(());
+    }
+
+    if let Some(ref drift) = outcome.drift {
+        println!("{}: {}", "Drift".bold(), drift.message());
+    }
+    let verb = |done: &'static str, planned: &'static str| {
+        if args.dry_run { planned } else { done }
+    };
+    match outcome.action {
+        SyncAction::None => println!("  {} Nothing to sync", "✓".green()),
+        SyncAction::RewroteManifest {
+            ref version,
+            ref modified_files,
+        } => {
+            println!(
+                "  {} {} {}",
+                "✓".green(),
+                verb("Manifest updated to", "Would update the manifest to"),
+                version.green().bold()
+            );
+            for file in modified_files {
+                println!("  {} {}", "→".dimmed(), file.cyan());
+            }
+        }
+        SyncAction::CreatedTag { ref tag } => println!(
+            "  {} {} {}",
+            "✓".green(),
+            verb("Created tag", "Would create tag"),
+            tag.green().bold()
+        ),
+    }
+    if args.dry_run {
+        println!();
+        println!("{}", "Dry run — no changes made.".yellow());
+    }
+    Ok(())
+}
+
 /// Display interactive context and prompt the user to pick a version.
 fn prompt_interactive_version(
     plan: &InteractiveBump,
@@ -150,20 +285,26 @@ fn prompt_interactive_version(
         );
     }
 
+    if let Some(level) = ctx.suggested {
+        println!(
+            "{}: {}",
+            "Suggested by commits".dimmed(),
+            level.to_string().green()
+        );
+    }
+
     // Build selection options
-    let options: Vec<String> = ctx
-        .candidates
-        .iter()
-        .map(|c| format!("{} ({})", c.version, c.level))
-        .collect();
+    let options: Vec<String> = ctx.candidates.iter().map(ToString::to_string).collect();
 
     if options.is_empty() {
         bail!("no version candidates available");
     }
 
-    let selection = Select::new("Select version:", options)
-        .prompt()
-        .context("version selection cancelled")?;
+    let mut select = Select::new("Select version:", options);
+    if let Some(index) = ctx.suggested_index() {
+        select = select.with_starting_cursor(index);
+    }
+    let selection = select.prompt().context("version selection cancelled")?;
 
     // Parse the version back from the selection
     let version_str = selection
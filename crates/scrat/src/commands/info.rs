@@ -6,8 +6,9 @@ use serde::Serialize;
 use tracing::{debug, instrument};
 
 use scrat_core::config::{self, Config};
-use scrat_core::detect;
+use scrat_core::detect::{self, EcosystemMatch};
 use scrat_core::ecosystem::ProjectDetection;
+use scrat_core::manifest;
 
 /// Arguments for the `info` subcommand.
 #[derive(Args, Debug, Default)]
@@ -68,6 +69,9 @@ struct FullInfo {
     config: ConfigInfo,
     #[serde(skip_serializing_if = "Option::is_none")]
     detection: Option<ProjectDetection>,
+    ecosystems: Vec<EcosystemMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project_version: Option<String>,
 }
 
 /// Print package information.
@@ -89,11 +93,16 @@ pub fn cmd_info(
 
     let config_info = ConfigInfo::from_config(config, cwd);
     let detection = detect::resolve_detection(cwd, config);
+    let project_version = detection
+        .as_ref()
+        .and_then(|d| manifest::current_version(cwd, d.ecosystem, config));
 
     let full_info = FullInfo {
         package: info,
         config: config_info,
         detection: detection.clone(),
+        ecosystems: detect::detect_ecosystems(cwd),
+        project_version,
     };
 
     if global_json {
@@ -150,6 +159,12 @@ pub fn cmd_info(
                 "Ecosystem".dimmed(),
                 det.ecosystem.to_string().cyan()
             );
+            if full_info.ecosystems.len() > 1 {
+                print_ecosystems(&full_info.ecosystems);
+            }
+            if let Some(ref version) = full_info.project_version {
+                println!("{}: {}", "Project version".dimmed(), version.cyan());
+            }
             println!(
                 "{}: {}",
                 "Version strategy".dimmed(),
@@ -170,6 +185,13 @@ pub fn cmd_info(
             if let Some(ref tool) = det.tools.changelog_tool {
                 println!("{}: {}", "Changelog tool".dimmed(), tool.to_string().cyan());
             }
+        } else if full_info.ecosystems.len() > 1 {
+            println!(
+                "  {} {}",
+                "○".yellow(),
+                "Several project types detected; set project.type to choose one".yellow()
+            );
+            print_ecosystems(&full_info.ecosystems);
         } else {
             println!(
                 "  {} {}",
@@ -182,6 +204,14 @@ pub fn cmd_info(
     Ok(())
 }
 
+/// List every detected ecosystem with the files that identified it.
+fn print_ecosystems(ecosystems: &[EcosystemMatch]) {
+    println!("{}:", "Ecosystems found".dimmed());
+    for found in ecosystems {
+        println!("  {} {}", "•".dimmed(), found.to_string().cyan());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,7 +249,7 @@ fn prompt_ecosystem(plan: &init::InitPlan) -> anyhow::Result<Option<Ecosystem>>
         }
 
         // User said no — let them pick
-        let eco = super::prompt_ecosystem_selection()?;
+        let eco = super::prompt_ecosystem_selection(&[], false)?;
         return Ok(Some(eco));
     }
 
@@ -258,6 +258,12 @@ fn prompt_ecosystem(plan: &init::InitPlan) -> anyhow::Result<Option<Ecosystem>>
         "Generic (no ecosystem-specific behavior)".to_string(),
         "Rust".to_string(),
         "Node".to_string(),
+        "Python".to_string(),
+        "Go".to_string(),
+        "Maven".to_string(),
+        "Gradle".to_string(),
+        "Ruby".to_string(),
+        "PHP".to_string(),
         "Skip (omit from config)".to_string(),
     ];
 
@@ -270,6 +276,12 @@ fn prompt_ecosystem(plan: &init::InitPlan) -> anyhow::Result<Option<Ecosystem>>
         s if s.starts_with("Generic") => Ok(Some(Ecosystem::Generic)),
         "Rust" => Ok(Some(Ecosystem::Rust)),
         "Node" => Ok(Some(Ecosystem::Node)),
+        "Python" => Ok(Some(Ecosystem::Python)),
+        "Go" => Ok(Some(Ecosystem::Go)),
+        "Maven" => Ok(Some(Ecosystem::Maven)),
+        "Gradle" => Ok(Some(Ecosystem::Gradle)),
+        "Ruby" => Ok(Some(Ecosystem::Ruby)),
+        "PHP" => Ok(Some(Ecosystem::Php)),
         s if s.starts_with("Skip") => Ok(None),
         _ => bail!("unexpected selection: {selection}"),
     }
@@ -14,45 +14,120 @@ pub mod preflight;
 
 pub mod ship;
 
+pub mod version;
+
 use anyhow::Context;
 use inquire::Select;
 use owo_colors::OwoColorize;
+use scrat_core::config::Config;
+use scrat_core::detect::EcosystemMatch;
 use scrat_core::ecosystem::Ecosystem;
+use scrat_core::package::{self, PackageScope};
 
 /// Prompt the user to select an ecosystem when auto-detection fails.
 ///
+/// `candidates` are the ecosystems found when detection was ambiguous; they
+/// are offered first. With no candidates, every built-in ecosystem is offered.
+///
+/// Without a terminal to prompt on (CI, piped input) or with `global_json`,
+/// fails with an error naming the candidates and the `project.type` key.
+///
 /// Shared across commands that need ecosystem detection (ship, bump, preflight).
-pub fn prompt_ecosystem_selection() -> anyhow::Result<Ecosystem> {
-    println!(
-        "\n{}",
-        "Could not auto-detect project type.".yellow().bold()
-    );
-    println!(
-        "{}",
-        "No Cargo.toml, package.json, or other marker file found.".dimmed()
-    );
+pub fn prompt_ecosystem_selection(
+    candidates: &[EcosystemMatch],
+    global_json: bool,
+) -> anyhow::Result<Ecosystem> {
+    if global_json || !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+        if candidates.is_empty() {
+            anyhow::bail!("could not detect the project type; set project.type in config");
+        }
+        let found: Vec<String> = candidates.iter().map(ToString::to_string).collect();
+        anyhow::bail!(
+            "several project types detected: {}; set project.type in config to choose one",
+            found.join("; ")
+        );
+    }
+
+    let mut choices: Vec<Option<Ecosystem>> = Vec::new();
+    let mut options: Vec<String> = Vec::new();
+
+    if candidates.is_empty() {
+        println!(
+            "\n{}",
+            "Could not auto-detect project type.".yellow().bold()
+        );
+        println!(
+            "{}",
+            "No Cargo.toml, package.json, pyproject.toml, go.mod, pom.xml, build.gradle, *.gemspec, composer.json, or other marker file found."
+                .dimmed()
+        );
+        for (ecosystem, label) in [
+            (
+                Ecosystem::Generic,
+                "Generic (no ecosystem-specific behavior)",
+            ),
+            (Ecosystem::Rust, "Rust"),
+            (Ecosystem::Node, "Node"),
+            (Ecosystem::Python, "Python"),
+            (Ecosystem::Go, "Go"),
+            (Ecosystem::Maven, "Maven"),
+            (Ecosystem::Gradle, "Gradle"),
+            (Ecosystem::Ruby, "Ruby"),
+            (Ecosystem::Php, "PHP"),
+        ] {
+            choices.push(Some(ecosystem));
+            options.push(label.to_string());
+        }
+    } else {
+        println!("\n{}", "Several project types detected:".yellow().bold());
+        for candidate in candidates {
+            println!("  {} {candidate}", "•".dimmed());
+            choices.push(Some(candidate.ecosystem));
+            options.push(candidate.to_string());
+        }
+        println!(
+            "{}",
+            "Set project.type in config to skip this prompt.".dimmed()
+        );
+        choices.push(Some(Ecosystem::Generic));
+        options.push("generic (no ecosystem-specific behavior)".to_string());
+    }
     println!();
 
-    let options = vec![
-        "Generic (no ecosystem-specific behavior)".to_string(),
-        "Rust".to_string(),
-        "Node".to_string(),
-        "Exit".to_string(),
-    ];
+    choices.push(None);
+    options.push("Exit".to_string());
 
     let selection = Select::new("Select project ecosystem:", options)
         .with_starting_cursor(0)
-        .prompt()
+        .raw_prompt()
         .context("ecosystem selection cancelled")?;
 
-    match selection.as_str() {
-        s if s.starts_with("Generic") => Ok(Ecosystem::Generic),
-        "Rust" => Ok(Ecosystem::Rust),
-        "Node" => Ok(Ecosystem::Node),
-        "Exit" => {
+    choices[selection.index].map_or_else(
+        || {
             println!("{}", "Cancelled.".yellow());
             std::process::exit(0);
-        }
-        _ => anyhow::bail!("unexpected selection: {selection}"),
+        },
+        Ok,
+    )
+}
+
+/// Resolve which monorepo packages a release command targets.
+///
+/// - `--package <name>` → just that package
+/// - `[[packages]]` configured → every package changed since its last tag
+/// - otherwise → `None` (release the repository as a whole)
+pub fn resolve_target_packages(
+    config: &Config,
+    cwd: &camino::Utf8Path,
+    name: Option<&str>,
+) -> anyhow::Result<Option<Vec<PackageScope>>> {
+    if let Some(name) = name {
+        return Ok(Some(vec![package::find_package(cwd, config, name)?]));
+    }
+    if package::configured_packages(cwd, config).is_empty() {
+        return Ok(None);
     }
+    let changed =
+        package::changed_packages(cwd, config).context("failed to find changed packages")?;
+    Ok(Some(changed))
 }
@@ -6,6 +6,8 @@ use tracing::{debug, instrument};
 
 use scrat_core::config::Config;
 use scrat_core::notes::{self, PreviewNotesOptions};
+use scrat_core::package;
+use scrat_core::version::BumpLevel;
 
 /// Arguments for the `notes` subcommand.
 #[derive(Args, Debug, Default)]
@@ -18,6 +20,10 @@ pub struct NotesArgs {
     #[arg(long, value_name = "VERSION")]
     pub version: Option<String>,
 
+    /// Render notes for the latest tag bumped by LEVEL instead of the version in project files
+    #[arg(long, value_name = "LEVEL", conflicts_with = "version")]
+    pub bump: Option<BumpLevel>,
+
     /// Path to a custom git-cliff template (overrides config and built-in)
     #[arg(long, value_name = "FILE")]
     pub template: Option<String>,
@@ -29,6 +35,10 @@ pub struct NotesArgs {
     /// Skip stats collection in rendered notes
     #[arg(long)]
     pub no_stats: bool,
+
+    /// Render notes for a single monorepo package
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
 }
 
 /// Execute the notes command.
@@ -44,9 +54,15 @@ pub fn cmd_notes(
     let options = PreviewNotesOptions {
         from: args.from,
         version: args.version,
+        bump: args.bump,
         template: args.template,
         no_deps: args.no_deps,
         no_stats: args.no_stats,
+        package: args
+            .package
+            .as_deref()
+            .map(|name| package::find_package(cwd, config, name))
+            .transpose()?,
     };
 
     let result =
@@ -28,7 +28,7 @@ pub fn cmd_preflight(
 
     // If no ecosystem detected and not in JSON mode, prompt the user
     if report.detection.is_none() && !global_json {
-        match super::prompt_ecosystem_selection() {
+        match super::prompt_ecosystem_selection(&report.ecosystems, global_json) {
             Ok(ecosystem) => {
                 // Re-run preflight with the user's ecosystem choice
                 let project = config.project.get_or_insert_with(ProjectConfig::default);
@@ -48,7 +48,9 @@ pub fn cmd_preflight(
         println!();
 
         for check in &report.checks {
-            let icon = if check.passed {
+            let icon = if check.warning {
+                "!".yellow().to_string()
+            } else if check.passed {
                 "✓".green().to_string()
             } else {
                 "✗".red().to_string()
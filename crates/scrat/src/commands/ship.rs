@@ -8,7 +8,8 @@ use owo_colors::OwoColorize;
 use tracing::{debug, instrument};
 
 use scrat_core::config::Config;
-use scrat_core::ship::{self, PhaseOutcome, ShipEvent, ShipOptions, ShipPlan};
+use scrat_core::ship::{self, PhaseOutcome, ShipEvent, ShipOptions, ShipOutcome, ShipPlan};
+use scrat_core::version::BumpLevel;
 
 /// Arguments for the `ship` subcommand.
 #[derive(Args, Debug, Default)]
@@ -17,6 +18,18 @@ pub struct ShipArgs {
     #[arg(long, value_name = "VERSION")]
     pub version: Option<String>,
 
+    /// Release a pre-release on CHANNEL (e.g. "rc": 1.3.0-rc.1, then 1.3.0-rc.2)
+    #[arg(long, value_name = "CHANNEL", conflicts_with = "version")]
+    pub pre: Option<String>,
+
+    /// Force a bump level instead of the computed one (the base is still the latest tag)
+    #[arg(long, value_name = "LEVEL", conflicts_with = "version")]
+    pub bump: Option<BumpLevel>,
+
+    /// Allow a version lower than or equal to the previous one
+    #[arg(long)]
+    pub allow_downgrade: bool,
+
     /// Skip changelog generation
     #[arg(long)]
     pub no_changelog: bool,
@@ -72,6 +85,11 @@ pub struct ShipArgs {
     /// Skip confirmation prompt
     #[arg(long, short = 'y')]
     pub yes: bool,
+
+    /// Release a single monorepo package (default: every changed package
+    /// when `[[packages]]` is configured)
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
 }
 
 /// Execute the ship command.
@@ -100,6 +118,9 @@ pub fn cmd_ship(
 
     let options = ShipOptions {
         explicit_version: args.version,
+        pre: args.pre,
+        bump: args.bump,
+        allow_downgrade: args.allow_downgrade,
         no_changelog: args.no_changelog,
         no_publish: args.no_publish,
         no_push: args.no_push,
@@ -112,8 +133,74 @@ pub fn cmd_ship(
         no_tag: args.no_tag,
         no_git: args.no_git,
         draft_override,
+        package: None,
+        no_preflight: false,
     };
 
+    let Some(packages) = super::resolve_target_packages(config, cwd, args.package.as_deref())?
+    else {
+        if let Some(outcome) = ship_one(options, skip_confirm, global_json, config, cwd)?
+            && global_json
+        {
+            println!("{}", serde_json::to_string_pretty(&outcome)?);
+        }
+        return Ok(());
+    };
+
+    if packages.is_empty() {
+        if global_json {
+            println!("[]");
+        } else {
+            println!(
+                "{}",
+                "No packages changed since their last release.".yellow()
+            );
+        }
+        return Ok(());
+    }
+    if packages.len() > 1 && options.explicit_version.is_some() {
+        bail!("--version applies to a single package — select one with --package");
+    }
+
+    let single = args.package.is_some();
+    let mut outcomes = Vec::new();
+    for (index, package) in packages.into_iter().enumerate() {
+        if !global_json {
+            println!("\n{}: {}", "Package".bold(), package.name.cyan().bold());
+        }
+        // Preflight runs once: an earlier package's release commit leaves
+        // the branch ahead of its remote when pushing is skipped
+        let options = ShipOptions {
+            package: Some(package),
+            no_preflight: index > 0,
+            ..options.clone()
+        };
+        outcomes.extend(ship_one(options, skip_confirm, global_json, config, cwd)?);
+    }
+
+    if global_json {
+        if single {
+            if let Some(outcome) = outcomes.first() {
+                println!("{}", serde_json::to_string_pretty(outcome)?);
+            }
+        } else {
+            println!("{}", serde_json::to_string_pretty(&outcomes)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Plan, confirm, and execute one release (the repository or one package).
+///
+/// Returns `None` if the user cancelled at the confirmation prompt.
+fn ship_one(
+    options: ShipOptions,
+    skip_confirm: bool,
+    global_json: bool,
+    config: &Config,
+    cwd: &camino::Utf8Path,
+) -> anyhow::Result<Option<ShipOutcome>> {
     let is_dry = options.dry_run;
 
     // Plan the ship (preflight + version resolution)
@@ -121,8 +208,8 @@ pub fn cmd_ship(
 
     // If ecosystem detection failed, prompt the user to select one
     if let ShipPlan::NeedsEcosystemSelection(selection) = plan {
-        let ecosystem =
-            super::prompt_ecosystem_selection().context("ecosystem selection failed")?;
+        let ecosystem = super::prompt_ecosystem_selection(&selection.candidates, global_json)
+            .context("ecosystem selection failed")?;
         plan = ship::resolve_ecosystem_selection(selection, ecosystem)
             .context("re-planning with selected ecosystem failed")?;
     }
@@ -133,7 +220,8 @@ pub fn cmd_ship(
         ShipPlan::NeedsInteraction(interactive) => {
             let chosen = prompt_interactive_version(&interactive)
                 .context("interactive version selection failed")?;
-            ship::resolve_ship_interaction(interactive, chosen)
+            ship::resolve_ship_interaction(cwd, interactive, chosen)
+                .context("ship planning failed")?
         }
         ShipPlan::NeedsEcosystemSelection(_) => {
             bail!("ecosystem selection returned NeedsEcosystemSelection again — this is a bug");
@@ -148,13 +236,17 @@ pub fn cmd_ship(
         println!(
             "\n{}: {} → {}",
             "Ship".bold(),
-            ready.bump.previous.to_string().dimmed(),
-            ready.bump.next.to_string().green().bold(),
+            ready.bump.previous_version().dimmed(),
+            ready.bump.version().green().bold(),
+        );
+        let strategy = ready.bump.forced.map_or_else(
+            || ready.bump.strategy.to_string(),
+            |level| format!("{} (forced {level})", ready.bump.strategy),
         );
         println!(
             "{}: {} | {}: {}",
             "Strategy".dimmed(),
-            ready.bump.strategy,
+            strategy,
             "Ecosystem".dimmed(),
             ready.detection.ecosystem,
         );
@@ -173,7 +265,7 @@ pub fn cmd_ship(
                 .context("confirmation prompt failed")?;
             if !confirmed {
                 println!("{}", "Ship cancelled.".yellow());
-                return Ok(());
+                return Ok(None);
             }
             println!();
         }
@@ -189,9 +281,7 @@ pub fn cmd_ship(
         .context("ship failed")?;
 
     // Display final summary
-    if global_json {
-        println!("{}", serde_json::to_string_pretty(&outcome)?);
-    } else {
+    if !global_json {
         println!();
         if is_dry {
             println!(
@@ -212,7 +302,7 @@ pub fn cmd_ship(
         }
     }
 
-    Ok(())
+    Ok(Some(outcome))
 }
 
 /// Handle a ship event for terminal progress display.
@@ -303,20 +393,26 @@ fn prompt_interactive_version(
         );
     }
 
+    if let Some(level) = ctx.suggested {
+        println!(
+            "{}: {}",
+            "Suggested by commits".dimmed(),
+            level.to_string().green()
+        );
+    }
+
     // Build selection options
-    let options: Vec<String> = ctx
-        .candidates
-        .iter()
-        .map(|c| format!("{} ({})", c.version, c.level))
-        .collect();
+    let options: Vec<String> = ctx.candidates.iter().map(ToString::to_string).collect();
 
     if options.is_empty() {
         bail!("no version candidates available");
     }
 
-    let selection = Select::new("Select version:", options)
-        .prompt()
-        .context("version selection cancelled")?;
+    let mut select = Select::new("Select version:", options);
+    if let Some(index) = ctx.suggested_index() {
+        select = select.with_starting_cursor(index);
+    }
+    let selection = select.prompt().context("version selection cancelled")?;
 
     // Parse the version back from the selection
     let version_str = selection
@@ -0,0 +1,160 @@
+//! Version command — thin CLI layer over `scrat_core::version::explain`.
+
+use anyhow::Context;
+use clap::Args;
+use owo_colors::OwoColorize;
+use tracing::{debug, instrument};
+
+use scrat_core::bump::BumpOptions;
+use scrat_core::config::Config;
+use scrat_core::package;
+use scrat_core::version::BumpLevel;
+use scrat_core::version::explain::{self, VersionExplanation};
+
+/// Arguments for the `version` subcommand.
+#[derive(Args, Debug, Default)]
+pub struct VersionArgs {
+    /// Show every commit since the last tag and the bump level it contributes
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Show the version of a single monorepo package
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
+
+    /// Show the next pre-release on CHANNEL, as `ship --pre` would release it
+    #[arg(long, value_name = "CHANNEL")]
+    pub pre: Option<String>,
+
+    /// Show the version a forced bump level leads to, as `ship --bump` would
+    #[arg(long, value_name = "LEVEL")]
+    pub bump: Option<BumpLevel>,
+}
+
+/// Execute the version command.
+#[instrument(name = "cmd_version", skip_all, fields(json_output))]
+pub fn cmd_version(
+    args: VersionArgs,
+    global_json: bool,
+    config: &Config,
+    cwd: &camino::Utf8Path,
+) -> anyhow::Result<()> {
+    debug!(json_output = global_json, "executing version command");
+
+    let package = args
+        .package
+        .as_deref()
+        .map(|name| package::find_package(cwd, config, name))
+        .transpose()?;
+    let options = BumpOptions {
+        package,
+        pre: args.pre,
+        bump: args.bump,
+        ..BumpOptions::default()
+    };
+    let explanation =
+        explain::explain_version(cwd, config, &options).context("failed to analyze commits")?;
+
+    if global_json {
+        println!("{}", serde_json::to_string_pretty(&explanation)?);
+        return Ok(());
+    }
+
+    print_summary(&explanation);
+    if args.explain {
+        print_commits(&explanation);
+    }
+    Ok(())
+}
+
+/// Current version, its source, and the next version.
+fn print_summary(explanation: &VersionExplanation) {
+    let current = explanation
+        .current
+        .as_deref()
+        .unwrap_or("none (first release)");
+    let source = explanation.tag.as_ref().map_or_else(
+        || explanation.source.to_string(),
+        |tag| format!("tag {tag}"),
+    );
+    println!(
+        "{}: {} {}",
+        "Current version".bold(),
+        current,
+        format!("(from {source})").dimmed()
+    );
+    if let Some(ref manifest) = explanation.manifest_version
+        && explanation.current.as_deref() != Some(manifest.as_str())
+    {
+        println!("{}: {}", "Manifest version".dimmed(), manifest.yellow());
+    }
+    match (&explanation.next, explanation.level) {
+        (Some(next), level) => {
+            let how = explanation.strategy.as_ref().map_or_else(
+                || level.map_or_else(String::new, |l| l.to_string()),
+                ToString::to_string,
+            );
+            println!(
+                "{}: {} {}",
+                "Next version".bold(),
+                next.green().bold(),
+                format!("({how})").dimmed()
+            );
+        }
+        (None, Some(level)) => println!("{}: {}", "Bump level".bold(), level.green()),
+        _ => println!("{}", "No releasable commits since the last tag.".yellow()),
+    }
+    if explanation.differs_from_builtin()
+        && let (Some(builtin), Some(level)) = (&explanation.builtin_next, explanation.level)
+    {
+        println!(
+            "{}: {} {}",
+            "From the commits alone".dimmed(),
+            builtin.yellow(),
+            format!("({level}, built-in analyzer)").dimmed()
+        );
+    }
+}
+
+/// Every commit with its classification, and the one that set the level.
+fn print_commits(explanation: &VersionExplanation) {
+    println!();
+    if explanation.commits.is_empty() {
+        println!("{}", "No commits since the last tag.".yellow());
+        return;
+    }
+    println!("{}", "Commits:".bold().underline());
+    for commit in &explanation.commits {
+        let short = &commit.id[..commit.id.len().min(7)];
+        let kind = match (&commit.kind, &commit.scope) {
+            (Some(kind), Some(scope)) => format!("{kind}({scope})"),
+            (Some(kind), None) => kind.clone(),
+            (None, _) => "—".to_string(),
+        };
+        let breaking = if commit.breaking { " breaking" } else { "" };
+        let level = commit.level.map_or_else(
+            || format!("{:<5}", "none").dimmed().to_string(),
+            |l| format!("{:<5}", l.to_string()).green().to_string(),
+        );
+        let marker = if explanation.decided_by.as_deref() == Some(commit.id.as_str()) {
+            "→".green().to_string()
+        } else {
+            " ".to_string()
+        };
+        println!(
+            "  {marker} {} {level} {}{} {}",
+            short.dimmed(),
+            kind.cyan(),
+            breaking.red(),
+            commit.subject
+        );
+    }
+    if let (Some(id), Some(level)) = (&explanation.decided_by, explanation.level) {
+        println!();
+        println!(
+            "{}: {} set the {level} level",
+            "Decided by".dimmed(),
+            &id[..id.len().min(7)]
+        );
+    }
+}
@@ -115,6 +115,9 @@ pub enum Commands {
 
     /// Run the full release workflow
     Ship(commands::ship::ShipArgs),
+
+    /// Show the current and next version (--explain: why)
+    Version(commands::version::VersionArgs),
 }
 
 /// Returns the clap command for documentation generation
@@ -86,6 +86,7 @@ fn main() -> anyhow::Result<()> {
         Commands::Bump(args) => commands::bump::cmd_bump(args, cli.json, &config, &cwd),
         Commands::Notes(args) => commands::notes::cmd_notes(args, cli.json, &config, &cwd),
         Commands::Ship(args) => commands::ship::cmd_ship(args, cli.json, &config, &cwd),
+        Commands::Version(args) => commands::version::cmd_version(args, cli.json, &config, &cwd),
     };
     if let Err(ref err) = result {
         tracing::error!(error = %err, "fatal error");
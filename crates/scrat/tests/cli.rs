@@ -115,6 +115,60 @@ fn info_help_shows_command_options() {
         .stdout(predicate::str::contains("--json"));
 }
 
+/// Run `info --json` in a fixture project and return the parsed output.
+fn info_json_in(files: &[(&str, &str)]) -> serde_json::Value {
+    let tmp = tempfile::TempDir::new().unwrap();
+    for (path, content) in files {
+        let path = tmp.path().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    let output = cmd()
+        .arg("-C")
+        .arg(tmp.path())
+        .args(["info", "--json"])
+        .assert()
+        .success();
+    serde_json::from_slice(&output.get_output().stdout).expect("info --json should output JSON")
+}
+
+#[test]
+fn info_detects_ruby_gem_fixture() {
+    let json = info_json_in(&[
+        (
+            "widgets.gemspec",
+            "Gem::Specification.new do |spec|\n  spec.name = \"widgets\"\nend\n",
+        ),
+        (
+            "lib/widgets/version.rb",
+            "module Widgets\n  VERSION = \"0.3.0\"\nend\n",
+        ),
+        ("Gemfile.lock", "GEM\n  specs:\n"),
+    ]);
+    assert_eq!(json["detection"]["ecosystem"], "ruby");
+    assert_eq!(json["project_version"], "0.3.0");
+    assert_eq!(
+        json["detection"]["tools"]["build_cmd"],
+        "gem build widgets.gemspec"
+    );
+    assert_eq!(
+        json["ecosystems"][0]["evidence"],
+        serde_json::json!(["widgets.gemspec", "Gemfile.lock"])
+    );
+}
+
+#[test]
+fn info_detects_composer_fixture() {
+    let json = info_json_in(&[
+        ("composer.json", "{\n    \"name\": \"acme/widgets\"\n}\n"),
+        ("composer.lock", "{}\n"),
+    ]);
+    assert_eq!(json["detection"]["ecosystem"], "php");
+    assert_eq!(json["detection"]["tools"]["test_cmd"], "composer test");
+    assert!(json["detection"]["tools"]["publish_cmd"].is_null());
+    assert!(json.get("project_version").is_none());
+}
+
 // =============================================================================
 // Global Flags
 // =============================================================================
@@ -229,7 +283,8 @@ fn ship_help_shows_usage() {
         .stdout(predicate::str::contains("--no-git"))
         .stdout(predicate::str::contains("--draft"))
         .stdout(predicate::str::contains("--no-changelog"))
-        .stdout(predicate::str::contains("--version"));
+        .stdout(predicate::str::contains("--version"))
+        .stdout(predicate::str::contains("--package"));
 }
 
 #[test]
@@ -240,3 +295,266 @@ fn ship_shows_in_subcommand_list() {
         .success()
         .stdout(predicate::str::contains("ship"));
 }
+
+#[test]
+fn ship_bump_conflicts_with_version() {
+    cmd()
+        .args(["ship", "--bump", "minor", "--version", "1.0.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn ship_releases_every_changed_package_without_pushing() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let remote = tmp.path().join("remote.git");
+    let dir = tmp.path().join("work");
+    std::fs::create_dir_all(&dir).unwrap();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+            .args(args)
+            .current_dir(&dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    };
+    std::fs::write(
+        dir.join("scrat.toml"),
+        "[version]\nstrategy = \"conventional-commits\"\n\n\
+         [[packages]]\nname = \"a\"\npath = \"a\"\n\n\
+         [[packages]]\nname = \"b\"\npath = \"b\"\n",
+    )
+    .unwrap();
+    for name in ["a", "b"] {
+        std::fs::create_dir_all(dir.join(name)).unwrap();
+        std::fs::write(
+            dir.join(name).join("package.json"),
+            format!("{{\n  \"name\": \"{name}\",\n  \"version\": \"0.1.0\"\n}}\n"),
+        )
+        .unwrap();
+    }
+    git(&["init", "-q", "--bare", remote.to_str().unwrap()]);
+    git(&["init", "-q", "-b", "main"]);
+    git(&["add", "-A"]);
+    git(&["commit", "-qm", "chore: init"]);
+    git(&["tag", "a-v0.1.0"]);
+    git(&["tag", "b-v0.1.0"]);
+    std::fs::write(dir.join("a/index.js"), "").unwrap();
+    git(&["add", "-A"]);
+    git(&["commit", "-qm", "feat: a change"]);
+    std::fs::write(dir.join("b/index.js"), "").unwrap();
+    git(&["add", "-A"]);
+    git(&["commit", "-qm", "fix: b change"]);
+    git(&["remote", "add", "origin", remote.to_str().unwrap()]);
+    git(&["push", "-q", "-u", "origin", "main"]);
+
+    cmd()
+        .arg("-C")
+        .arg(&dir)
+        .args([
+            "ship",
+            "--no-push",
+            "--no-publish",
+            "--no-release",
+            "--no-test",
+            "--yes",
+        ])
+        .env("GIT_AUTHOR_NAME", "t")
+        .env("GIT_AUTHOR_EMAIL", "t@t")
+        .env("GIT_COMMITTER_NAME", "t")
+        .env("GIT_COMMITTER_EMAIL", "t@t")
+        .write_stdin("")
+        .assert()
+        .success();
+
+    let tags = std::process::Command::new("git")
+        .args(["tag", "--list"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    let tags = String::from_utf8_lossy(&tags.stdout);
+    assert!(tags.contains("a-v0.2.0"), "{tags}");
+    assert!(tags.contains("b-v0.1.1"), "{tags}");
+}
+
+// =============================================================================
+// Bump Command
+// =============================================================================
+
+#[test]
+fn bump_help_shows_forced_level() {
+    cmd()
+        .args(["bump", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--bump <LEVEL>"))
+        .stdout(predicate::str::contains("major"))
+        .stdout(predicate::str::contains("patch"));
+}
+
+#[test]
+fn bump_rejects_unknown_level() {
+    cmd()
+        .args(["bump", "--bump", "huge"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'huge'"));
+}
+
+#[test]
+fn bump_sync_conflicts_with_version() {
+    cmd()
+        .args(["bump", "--sync", "--version", "1.0.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn bump_sync_rewrites_version_files_of_snapshot_projects() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let dir = tmp.path();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    };
+    let pom = |version: &str| {
+        format!(
+            "<project>\n  <groupId>t</groupId>\n  <artifactId>app</artifactId>\n  <version>{version}</version>\n</project>\n"
+        )
+    };
+    std::fs::write(
+        dir.join("scrat.toml"),
+        "[[version.files]]\npath = \"VERSION\"\nregex = '(.+)'\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("VERSION"), "1.3.0\n").unwrap();
+    std::fs::write(dir.join("pom.xml"), pom("1.3.1-SNAPSHOT")).unwrap();
+    git(&["init", "-q", "-b", "main"]);
+    git(&["add", "-A"]);
+    git(&["commit", "-qm", "chore: release 1.3.0"]);
+    git(&["tag", "v1.4.0"]);
+
+    cmd()
+        .arg("-C")
+        .arg(dir)
+        .args(["bump", "--sync"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        std::fs::read_to_string(dir.join("pom.xml")).unwrap(),
+        pom("1.4.1-SNAPSHOT")
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.join("VERSION")).unwrap(),
+        "1.4.0\n"
+    );
+}
+
+#[test]
+fn bump_without_a_terminal_names_ambiguous_ecosystems() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let dir = tmp.path();
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("package.json"),
+        "{\"name\": \"app\", \"version\": \"0.1.0\"}\n",
+    )
+    .unwrap();
+    let status = std::process::Command::new("git")
+        .args(["init", "-q", "-b", "main"])
+        .current_dir(dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    cmd()
+        .arg("-C")
+        .arg(dir)
+        .args(["bump", "--dry-run"])
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "rust (Cargo.toml); node (package.json)",
+        ))
+        .stderr(predicate::str::contains("set project.type in config"));
+}
+
+// =============================================================================
+// Version Command
+// =============================================================================
+
+#[test]
+fn version_help_shows_explain() {
+    cmd()
+        .args(["version", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--explain"))
+        .stdout(predicate::str::contains("--package"));
+}
+
+#[test]
+fn version_plans_next_like_bump_during_a_pre_release_cycle() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let dir = tmp.path();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    };
+    std::fs::write(
+        dir.join("scrat.toml"),
+        "[project]\ntype = \"generic\"\n\n[version]\nstrategy = \"conventional-commits\"\n",
+    )
+    .unwrap();
+    git(&["init", "-q", "-b", "main"]);
+    git(&["add", "-A"]);
+    git(&["commit", "-qm", "feat: first"]);
+    git(&["tag", "v1.3.0-rc.1"]);
+    git(&[
+        "commit",
+        "-q",
+        "--allow-empty",
+        "-m",
+        "feat!: drop the old api",
+    ]);
+
+    let explain = |args: &[&str]| -> serde_json::Value {
+        let output = cmd()
+            .arg("-C")
+            .arg(dir)
+            .args(["version", "--json"])
+            .args(args)
+            .assert()
+            .success();
+        serde_json::from_slice(&output.get_output().stdout).unwrap()
+    };
+
+    // The open rc cycle leads to its own final version, whatever the commits say
+    let json = explain(&[]);
+    assert_eq!(json["current"], "1.3.0-rc.1");
+    assert_eq!(json["level"], "major");
+    assert_eq!(json["builtin_next"], "2.0.0");
+    assert_eq!(json["next"], "1.3.0");
+
+    assert_eq!(explain(&["--pre", "rc"])["next"], "1.3.0-rc.2");
+    assert_eq!(explain(&["--bump", "major"])["next"], "2.0.0");
+}